@@ -50,13 +50,19 @@ pub fn theme() -> conrod_core::Theme {
         border_color: conrod_core::color::BLACK,
         border_width: 0.0,
         label_color: conrod_core::color::WHITE,
+        disabled_color: conrod_core::color::GREY,
         font_id: None,
         font_size_large: 26,
         font_size_medium: 18,
         font_size_small: 12,
+        spacing_lg: 20.0,
+        spacing_md: 10.0,
+        spacing_sm: 5.0,
+        spacing_xs: 4.0,
         widget_styling: conrod_core::theme::StyleMap::default(),
         mouse_drag_threshold: 0.0,
         double_click_threshold: std::time::Duration::from_millis(500),
+        tooltip_delay: std::time::Duration::from_millis(500),
     }
 }
 
@@ -322,19 +328,16 @@ pub fn gui(ui: &mut conrod_core::UiCell, ids: &Ids, app: &mut DemoApp) {
         .font_size(SUBTITLE_SIZE)
         .set(ids.dialer_title, ui);
 
-    // Use a `NumberDialer` widget to adjust the frequency of the sine wave below.
+    // Use a `NumberDialer` bound to the frequency of the sine wave below to adjust it.
     let min = 0.5;
     let max = 200.0;
     let decimal_precision = 1;
-    for new_freq in widget::NumberDialer::new(app.sine_frequency, min, max, decimal_precision)
+    widget::NumberDialer::bind(&mut app.sine_frequency, min, max, decimal_precision)
         .down(60.0)
         .align_middle_x_of(ids.canvas)
         .w_h(160.0, 40.0)
         .label("F R E Q")
-        .set(ids.number_dialer, ui)
-    {
-        app.sine_frequency = new_freq;
-    }
+        .set(ids.number_dialer, ui);
 
     // Use the `PlotPath` widget to display a sine wave.
     let min_x = 0.0;
@@ -23,8 +23,6 @@ use gfx::format::Formatted;
 use gfx::Device;
 use old_school_gfx_glutin_ext::*;
 
-const CLEAR_COLOR: [f32; 4] = [0.2, 0.2, 0.2, 1.0];
-
 type DepthFormat = gfx::format::DepthStencil;
 
 fn get_window_dimensions(
@@ -70,6 +68,7 @@ fn main() {
     let mut ui = conrod_core::UiBuilder::new([WIN_W as f64, WIN_H as f64])
         .theme(conrod_example_shared::theme())
         .build();
+    ui.set_background_color(conrod_core::color::rgb(0.2, 0.2, 0.2));
     let ids = conrod_example_shared::Ids::new(ui.widget_id_generator());
 
     // Load font from file
@@ -143,7 +142,7 @@ fn main() {
             let dims = window_size.into();
 
             //Clear the window
-            renderer.clear(&mut encoder, CLEAR_COLOR);
+            renderer.clear(&mut encoder, ui.background_color().to_fsa());
 
             renderer.fill(&mut encoder, dims, dpi_factor, primitives, &image_map);
 
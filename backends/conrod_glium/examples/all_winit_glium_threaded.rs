@@ -59,7 +59,8 @@ fn main() {
 
     // A channel to send events from the main `winit` thread to the conrod thread.
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    // A channel to send `render::Primitive`s from the conrod thread to the `winit thread.
+    // A channel to send `render::Primitive`s and the background color from the conrod thread to
+    // the `winit thread.
     let (render_tx, render_rx) = std::sync::mpsc::channel();
     // Clone the handle to the events loop so that we can interrupt it when ready to draw.
     let events_loop_proxy = event_loop.create_proxy();
@@ -68,7 +69,10 @@ fn main() {
     fn run_conrod(
         rust_logo: conrod_core::image::Id,
         event_rx: std::sync::mpsc::Receiver<conrod_core::event::Input>,
-        render_tx: std::sync::mpsc::Sender<conrod_core::render::OwnedPrimitives>,
+        render_tx: std::sync::mpsc::Sender<(
+            conrod_core::render::OwnedPrimitives,
+            conrod_core::Color,
+        )>,
         events_loop_proxy: glium::glutin::event_loop::EventLoopProxy<()>,
     ) {
         // Construct our `Ui`.
@@ -122,7 +126,9 @@ fn main() {
             // display. Wakeup `winit` for rendering.
             if let Some(primitives) = ui.draw_if_changed() {
                 needs_update = true;
-                if render_tx.send(primitives.owned()).is_err()
+                if render_tx
+                    .send((primitives.owned(), ui.background_color()))
+                    .is_err()
                     || events_loop_proxy.send_event(()).is_err()
                 {
                     break 'conrod;
@@ -131,16 +137,18 @@ fn main() {
         }
     }
 
-    // Draws the given `primitives` to the given `Display`.
+    // Draws the given `primitives` over the `background_color` to the given `Display`.
     fn draw(
         display: &glium::Display,
         renderer: &mut Renderer,
         image_map: &conrod_core::image::Map<glium::Texture2d>,
         primitives: &conrod_core::render::OwnedPrimitives,
+        background_color: conrod_core::Color,
     ) {
         renderer.fill(display, primitives.walk(), &image_map);
         let mut target = display.draw();
-        target.clear_color(0.0, 0.0, 0.0, 1.0);
+        let [r, g, b, a] = support::clear_color(background_color);
+        target.clear_color(r, g, b, a);
         renderer.draw(display, &mut target, &image_map).unwrap();
         target.finish().unwrap();
     }
@@ -182,8 +190,14 @@ fn main() {
                             if let Some(primitives) = render_rx.try_iter().last() {
                                 latest_primitives = Some(primitives);
                             }
-                            if let Some(primitives) = &latest_primitives {
-                                draw(&display, &mut renderer, &image_map, primitives);
+                            if let Some((primitives, background_color)) = &latest_primitives {
+                                draw(
+                                    &display,
+                                    &mut renderer,
+                                    &image_map,
+                                    primitives,
+                                    *background_color,
+                                );
                             }
                         }
                         _ => {}
@@ -205,8 +219,14 @@ fn main() {
                 if let Some(primitives) = render_rx.try_iter().last() {
                     latest_primitives = Some(primitives);
                 }
-                if let Some(primitives) = &latest_primitives {
-                    draw(&display, &mut renderer, &image_map, primitives);
+                if let Some((primitives, background_color)) = &latest_primitives {
+                    draw(
+                        &display,
+                        &mut renderer,
+                        &image_map,
+                        primitives,
+                        *background_color,
+                    );
                 }
             }
         }
@@ -354,7 +354,8 @@ fn main() {
 
                 renderer.fill(display, primitives, &image_map);
                 let mut target = display.draw();
-                target.clear_color(0.0, 0.0, 0.0, 1.0);
+                let [r, g, b, a] = support::clear_color(ui.background_color());
+                target.clear_color(r, g, b, a);
                 renderer.draw(display, &mut target, &image_map).unwrap();
                 target.finish().unwrap();
             }
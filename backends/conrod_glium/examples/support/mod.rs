@@ -92,6 +92,17 @@ where
     })
 }
 
+/// The color with which to clear the window before drawing the `Ui` over it.
+///
+/// Falls back to opaque black for a fully transparent color, as is the `Ui`'s background color
+/// when none has been set, so that the window does not show whatever was last drawn to it.
+pub fn clear_color(color: conrod_core::Color) -> [f32; 4] {
+    match color.to_fsa() {
+        [_, _, _, a] if a == 0.0 => [0.0, 0.0, 0.0, 1.0],
+        rgba => rgba,
+    }
+}
+
 // Conversion functions for converting between types from glium's version of `winit` and
 // `conrod_core`.
 conrod_winit::v023_conversion_fns!();
@@ -755,23 +755,24 @@ impl Renderer {
 
                     let cache_id = font_id.index();
 
-                    let origin = text::rt::point(0.0, 0.0);
-                    let to_gl_rect = |screen_rect: text::rt::Rect<i32>| text::rt::Rect {
-                        min: origin
-                            + (text::rt::vector(
-                                screen_rect.min.x as f32 / screen_w as f32 - 0.5,
-                                1.0 - screen_rect.min.y as f32 / screen_h as f32 - 0.5,
-                            )) * 2.0,
-                        max: origin
-                            + (text::rt::vector(
-                                screen_rect.max.x as f32 / screen_w as f32 - 0.5,
-                                1.0 - screen_rect.max.y as f32 / screen_h as f32 - 0.5,
-                            )) * 2.0,
+                    // A closure to convert pixel coordinates to GL coordinates.
+                    let to_gl_point = |[x, y]: [f32; 2]| {
+                        [
+                            (x / screen_w as f32 - 0.5) * 2.0,
+                            (1.0 - y / screen_h as f32 - 0.5) * 2.0,
+                        ]
                     };
 
                     for g in positioned_glyphs.drain(..) {
                         if let Ok(Some((uv_rect, screen_rect))) = cache.rect_for(cache_id, &g) {
-                            let gl_rect = to_gl_rect(screen_rect);
+                            // The corners are rotated for vertical text.
+                            let corners = text.glyph_corners(dpi_factor as f32, screen_rect);
+                            let [bl, tl, tr, br] = [
+                                to_gl_point(corners[0]),
+                                to_gl_point(corners[1]),
+                                to_gl_point(corners[2]),
+                                to_gl_point(corners[3]),
+                            ];
                             let v = |p, t| Vertex {
                                 position: p,
                                 tex_coords: t,
@@ -779,30 +780,12 @@ impl Renderer {
                                 mode: MODE_TEXT,
                             };
                             let mut push_v = |p, t| vertices.push(v(p, t));
-                            push_v(
-                                [gl_rect.min.x, gl_rect.max.y],
-                                [uv_rect.min.x, uv_rect.max.y],
-                            );
-                            push_v(
-                                [gl_rect.min.x, gl_rect.min.y],
-                                [uv_rect.min.x, uv_rect.min.y],
-                            );
-                            push_v(
-                                [gl_rect.max.x, gl_rect.min.y],
-                                [uv_rect.max.x, uv_rect.min.y],
-                            );
-                            push_v(
-                                [gl_rect.max.x, gl_rect.min.y],
-                                [uv_rect.max.x, uv_rect.min.y],
-                            );
-                            push_v(
-                                [gl_rect.max.x, gl_rect.max.y],
-                                [uv_rect.max.x, uv_rect.max.y],
-                            );
-                            push_v(
-                                [gl_rect.min.x, gl_rect.max.y],
-                                [uv_rect.min.x, uv_rect.max.y],
-                            );
+                            push_v(bl, [uv_rect.min.x, uv_rect.max.y]);
+                            push_v(tl, [uv_rect.min.x, uv_rect.min.y]);
+                            push_v(tr, [uv_rect.max.x, uv_rect.min.y]);
+                            push_v(tr, [uv_rect.max.x, uv_rect.min.y]);
+                            push_v(br, [uv_rect.max.x, uv_rect.max.y]);
+                            push_v(bl, [uv_rect.min.x, uv_rect.max.y]);
                         }
                     }
                 }
@@ -811,6 +794,7 @@ impl Renderer {
                     image_id,
                     color,
                     source_rect,
+                    rotation,
                 } => {
                     // Switch to the `Image` state for this image if we're not in it already.
                     let new_image_id = image_id;
@@ -861,7 +845,7 @@ impl Renderer {
                             None => (0.0, 1.0, 0.0, 1.0),
                         };
 
-                        let v = |x, y, t| {
+                        let v = |[x, y]: [Scalar; 2], t| {
                             // Convert from conrod Scalar range to GL range -1.0 to 1.0.
                             let x = (x * dpi_factor as Scalar / half_win_w) as f32;
                             let y = (y * dpi_factor as Scalar / half_win_h) as f32;
@@ -873,19 +857,20 @@ impl Renderer {
                             }
                         };
 
-                        let mut push_v = |x, y, t| vertices.push(v(x, y, t));
+                        let mut push_v = |p, t| vertices.push(v(p, t));
 
-                        let (l, r, b, t) = rect.l_r_b_t();
+                        // The corners of the image, rotated about its middle.
+                        let [bl, tl, tr, br] = render::rotated_corners(rect, rotation);
 
                         // Bottom left triangle.
-                        push_v(l, t, [uv_l, uv_t]);
-                        push_v(r, b, [uv_r, uv_b]);
-                        push_v(l, b, [uv_l, uv_b]);
+                        push_v(tl, [uv_l, uv_t]);
+                        push_v(br, [uv_r, uv_b]);
+                        push_v(bl, [uv_l, uv_b]);
 
                         // Top right triangle.
-                        push_v(l, t, [uv_l, uv_t]);
-                        push_v(r, b, [uv_r, uv_b]);
-                        push_v(r, t, [uv_r, uv_t]);
+                        push_v(tl, [uv_l, uv_t]);
+                        push_v(br, [uv_r, uv_b]);
+                        push_v(tr, [uv_r, uv_t]);
                     }
                 }
 
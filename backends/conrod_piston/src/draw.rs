@@ -1,5 +1,6 @@
 //! A piston backend for rendering conrod primitives.
 
+use conrod_core::position::Orientation;
 use conrod_core::{image, render, text, utils, Rect};
 use piston_graphics;
 
@@ -143,6 +144,18 @@ pub fn primitive<'a, Img, G, T, C, F>(
                 .map(|v| v.draw_size[0] as f32 / v.window_size[0] as f32)
                 .unwrap_or(1.0);
             let positioned_glyphs: Vec<_> = text.positioned_glyphs(dpi_factor).collect();
+            // Rotate vertical text a quarter turn anticlockwise about its middle, as described by
+            // `render::Text::glyph_corners`.
+            let context = match text.orientation() {
+                Orientation::Horizontal => context,
+                Orientation::Vertical => {
+                    let (x, y) = (rect.x(), rect.y());
+                    context
+                        .trans(x, y)
+                        .rot_rad(std::f64::consts::FRAC_PI_2)
+                        .trans(-x, -y)
+                }
+            };
             // Re-orient the context to top-left origin with *y* facing downwards, as the
             // `positioned_glyphs` yield pixel positioning.
             let context = context
@@ -204,6 +217,7 @@ pub fn primitive<'a, Img, G, T, C, F>(
             image_id,
             color,
             source_rect,
+            rotation,
         } => {
             if let Some(img) = image_map.get(&image_id) {
                 let mut image = piston_graphics::image::Image::new();
@@ -212,9 +226,14 @@ pub fn primitive<'a, Img, G, T, C, F>(
                     let (x, y, w, h) = source_rect.x_y_w_h();
                     image.source_rectangle = Some([x, y, w, h]);
                 }
-                let (left, top, w, h) = rect.l_t_w_h();
+                let (x, y, w, h) = rect.x_y_w_h();
                 image.rectangle = Some([0.0, 0.0, w, h]);
-                let context = context.trans(left, top).scale(1.0, -1.0);
+                // Rotate about the middle of the image before moving to its top left corner.
+                let context = context
+                    .trans(x, y)
+                    .rot_rad(rotation)
+                    .trans(-w / 2.0, h / 2.0)
+                    .scale(1.0, -1.0);
                 let transform = context.transform;
                 let draw_state = &context.draw_state;
                 let tex = texture_from_image(img);
@@ -27,8 +27,6 @@ use rendy::{
 };
 use std::path::Path;
 
-const CLEAR_COLOR: [f32; 4] = [0.2, 0.2, 0.2, 1.0];
-
 // A wrapper around the winit window that allows us to implement the trait necessary for enabling
 // the winit <-> conrod conversion functions.
 struct WindowRef<'a>(&'a Window);
@@ -58,6 +56,7 @@ fn main() {
     let mut ui = conrod_core::UiBuilder::new([WIN_W as f64, WIN_H as f64])
         .theme(conrod_example_shared::theme())
         .build();
+    ui.set_background_color(conrod_core::color::rgb(0.2, 0.2, 0.2));
     let ids = conrod_example_shared::Ids::new(ui.widget_id_generator());
 
     // Load font from file
@@ -184,7 +183,7 @@ where
     let format = factory.get_surface_format(&surface);
     let clear = Some(ClearValue {
         color: ClearColor {
-            float32: CLEAR_COLOR,
+            float32: aux.ui.background_color().to_fsa(),
         },
     });
     let color = graph_builder.create_image(kind, levels, format, clear);
@@ -27,7 +27,6 @@ use winit::event_loop::ControlFlow;
 conrod_winit::v023_conversion_fns!();
 mod support;
 const DEPTH_FORMAT: Format = Format::D16_UNORM;
-const CLEAR_COLOR: [f32; 4] = [0.2, 0.2, 0.2, 1.0];
 fn main() {
     let event_loop = winit::event_loop::EventLoop::new();
     let mut window = support::Window::new(WIN_W, WIN_H, "Conrod with vulkno", &event_loop);
@@ -46,6 +45,7 @@ fn main() {
     let mut ui = conrod_core::UiBuilder::new([WIN_W as f64, WIN_H as f64])
         .theme(conrod_example_shared::theme())
         .build();
+    ui.set_background_color(conrod_core::color::rgb(0.2, 0.2, 0.2));
     let ids = conrod_example_shared::Ids::new(ui.widget_id_generator());
     let assets = find_folder::Search::KidsThenParents(3, 5)
         .for_folder("assets")
@@ -184,19 +184,30 @@ fn main() {
                         .fill(&image_map, viewport, dpi_factor, primitives)
                         .unwrap()
                     {
-                        let buffer = cmd
-                            .glyph_cpu_buffer_pool
-                            .chunk(cmd.glyph_cache_pixel_buffer.iter().cloned())
-                            .unwrap();
-                        command_buffer_builder
-                            .copy_buffer_to_image(buffer, cmd.glyph_cache_texture)
-                            .expect("failed to submit command for caching glyph");
+                        // Only upload the regions of the glyph cache that changed.
+                        for &region in cmd.glyph_cache_regions {
+                            let buffer = cmd
+                                .glyph_cpu_buffer_pool
+                                .chunk(cmd.region_pixels(region))
+                                .unwrap();
+                            command_buffer_builder
+                                .copy_buffer_to_image_dimensions(
+                                    buffer,
+                                    cmd.glyph_cache_texture.clone(),
+                                    [region.min.x, region.min.y, 0],
+                                    [region.width(), region.height(), 1],
+                                    0,
+                                    1,
+                                    0,
+                                )
+                                .expect("failed to submit command for caching glyph");
+                        }
                     }
                     command_buffer_builder
                         .begin_render_pass(
                             render_target.framebuffers[image_num].clone(),
                             SubpassContents::Inline,
-                            vec![CLEAR_COLOR.into(), 1f32.into()],
+                            vec![ui.background_color().to_fsa().into(), 1f32.into()],
                         )
                         .unwrap();
                     let draw_cmds = renderer
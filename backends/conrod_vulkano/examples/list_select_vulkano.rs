@@ -35,7 +35,6 @@ use winit::event::{ElementState, KeyboardInput, VirtualKeyCode};
 use winit::event_loop::ControlFlow;
 
 const DEPTH_FORMAT: Format = Format::D16_UNORM;
-const CLEAR_COLOR: [f32; 4] = [0.2, 0.2, 0.2, 1.0];
 const WIN_W: u32 = 600;
 const WIN_H: u32 = 300;
 use vulkano::buffer::TypedBufferAccess;
@@ -66,6 +65,7 @@ fn main() {
 
     // Create Ui and Ids of widgets to instantiate
     let mut ui = conrod_core::UiBuilder::new([WIN_W as f64, WIN_H as f64]).build();
+    ui.set_background_color(conrod_core::color::rgb(0.2, 0.2, 0.2));
     let ids = Ids::new(ui.widget_id_generator());
 
     // Load font from file
@@ -209,7 +209,7 @@ fn main() {
                         .begin_render_pass(
                             render_target.framebuffers[image_num].clone(),
                             SubpassContents::Inline,
-                            vec![CLEAR_COLOR.into(), 1f32.into()],
+                            vec![ui.background_color().to_fsa().into(), 1f32.into()],
                         )
                         .unwrap();
                     let draw_cmds = renderer
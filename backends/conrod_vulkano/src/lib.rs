@@ -151,6 +151,18 @@ pub struct GlyphCacheCommand<'a> {
     pub glyph_cpu_buffer_pool: Arc<CpuBufferPool<u8>>,
     /// The GPU image to which the glyphs are cached.
     pub glyph_cache_texture: Arc<StorageImage>,
+    /// The regions of the glyph cache that were written to during the last `fill`.
+    ///
+    /// Uploading only these regions via `region_pixels` avoids re-uploading the whole cache.
+    pub glyph_cache_regions: &'a [rt::Rect<u32>],
+    mesh: &'a Mesh,
+}
+
+impl<'a> GlyphCacheCommand<'a> {
+    /// The pixel data within the given region of the glyph cache, row by row.
+    pub fn region_pixels(&self, region: rt::Rect<u32>) -> impl Iterator<Item = u8> + 'a {
+        self.mesh.glyph_cache_region_pixels(region)
+    }
 }
 
 /// A draw command that maps directly to the `AutoCommandBufferBuilder::draw` method. By returning
@@ -354,6 +366,8 @@ impl Renderer {
     /// This method may return an `Option<GlyphCacheCommand>`, in which case the user should use
     /// the contained `glyph_cpu_buffer_pool` to write the pixel data to the GPU, and then use a
     /// `copy_buffer_to_image` command to write the data to the given `glyph_cache_texture` image.
+    /// Alternatively, only the `glyph_cache_regions` that changed may be uploaded via the
+    /// `copy_buffer_to_image_dimensions` command.
     pub fn fill<P: render::PrimitiveWalker>(
         &mut self,
         image_map: &image::Map<Image>,
@@ -378,6 +392,8 @@ impl Renderer {
                 glyph_cache_pixel_buffer: mesh.glyph_cache_pixel_buffer(),
                 glyph_cpu_buffer_pool: glyph_uploads.clone(),
                 glyph_cache_texture: glyph_cache_tex.clone(),
+                glyph_cache_regions: mesh.glyph_cache_regions(),
+                mesh,
             }),
         };
         Ok(glyph_cache_cmd)
@@ -12,7 +12,7 @@
 //!
 
 use std::f32::consts::PI;
-use utils::{degrees, fmod, turns};
+use utils::{self, degrees, fmod, turns};
 
 /// Color supporting RGB and HSL variants.
 #[derive(PartialEq, Copy, Clone, Debug)]
@@ -401,6 +401,43 @@ pub enum Gradient {
     Radial((f64, f64), f64, (f64, f64), f64, Vec<(f64, Color)>),
 }
 
+/// Interpolate from color `a` to color `b` by `t`, where `t` is clamped to `0.0..=1.0`.
+///
+/// The RGB channels are interpolated in linear light (rather than through HSL or the sRGB encoded
+/// values) so that the midpoint appears halfway between the two colors. The alpha channel is
+/// interpolated as is.
+pub fn lerp(a: Color, b: Color, t: f32) -> Color {
+    let Rgba(ar, ag, ab, aa) = a.to_rgb();
+    let Rgba(br, bg, bb, ba) = b.to_rgb();
+    let channel =
+        |a: f32, b: f32| linear_to_srgb(utils::lerp(srgb_to_linear(a), srgb_to_linear(b), t));
+    Color::Rgba(
+        channel(ar, br),
+        channel(ag, bg),
+        channel(ab, bb),
+        utils::lerp(aa, ba, t),
+    )
+}
+
+/// Convert an sRGB encoded color channel to linear light.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    // Taken from https://github.com/PistonDevelopers/graphics/src/color.rs#L42
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a color channel in linear light to its sRGB encoding.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 /// Create a linear gradient.
 pub fn linear(start: (f64, f64), end: (f64, f64), colors: Vec<(f64, Color)>) -> Gradient {
     Gradient::Linear(start, end, colors)
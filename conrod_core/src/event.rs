@@ -320,6 +320,32 @@ pub struct Scroll {
     pub modifiers: input::keyboard::ModifierKey,
 }
 
+/// A single widget interaction, as recorded within the `Ui`'s per-frame interaction log.
+///
+/// Widgets report interactions from within their `update` methods via
+/// `UiCell::log_interaction`. The log for the last call to `Ui::set_widgets` may be retrieved via
+/// `Ui::interaction_log`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Interaction {
+    /// The unique identifier of the widget that was interacted with.
+    pub widget: widget::Id,
+    /// The kind of widget that was interacted with, e.g. `"Button"` or `"Slider"`.
+    pub widget_kind: &'static str,
+    /// The kind of interaction that occurred.
+    pub kind: InteractionKind,
+}
+
+/// The different kinds of interactions that may be recorded within the interaction log.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum InteractionKind {
+    /// The widget was clicked or tapped, e.g. a `Button` was triggered.
+    Click,
+    /// The widget was dragged, e.g. the handle of a `Slider` was moved by the mouse.
+    Drag,
+    /// The value represented by the widget was changed by some other means.
+    ValueChanged,
+}
+
 impl Motion {
     /// Returns a copy of the `Motion` relative to the given `xy`
     pub fn relative_to(&self, xy: Point) -> Motion {
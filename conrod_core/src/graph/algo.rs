@@ -4,7 +4,7 @@
 //! This module hosts more complex algorithms in which the **Graph** is a key component in
 //! producing the desired result.
 
-use super::{EdgeIndex, Graph};
+use super::{Container, EdgeIndex, Graph};
 use daggy::Walker;
 use fnv;
 use position::{Point, Rect};
@@ -47,7 +47,10 @@ impl PickWidgets {
                 None => break,
                 Some(&idx) => idx,
             };
-            let visible_rect = match cropped_area_of_widget(graph, idx) {
+            if ignores_mouse(graph, idx) {
+                continue;
+            }
+            let visible_rect = match hit_area_of_widget(graph, idx) {
                 None => continue,
                 Some(rect) => rect,
             };
@@ -146,13 +149,41 @@ pub fn pick_scrollable_widgets(depth_order: &[widget::Id], xy: Point) -> PickScr
     }
 }
 
+/// Whether or not the widget with the given index, or any widget for which it is a graphical
+/// element, has been instantiated with `ignore_mouse` and thus should not be picked.
+pub fn ignores_mouse(graph: &Graph, idx: widget::Id) -> bool {
+    let ignores = |id| match graph.widget(id) {
+        Some(widget) => widget.ignore_mouse,
+        None => false,
+    };
+    if ignores(idx) {
+        return true;
+    }
+    let mut graphic_parents = graph.graphic_parent_recursion(idx);
+    while let Some(graphic_parent) = graphic_parents.next_node(graph) {
+        if ignores(graphic_parent) {
+            return true;
+        }
+    }
+    false
+}
+
 /// The rectangle that represents the maximum visible area for the widget with the given index.
 ///
-/// Specifically, this considers the cropped scroll area for all parents.
+/// Specifically, this considers the cropped area of all parents that crop their kids, including all
+/// scrollable parents.
 ///
 /// Otherwise, return None if the widget is hidden.
 pub fn cropped_area_of_widget(graph: &Graph, idx: widget::Id) -> Option<Rect> {
-    cropped_area_of_widget_maybe_within_depth(graph, idx, None)
+    cropped_area_of_widget_maybe_within_depth(graph, idx, None, |widget| widget.rect)
+}
+
+/// The same as `cropped_area_of_widget` but starting from the widget's hit-test rectangle, which
+/// includes the widget's `hit_padding`.
+///
+/// This is the area within which the widget may be picked.
+pub fn hit_area_of_widget(graph: &Graph, idx: widget::Id) -> Option<Rect> {
+    cropped_area_of_widget_maybe_within_depth(graph, idx, None, |widget| widget.hit_rect())
 }
 
 /// The rectangle that represents the maximum visible area for the widget with the given index.
@@ -166,18 +197,24 @@ pub fn cropped_area_of_widget_within_depth(
     idx: widget::Id,
     deepest_parent_idx: widget::Id,
 ) -> Option<Rect> {
-    cropped_area_of_widget_maybe_within_depth(graph, idx, Some(deepest_parent_idx))
+    cropped_area_of_widget_maybe_within_depth(graph, idx, Some(deepest_parent_idx), |widget| {
+        widget.rect
+    })
 }
 
-/// Logic shared between the `cropped_area_of_widget` and `cropped_area_of_widget_within_depth`
-/// functions.
-fn cropped_area_of_widget_maybe_within_depth(
+/// Logic shared between the `cropped_area_of_widget`, `cropped_area_of_widget_within_depth` and
+/// `hit_area_of_widget` functions.
+fn cropped_area_of_widget_maybe_within_depth<F>(
     graph: &Graph,
     mut id: widget::Id,
     deepest_id: Option<widget::Id>,
-) -> Option<Rect> {
+    widget_rect: F,
+) -> Option<Rect>
+where
+    F: FnOnce(&Container) -> Rect,
+{
     graph.widget(id).and_then(|widget| {
-        let mut overlapping_rect = widget.rect;
+        let mut overlapping_rect = widget_rect(widget);
         let mut depth_parents = graph.depth_parent_recursion(id);
         while let Some(depth_parent) = depth_parents.next_node(graph) {
             // If the parent's index matches that of the deepest, we're done.
@@ -185,12 +222,11 @@ fn cropped_area_of_widget_maybe_within_depth(
                 break;
             }
 
-            // Check to see if our parent is a scrollable widget and whether or not we need to
-            // update the overlap.
-            //
-            // TODO: Consider "cropped area" here once implemented instead of scrolling.
+            // Check to see if our parent crops its kids (e.g. a scrollable widget) and whether or
+            // not we need to update the overlap.
             if let Some(depth_parent_widget) = graph.widget(depth_parent) {
-                if depth_parent_widget.maybe_x_scroll_state.is_some()
+                if depth_parent_widget.crop_kids
+                    || depth_parent_widget.maybe_x_scroll_state.is_some()
                     || depth_parent_widget.maybe_y_scroll_state.is_some()
                 {
                     // If the depth_parent is also a **Graphic** parent, there is no need to
@@ -213,6 +249,20 @@ fn cropped_area_of_widget_maybe_within_depth(
     })
 }
 
+/// The opacity of the widget at the given index multiplied by the opacity of each of its
+/// depth-wise parents.
+///
+/// Returns `1.0` (fully opaque) if there is no widget for the given index.
+pub fn cascaded_opacity(graph: &Graph, idx: widget::Id) -> f32 {
+    let opacity_of = |id| graph.widget(id).map(|w| w.opacity).unwrap_or(1.0);
+    let mut opacity = opacity_of(idx);
+    let mut depth_parents = graph.depth_parent_recursion(idx);
+    while let Some(depth_parent) = depth_parents.next_node(graph) {
+        opacity *= opacity_of(depth_parent);
+    }
+    opacity
+}
+
 /// Find the absolute `Rect` that bounds all widgets that are `Depth` children of the widget at the
 /// given `idx`.
 ///
@@ -275,6 +325,74 @@ pub fn kids_bounding_box(
     })
 }
 
+/// Find the absolute `Rect` that bounds all widgets that are `Depth` children of the widget at the
+/// given `idx` as they would be positioned if the widget were not scrolled.
+///
+/// This is the `kids_bounding_box` with the widget's own scroll offset removed, i.e. the total
+/// extent of the content that may be scrolled through. The kids of nested scrollable widgets are
+/// already cropped to the visible area of those widgets and kids positioned relatively to a
+/// scrolled sibling are only offset once, so only the offset of the widget at `idx` is undone.
+pub fn scrollable_bounding_box(
+    graph: &Graph,
+    prev_updated: &fnv::FnvHashSet<widget::Id>,
+    idx: widget::Id,
+) -> Option<Rect> {
+    graph.widget(idx).and_then(|container| {
+        let x_offset = container
+            .maybe_x_scroll_state
+            .map_or(0.0, |scroll| scroll.offset);
+        let y_offset = container
+            .maybe_y_scroll_state
+            .map_or(0.0, |scroll| scroll.offset);
+        kids_bounding_box(graph, prev_updated, idx)
+            .map(|rect| rect.shift_x(-x_offset).shift_y(-y_offset))
+    })
+}
+
+/// The next widget within the given `depth_order` that accepts keyboard focus, following the
+/// widget at `current`.
+///
+/// The search wraps around the end of the `depth_order`. If there is no `current` widget (or it
+/// does not exist within the `depth_order`), the search begins from the first widget, or from the
+/// last widget if `reverse` is `true`.
+///
+/// Widgets whose depth-wise ancestors were opted out of focus via `Widget::focusable` are
+/// skipped.
+///
+/// Returns `None` if no other widget accepts focus.
+pub fn next_focusable(
+    graph: &Graph,
+    depth_order: &[widget::Id],
+    current: Option<widget::Id>,
+    reverse: bool,
+) -> Option<widget::Id> {
+    let len = depth_order.len();
+    let maybe_current_idx = current.and_then(|id| depth_order.iter().position(|&idx| idx == id));
+    let accepts_focus = |id: widget::Id| {
+        if !graph.widget(id).map(|w| w.accepts_focus).unwrap_or(false) {
+            return false;
+        }
+        let mut depth_parents = graph.depth_parent_recursion(id);
+        while let Some(depth_parent) = depth_parents.next_node(graph) {
+            if let Some(parent) = graph.widget(depth_parent) {
+                if !parent.kids_accept_focus {
+                    return false;
+                }
+            }
+        }
+        true
+    };
+    (0..len)
+        .map(|step| match (maybe_current_idx, reverse) {
+            (Some(i), false) => (i + 1 + step) % len,
+            (Some(i), true) => (i + len - 1 - step % len) % len,
+            (None, false) => step,
+            (None, true) => len - 1 - step,
+        })
+        .map(|i| depth_order[i])
+        .find(|&id| Some(id) != current && accepts_focus(id))
+}
+
 /// Return the `scroll_offset` for the widget at the given index.
 ///
 /// The offset is retrieved from the widget that is the immediate `depth_parent` of the widget at
@@ -4,7 +4,7 @@
 //! The primary type of interest in this module is the [**Graph**](./struct.Graph) type.
 
 use daggy;
-use position::{Axis, Depth, Point, Rect};
+use position::{Axis, Depth, Point, Range, Rect, Scalar};
 use std;
 use std::any::Any;
 use std::ops::{Index, IndexMut};
@@ -45,6 +45,23 @@ pub type PositionChildren = daggy::walker::Chain<Graph, u32, XPositionChildren,
 /// An alias for a **Walker** over a node's **Graphic** children.
 pub type GraphicChildren = FilteredChildren;
 
+/// An iterator yielding the **Depth** children of some node that are **Node::Widget**s.
+///
+/// See `Graph::widget_children`.
+pub struct WidgetChildren<'a> {
+    graph: &'a Graph,
+    depth_children: DepthChildren,
+}
+
+/// An iterator yielding every **Node::Widget** descendant of some node in depth-first order.
+///
+/// See `Graph::widget_descendants`.
+pub struct WidgetDescendants<'a> {
+    graph: &'a Graph,
+    stack: Vec<widget::Id>,
+    visited: std::collections::HashSet<widget::Id>,
+}
+
 /// An alias for our Graph's recursive walker.
 pub type RecursiveWalk<F> = daggy::walker::Recursive<Graph, u32, F>;
 
@@ -73,6 +90,8 @@ pub struct Container {
     ///
     /// This is equal to `std::any::TypeId::of::<Widget::State>()`.
     pub type_id: std::any::TypeId,
+    /// The name of the **Widget**'s type, e.g. `conrod_core::widget::button::Button<..>`.
+    pub type_name: &'static str,
     /// The rectangle describing the Widget's area.
     pub rect: Rect,
     /// The depth at which the widget will be rendered comparatively to its siblings.
@@ -101,6 +120,21 @@ pub struct Container {
     ///
     /// NOTE: See `Wiget::is_over` for more details and a note on possible future plans.
     pub is_over: IsOverFn,
+    /// The opacity of the widget, which is cascaded to all of its depth-wise descendants.
+    pub opacity: f32,
+    /// Whether or not the widget may receive keyboard focus via `Tab` navigation.
+    pub accepts_focus: bool,
+    /// Whether or not the widget's depth-wise descendants may receive keyboard focus, i.e. `false`
+    /// if the widget was opted out of focus via `Widget::focusable`.
+    pub kids_accept_focus: bool,
+    /// The stable key under which the widget's state is saved via `Ui::save_state`.
+    pub maybe_state_key: Option<&'static str>,
+    /// A function specified by the widget for saving its unique state.
+    pub save_state: SaveStateFn,
+    /// The distance by which the widget's hit-test area extends beyond its `rect`.
+    pub hit_padding: Scalar,
+    /// Whether or not the widget lets the mouse and touch pass through to widgets beneath.
+    pub ignore_mouse: bool,
 }
 
 /// A wrapper around a `widget::IsOverFn` to make implementing `Debug` easier for `Container`.
@@ -113,6 +147,16 @@ impl std::fmt::Debug for IsOverFn {
     }
 }
 
+/// A wrapper around a `widget::SaveStateFn` to make implementing `Debug` easier for `Container`.
+#[derive(Copy, Clone)]
+pub struct SaveStateFn(pub widget::SaveStateFn);
+
+impl std::fmt::Debug for SaveStateFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SaveStateFn")
+    }
+}
+
 /// A node for use within the **Graph**.
 #[derive(Debug)]
 pub enum Node {
@@ -161,6 +205,8 @@ pub struct Graph {
     /// Cached widget state in a directed acyclic graph whose edges describe the rendering tree and
     /// positioning.
     dag: Dag,
+    /// The `widget::Id`s generated via `widget::id::Generator::named`, keyed by their names.
+    names: std::collections::BTreeMap<String, widget::Id>,
 }
 
 impl Container {
@@ -185,6 +231,13 @@ impl Container {
     {
         self.state_and_style::<W::State, W::Style>()
     }
+
+    /// The area within which the widget may be hit by the mouse or touch.
+    ///
+    /// This is the widget's `rect` extended by its `hit_padding`.
+    pub fn hit_rect(&self) -> Rect {
+        self.rect.pad(-self.hit_padding)
+    }
 }
 
 impl Node {
@@ -198,10 +251,41 @@ impl Node {
     }
 }
 
+impl<'a> Iterator for WidgetChildren<'a> {
+    type Item = widget::Id;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(child) = self.depth_children.next_node(self.graph) {
+            if self.graph.widget(child).is_some() {
+                return Some(child);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for WidgetDescendants<'a> {
+    type Item = widget::Id;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(id) = self.stack.pop() {
+            // Guard against visiting a node twice in case the graph contains a cycle.
+            if !self.visited.insert(id) {
+                continue;
+            }
+            let children: Vec<_> = self.graph.widget_children(id).collect();
+            self.stack.extend(children.into_iter().rev());
+            return Some(id);
+        }
+        None
+    }
+}
+
 impl Graph {
     /// A new empty **Graph**.
     pub fn new() -> Self {
-        Graph { dag: Dag::new() }
+        Graph {
+            dag: Dag::new(),
+            names: std::collections::BTreeMap::new(),
+        }
     }
 
     /// A new **Graph** with the given node capacity.
@@ -213,12 +297,14 @@ impl Graph {
         let n_edges = n_nodes * NUM_EDGE_VARIANTS;
         Graph {
             dag: Dag::with_capacity(n_nodes, n_edges),
+            names: std::collections::BTreeMap::new(),
         }
     }
 
     /// Removes all **Node**s and **Edge**s from the **Graph**.
     pub fn clear(&mut self) {
-        self.dag.clear()
+        self.dag.clear();
+        self.names.clear();
     }
 
     /// The total number of **Node**s in the **Graph**.
@@ -226,6 +312,20 @@ impl Graph {
         self.dag.node_count()
     }
 
+    /// The `widget::Id` generated for the given name via `widget::id::Generator::named`, if any.
+    pub fn named_id(&self, name: &str) -> Option<widget::Id> {
+        self.names.get(name).cloned()
+    }
+
+    /// All `widget::Id`s generated via `widget::id::Generator::named` along with their names,
+    /// ordered by name.
+    ///
+    /// Unlike the order of the `widget::Id`s themselves, this order does not depend on the order
+    /// in which the ids were generated, making it suitable for keying snapshots of the **Graph**.
+    pub fn named_ids(&self) -> std::collections::btree_map::Iter<'_, String, widget::Id> {
+        self.names.iter()
+    }
+
     /// The total number of **Node::Widget**s in the **Graph**.
     pub fn widget_count(&self) -> usize {
         (0..self.node_count())
@@ -238,6 +338,56 @@ impl Graph {
         self.dag.edge_count()
     }
 
+    /// A Graphviz DOT description of the **Graph**, useful for debugging layout.
+    ///
+    /// Each widget node is labelled with its `widget::Id`, its name (if it was generated via
+    /// `widget::id::Generator::named`), the name of its type and its `Rect`. Placeholder nodes are
+    /// drawn dashed. Each edge is labelled with its **Edge** variant.
+    pub fn to_dot(&self) -> String {
+        use std::fmt::Write;
+
+        let mut names = std::collections::HashMap::new();
+        for (name, &id) in &self.names {
+            names.insert(id, name.as_str());
+        }
+
+        let mut dot = String::from("digraph {\n");
+        for i in 0..self.node_count() {
+            let id = widget::Id::new(i);
+            let mut label = format!("{}", i);
+            if let Some(name) = names.get(&id) {
+                write!(label, " \"{}\"", name).unwrap();
+            }
+            match self[id] {
+                Node::Widget(ref container) => {
+                    let (x, y, w, h) = container.rect.x_y_w_h();
+                    write!(
+                        label,
+                        ": {}\n[{}, {}] {}x{}",
+                        short_type_name(container.type_name),
+                        x,
+                        y,
+                        w,
+                        h
+                    )
+                    .unwrap();
+                    writeln!(dot, "    {} [label={:?}];", i, label).unwrap();
+                }
+                Node::Placeholder => {
+                    label.push_str(": Placeholder");
+                    writeln!(dot, "    {} [label={:?}, style=dashed];", i, label).unwrap();
+                }
+            }
+        }
+        for edge in self.dag.raw_edges() {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            let label = format!("{:?}", edge.weight);
+            writeln!(dot, "    {} -> {} [label={:?}];", a, b, label).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
     /// The current capacity for the **Graph**'s internal node `Vec`.
     pub fn node_capacity(&self) -> usize {
         unimplemented!();
@@ -332,6 +482,19 @@ impl Graph {
         self.add_node(Node::Placeholder)
     }
 
+    /// Return the `widget::Id` associated with the given name, first adding a new placeholder
+    /// node for it if there is none.
+    ///
+    /// This method is used by `widget::id::Generator::named`.
+    pub fn named_placeholder(&mut self, name: &str) -> widget::Id {
+        if let Some(id) = self.named_id(name) {
+            return id;
+        }
+        let id = self.add_placeholder();
+        self.names.insert(name.to_string(), id);
+        id
+    }
+
     /// Borrow the node at the given **widget::Id** if there is one.
     pub fn node(&self, idx: widget::Id) -> Option<&Node> {
         self.dag.node_weight(idx)
@@ -373,6 +536,119 @@ impl Graph {
         })
     }
 
+    /// Set the scroll offset of the scrollable widget at the given index.
+    ///
+    /// The offset along each scrollable axis is clamped to the axis' `offset_bounds` and any
+    /// momentum is stopped. Axes that are not scrollable are ignored. The new offset is reflected
+    /// by `algo::scroll_offset` immediately and by the positions of the widget's children (and any
+    /// `Scrollbar` handle) upon the next update.
+    ///
+    /// Returns `false` if there is no scrollable widget for the given index.
+    pub fn set_scroll_offset(&mut self, idx: widget::Id, offset: Point) -> bool {
+        let container = match self.widget_mut(idx) {
+            Some(container) => container,
+            None => return false,
+        };
+        if let Some(ref mut scroll) = container.maybe_x_scroll_state {
+            scroll.offset = scroll.offset_bounds.clamp_value(offset[0]);
+            scroll.velocity = 0.0;
+        }
+        if let Some(ref mut scroll) = container.maybe_y_scroll_state {
+            scroll.offset = scroll.offset_bounds.clamp_value(offset[1]);
+            scroll.velocity = 0.0;
+        }
+        container.maybe_x_scroll_state.is_some() || container.maybe_y_scroll_state.is_some()
+    }
+
+    /// Scroll the scrollable widget at the `container` index just far enough that the `Rect` of the
+    /// widget at the `child` index lies within the `container`'s padded `kid_area`.
+    ///
+    /// If the child is larger than the `kid_area` along an axis, its left or top edge is brought
+    /// into view. See `Graph::set_scroll_offset` for details on when the change takes effect.
+    ///
+    /// Returns `false` if either widget does not exist or if the `container` is not scrollable.
+    pub fn scroll_to_widget(&mut self, container: widget::Id, child: widget::Id) -> bool {
+        let child_rect = match self.widget(child) {
+            Some(child) => child.rect,
+            None => return false,
+        };
+        let (kid_area, offset) = match self.widget(container) {
+            Some(container) => {
+                let kid_area = &container.kid_area;
+                let rect = kid_area.rect.padding(kid_area.pad);
+                let x = container.maybe_x_scroll_state.map_or(0.0, |s| s.offset);
+                let y = container.maybe_y_scroll_state.map_or(0.0, |s| s.offset);
+                (rect, [x, y])
+            }
+            None => return false,
+        };
+
+        // The distance by which the child must be shifted to lie within the area.
+        fn shift_into_range(child: Range, area: Range, align_end: bool) -> Scalar {
+            let (child, area) = (child.undirected(), area.undirected());
+            if child.len() > area.len() {
+                if align_end {
+                    area.end - child.end
+                } else {
+                    area.start - child.start
+                }
+            } else if child.start < area.start {
+                area.start - child.start
+            } else if child.end > area.end {
+                area.end - child.end
+            } else {
+                0.0
+            }
+        }
+
+        let offset = [
+            offset[0] + shift_into_range(child_rect.x, kid_area.x, false),
+            offset[1] + shift_into_range(child_rect.y, kid_area.y, true),
+        ];
+        self.set_scroll_offset(container, offset)
+    }
+
+    /// Bring the floating widget at the given index in front of all other floating widgets.
+    ///
+    /// This sets the widget's `time_last_clicked` to now, as though it were clicked. The change is
+    /// reflected by the next update of the **DepthOrder**.
+    ///
+    /// Returns `false` if there is no floating widget for the given index.
+    pub fn raise_to_front(&mut self, idx: widget::Id) -> bool {
+        match self.widget_mut(idx).and_then(|w| w.maybe_floating.as_mut()) {
+            Some(floating) => {
+                floating.time_last_clicked = instant::Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Send the floating widget at the given index behind all other floating widgets.
+    ///
+    /// This sets the widget's `time_last_clicked` to just before that of the floating widget that
+    /// was clicked least recently. The change is reflected by the next update of the
+    /// **DepthOrder**.
+    ///
+    /// Returns `false` if there is no floating widget for the given index.
+    pub fn send_to_back(&mut self, idx: widget::Id) -> bool {
+        let earliest = (0..self.node_count())
+            .filter_map(|i| self.widget(widget::Id::new(i)))
+            .filter_map(|container| container.maybe_floating)
+            .map(|floating| floating.time_last_clicked)
+            .min();
+        match self.widget_mut(idx).and_then(|w| w.maybe_floating.as_mut()) {
+            Some(floating) => {
+                if let Some(earliest) = earliest {
+                    let step = std::time::Duration::from_nanos(1);
+                    floating.time_last_clicked = earliest.checked_sub(step).unwrap_or(earliest);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
     /// A **Walker** type that may be used to step through the parents of the given child node.
     pub fn parents(&self, child: widget::Id) -> Parents {
         self.dag.parents(child)
@@ -528,6 +804,33 @@ impl Graph {
         self.children(idx).filter(is_depth_edge)
     }
 
+    /// An iterator yielding the **Depth** children of the given parent node, skipping any
+    /// **Node::Placeholder**s.
+    ///
+    /// Unlike `depth_children`, the returned type is an `Iterator` that borrows the **Graph**, so
+    /// it may be used directly with iterator adaptors, e.g. to traverse a subtree.
+    pub fn widget_children(&self, idx: widget::Id) -> WidgetChildren<'_> {
+        WidgetChildren {
+            graph: self,
+            depth_children: self.depth_children(idx),
+        }
+    }
+
+    /// An iterator yielding every **Depth** descendant of the given node that is a
+    /// **Node::Widget**, in depth-first order, not including the node itself.
+    ///
+    /// Each child is yielded before its own descendants and each node is yielded at most once.
+    pub fn widget_descendants(&self, idx: widget::Id) -> WidgetDescendants<'_> {
+        let mut visited = std::collections::HashSet::new();
+        visited.insert(idx);
+        let children: Vec<_> = self.widget_children(idx).collect();
+        WidgetDescendants {
+            graph: self,
+            stack: children.into_iter().rev().collect(),
+            visited: visited,
+        }
+    }
+
     /// For walking the **Position(X)** children of the given parent node.
     pub fn x_position_children(&self, idx: widget::Id) -> XPositionChildren {
         self.children(idx).filter(is_x_position_edge)
@@ -654,6 +957,7 @@ impl Graph {
     ) {
         let widget::PreUpdateCache {
             type_id,
+            type_name,
             id,
             maybe_parent_id,
             maybe_x_positioned_relatively_id,
@@ -668,6 +972,13 @@ impl Graph {
             maybe_y_scroll_state,
             maybe_graphics_for,
             is_over,
+            opacity,
+            accepts_focus,
+            kids_accept_focus,
+            maybe_state_key,
+            save_state,
+            hit_padding,
+            ignore_mouse,
         } = widget;
 
         assert!(
@@ -680,6 +991,7 @@ impl Graph {
         let new_container = || Container {
             maybe_state: None,
             type_id: type_id,
+            type_name: type_name,
             rect: rect,
             depth: depth,
             kid_area: kid_area,
@@ -690,6 +1002,13 @@ impl Graph {
             maybe_y_scroll_state: maybe_y_scroll_state,
             instantiation_order_idx: instantiation_order_idx,
             is_over: IsOverFn(is_over),
+            opacity: opacity,
+            accepts_focus: accepts_focus,
+            kids_accept_focus: kids_accept_focus,
+            maybe_state_key: maybe_state_key,
+            save_state: SaveStateFn(save_state),
+            hit_padding: hit_padding,
+            ignore_mouse: ignore_mouse,
         };
 
         // Retrieves the widget's parent index.
@@ -754,6 +1073,13 @@ impl Graph {
                 container.maybe_y_scroll_state = maybe_y_scroll_state;
                 container.instantiation_order_idx = instantiation_order_idx;
                 container.is_over = IsOverFn(is_over);
+                container.opacity = opacity;
+                container.accepts_focus = accepts_focus;
+                container.kids_accept_focus = kids_accept_focus;
+                container.maybe_state_key = maybe_state_key;
+                container.save_state = SaveStateFn(save_state);
+                container.hit_padding = hit_padding;
+                container.ignore_mouse = ignore_mouse;
             }
         }
 
@@ -832,6 +1158,12 @@ fn is_graphic_edge(g: &Graph, e: EdgeIndex, _: widget::Id) -> bool {
     g[e] == Edge::Graphic
 }
 
+// The name of the given type without its module path or type parameters.
+fn short_type_name(type_name: &str) -> &str {
+    let without_params = type_name.split('<').next().unwrap_or(type_name);
+    without_params.rsplit("::").next().unwrap_or(without_params)
+}
+
 impl Walker<Graph> for Children {
     type Index = u32;
     #[inline]
@@ -1,8 +1,10 @@
 //! A type used to manage a user's image data and map them to `Image` widgets:
 //!
 //! - [Map](./struct.Map.html)
+//! - [AtlasPacker](./struct.AtlasPacker.html)
 
 use fnv;
+use position::Rect;
 use std;
 
 /// Unique image identifier.
@@ -29,6 +31,68 @@ pub struct Map<Img> {
 /// The type of `std::collections::HashMap` with `fnv::FnvHasher` used within the `image::Map`.
 pub type HashMap<Img> = fnv::FnvHashMap<Id, Img>;
 
+/// Packs many small RGBA images into one or more larger atlas images.
+///
+/// Loading many small images as a single texture reduces the number of texture binds required
+/// when drawing. Once packed, each atlas may be loaded as a texture and inserted into a `Map`,
+/// while each image is drawn via an `Image` widget using the `source_rectangle` of its
+/// `Placement`.
+///
+/// Images are packed onto "shelves": rows spanning the width of the atlas whose height is that
+/// of the first image placed on them. Each image is placed on the shelf that wastes the least
+/// height, or on a new shelf if none have room. Once an atlas is full, a new atlas is started.
+pub struct AtlasPacker {
+    dimensions: [u32; 2],
+    atlases: Vec<Atlas>,
+}
+
+/// A single RGBA atlas image produced by an `AtlasPacker`.
+pub struct Atlas {
+    pixels: Vec<u8>,
+    shelves: Vec<Shelf>,
+}
+
+/// A row of packed images within an `Atlas`.
+struct Shelf {
+    y: u32,
+    height: u32,
+    next_x: u32,
+}
+
+/// The location of an image packed by an `AtlasPacker`.
+///
+/// Coordinates are in pixels, where `[0, 0]` is the top left corner of the atlas.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// The index of the atlas in which the image was placed.
+    pub atlas: usize,
+    /// The position of the left edge of the image within the atlas.
+    pub x: u32,
+    /// The position of the top edge of the image within the atlas.
+    pub y: u32,
+    /// The width of the image.
+    pub w: u32,
+    /// The height of the image.
+    pub h: u32,
+}
+
+/// Errors that may occur while packing an image via an `AtlasPacker`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PackError {
+    /// The image is larger than the dimensions of an atlas and can never be packed.
+    TooLarge {
+        /// The dimensions of the image.
+        dimensions: [u32; 2],
+    },
+    /// The length of the given RGBA pixel data does not match the dimensions of the image.
+    InvalidPixelData {
+        /// The expected length of the pixel data.
+        expected: usize,
+        /// The actual length of the pixel data.
+        actual: usize,
+    },
+}
+
 /// An iterator yielding an `Id` for each new `Img` inserted into the `Map` via the `extend`
 /// method.
 pub struct NewIds {
@@ -129,3 +193,184 @@ impl ExactSizeIterator for NewIds {
         self.index_range.len()
     }
 }
+
+impl AtlasPacker {
+    /// Construct a new `AtlasPacker` that packs images into atlases of the given dimensions.
+    pub fn new(dimensions: [u32; 2]) -> Self {
+        AtlasPacker {
+            dimensions: dimensions,
+            atlases: Vec::new(),
+        }
+    }
+
+    /// The dimensions of each atlas.
+    pub fn dimensions(&self) -> [u32; 2] {
+        self.dimensions
+    }
+
+    /// The atlases that have been started so far.
+    pub fn atlases(&self) -> &[Atlas] {
+        &self.atlases
+    }
+
+    /// Reserve space for an image with the given dimensions, returning where it was placed.
+    ///
+    /// The image is placed within the first atlas with room for it, starting a new atlas if
+    /// necessary.
+    pub fn pack(&mut self, dimensions: [u32; 2]) -> Result<Placement, PackError> {
+        let [atlas_w, atlas_h] = self.dimensions;
+        let [w, h] = dimensions;
+        if w > atlas_w || h > atlas_h {
+            return Err(PackError::TooLarge {
+                dimensions: dimensions,
+            });
+        }
+
+        // An image without any area needs no space, so is placed at the origin of the last atlas.
+        if w == 0 || h == 0 {
+            if self.atlases.is_empty() {
+                self.atlases.push(Atlas::new(self.dimensions));
+            }
+            return Ok(Placement {
+                atlas: self.atlases.len() - 1,
+                x: 0,
+                y: 0,
+                w: w,
+                h: h,
+            });
+        }
+
+        for (i, atlas) in self.atlases.iter_mut().enumerate() {
+            if let Some([x, y]) = atlas.reserve(self.dimensions, dimensions) {
+                return Ok(Placement {
+                    atlas: i,
+                    x: x,
+                    y: y,
+                    w: w,
+                    h: h,
+                });
+            }
+        }
+
+        let mut atlas = Atlas::new(self.dimensions);
+        let [x, y] = atlas
+            .reserve(self.dimensions, dimensions)
+            .expect("an empty atlas must fit an image no larger than itself");
+        self.atlases.push(atlas);
+        Ok(Placement {
+            atlas: self.atlases.len() - 1,
+            x: x,
+            y: y,
+            w: w,
+            h: h,
+        })
+    }
+
+    /// Pack the given RGBA image, copying its pixels into the atlas in which it is placed.
+    ///
+    /// The `pixels` are given row by row starting from the top, with four bytes per pixel.
+    pub fn pack_rgba(
+        &mut self,
+        dimensions: [u32; 2],
+        pixels: &[u8],
+    ) -> Result<Placement, PackError> {
+        let [w, h] = dimensions;
+        let expected = w as usize * h as usize * 4;
+        if pixels.len() != expected {
+            return Err(PackError::InvalidPixelData {
+                expected: expected,
+                actual: pixels.len(),
+            });
+        }
+
+        let placement = self.pack(dimensions)?;
+        if w == 0 || h == 0 {
+            return Ok(placement);
+        }
+        let atlas_row_len = self.dimensions[0] as usize * 4;
+        let row_len = w as usize * 4;
+        let atlas = &mut self.atlases[placement.atlas];
+        for (row, src) in pixels.chunks(row_len).enumerate() {
+            let start = (placement.y as usize + row) * atlas_row_len + placement.x as usize * 4;
+            atlas.pixels[start..start + row_len].copy_from_slice(src);
+        }
+        Ok(placement)
+    }
+
+    /// The rectangle to pass to `Image::source_rectangle` in order to draw the image at the given
+    /// `Placement` from its atlas texture.
+    pub fn source_rectangle(&self, placement: Placement) -> Rect {
+        let atlas_h = self.dimensions[1] as f64;
+        let l = placement.x as f64;
+        let r = l + placement.w as f64;
+        let t = atlas_h - placement.y as f64;
+        let b = t - placement.h as f64;
+        Rect::from_corners([l, b], [r, t])
+    }
+}
+
+impl Atlas {
+    /// A new, empty atlas with the given dimensions.
+    fn new(dimensions: [u32; 2]) -> Self {
+        let [w, h] = dimensions;
+        Atlas {
+            pixels: vec![0; w as usize * h as usize * 4],
+            shelves: Vec::new(),
+        }
+    }
+
+    /// The RGBA pixel data of the atlas, row by row starting from the top.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Reserve space for an image of the given dimensions, returning its top left corner.
+    fn reserve(&mut self, atlas_dimensions: [u32; 2], dimensions: [u32; 2]) -> Option<[u32; 2]> {
+        let [atlas_w, atlas_h] = atlas_dimensions;
+        let [w, h] = dimensions;
+
+        // Find the existing shelf with room for the image that wastes the least height.
+        let best_shelf = self
+            .shelves
+            .iter_mut()
+            .filter(|shelf| h <= shelf.height && shelf.next_x + w <= atlas_w)
+            .min_by_key(|shelf| shelf.height - h);
+        if let Some(shelf) = best_shelf {
+            let x = shelf.next_x;
+            shelf.next_x += w;
+            return Some([x, shelf.y]);
+        }
+
+        // Otherwise, start a new shelf below the last.
+        let y = self
+            .shelves
+            .last()
+            .map_or(0, |shelf| shelf.y + shelf.height);
+        if y + h > atlas_h {
+            return None;
+        }
+        self.shelves.push(Shelf {
+            y: y,
+            height: h,
+            next_x: w,
+        });
+        Some([0, y])
+    }
+}
+
+impl std::fmt::Display for PackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PackError::TooLarge { dimensions: [w, h] } => {
+                write!(f, "the {}x{} image is larger than the atlas", w, h)
+            }
+            PackError::InvalidPixelData { expected, actual } => write!(
+                f,
+                "expected {} bytes of RGBA pixel data but found {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PackError {}
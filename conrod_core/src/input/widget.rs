@@ -186,6 +186,23 @@ impl<'a> Widget<'a> {
         None
     }
 
+    /// Whether or not the mouse has moved over the widget since the last update.
+    ///
+    /// Only the top-most widget under the mouse is considered to be under it, so moving onto one
+    /// of the widget's children is considered to leave the widget.
+    pub fn mouse_entered(&self) -> bool {
+        self.global.start.widget_under_mouse != Some(self.idx)
+            && self.global.current.widget_under_mouse == Some(self.idx)
+    }
+
+    /// Whether or not the mouse has moved off of the widget since the last update.
+    ///
+    /// See `mouse_entered` for the widgets that are considered to be under the mouse.
+    pub fn mouse_exited(&self) -> bool {
+        self.global.start.widget_under_mouse == Some(self.idx)
+            && self.global.current.widget_under_mouse != Some(self.idx)
+    }
+
     /// Produces an iterator yielding all events that are relevant to a specific widget.
     ///
     /// All events provided by this Iterator will be filtered in accordance with input capturing. For
@@ -263,6 +280,44 @@ impl<'a> Widget<'a> {
             events: self.events(),
         }
     }
+
+    /// The sum of all `Scroll` events yielded by the `Events` iterator along both axes.
+    ///
+    /// The `modifiers` are those of the last `Scroll` event. Returns `None` if there were no
+    /// `Scroll` events.
+    pub fn scroll(&self) -> Option<event::Scroll> {
+        self.scrolls().last().map(|last| event::Scroll {
+            x: self.scroll_x().unwrap_or(0.0),
+            y: self.scroll_y().unwrap_or(0.0),
+            modifiers: last.modifiers,
+        })
+    }
+
+    /// The sum of all `Scroll` events yielded by the `Events` iterator along the *x* axis.
+    ///
+    /// Returns `None` if there was no scrolling along the *x* axis, e.g. if a trackpad was only
+    /// scrolled vertically.
+    pub fn scroll_x(&self) -> Option<f64> {
+        sum_scrolls(self.scrolls().map(|scroll| scroll.x))
+    }
+
+    /// The sum of all `Scroll` events yielded by the `Events` iterator along the *y* axis.
+    ///
+    /// Returns `None` if there was no scrolling along the *y* axis, e.g. if a trackpad was only
+    /// scrolled horizontally.
+    pub fn scroll_y(&self) -> Option<f64> {
+        sum_scrolls(self.scrolls().map(|scroll| scroll.y))
+    }
+}
+
+// Sum the given scroll deltas along a single axis, ignoring those with no movement.
+fn sum_scrolls<I>(deltas: I) -> Option<f64>
+where
+    I: Iterator<Item = f64>,
+{
+    deltas
+        .filter(|&delta| delta != 0.0)
+        .fold(None, |sum, delta| Some(sum.unwrap_or(0.0) + delta))
 }
 
 impl<'a> Mouse<'a> {
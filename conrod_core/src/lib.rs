@@ -16,6 +16,8 @@ extern crate fnv;
 extern crate input as piston_input;
 extern crate num;
 extern crate rusttype;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 pub use border::{Borderable, Bordering};
 pub use color::{Color, Colorable};
@@ -23,6 +23,7 @@ pub trait ImageDimensions {
 pub struct Mesh {
     glyph_cache: GlyphCache,
     glyph_cache_pixel_buffer: Vec<u8>,
+    glyph_cache_regions: Vec<rt::Rect<u32>>,
     commands: Vec<PreparedCommand>,
     vertices: Vec<Vertex>,
     positioned_glyphs: Vec<text::PositionedGlyph>,
@@ -93,6 +94,10 @@ pub struct Vertex {
 ///
 /// Provides information on whether or not the glyph cache has been updated and requires
 /// re-uploading to the GPU.
+///
+/// The regions of the glyph cache that were written to may be retrieved via the
+/// `Mesh::glyph_cache_regions` method, allowing backends to upload only the pixel data that
+/// changed.
 #[allow(missing_copy_implementations)]
 pub struct Fill {
     /// Whether or not the glyph cache pixel data should be written to the GPU.
@@ -137,12 +142,14 @@ impl Mesh {
             .build()
             .into();
         let glyph_cache_pixel_buffer = vec![0u8; gc_width as usize * gc_height as usize];
+        let glyph_cache_regions = vec![];
         let commands = vec![];
         let vertices = vec![];
         let positioned_glyphs = vec![];
         Mesh {
             glyph_cache,
             glyph_cache_pixel_buffer,
+            glyph_cache_regions,
             commands,
             vertices,
             positioned_glyphs,
@@ -171,6 +178,7 @@ impl Mesh {
         let Mesh {
             ref mut glyph_cache,
             ref mut glyph_cache_pixel_buffer,
+            ref mut glyph_cache_regions,
             ref mut commands,
             ref mut vertices,
             ref mut positioned_glyphs,
@@ -178,6 +186,7 @@ impl Mesh {
 
         commands.clear();
         vertices.clear();
+        glyph_cache_regions.clear();
 
         enum State {
             Image { image_id: image::Id, start: usize },
@@ -379,31 +388,32 @@ impl Mesh {
                             dst_ix += glyph_cache_w;
                             src_ix += width;
                         }
+                        glyph_cache_regions.push(rect);
                         glyph_cache_requires_upload = true;
                     })?;
 
                     let color = gamma_srgb_to_linear(color.to_fsa());
                     let cache_id = font_id.index();
-                    let origin = rt::point(0.0, 0.0);
-
-                    // A closure to convert RustType rects to GL rects
-                    let to_vk_rect = |screen_rect: rt::Rect<i32>| rt::Rect {
-                        min: origin
-                            + (rt::vector(
-                                screen_rect.min.x as f32 / viewport_w as f32 - 0.5,
-                                screen_rect.min.y as f32 / viewport_h as f32 - 0.5,
-                            )) * 2.0,
-                        max: origin
-                            + (rt::vector(
-                                screen_rect.max.x as f32 / viewport_w as f32 - 0.5,
-                                screen_rect.max.y as f32 / viewport_h as f32 - 0.5,
-                            )) * 2.0,
+
+                    // A closure to convert pixel coordinates to GL coordinates.
+                    let to_vk_point = |[x, y]: [f32; 2]| {
+                        [
+                            (x / viewport_w as f32 - 0.5) * 2.0,
+                            (y / viewport_h as f32 - 0.5) * 2.0,
+                        ]
                     };
 
                     for g in positioned_glyphs.drain(..) {
                         if let Ok(Some((uv_rect, screen_rect))) = glyph_cache.rect_for(cache_id, &g)
                         {
-                            let vk_rect = to_vk_rect(screen_rect);
+                            // The corners are rotated for vertical text.
+                            let corners = text.glyph_corners(dpi_factor as f32, screen_rect);
+                            let [bl, tl, tr, br] = [
+                                to_vk_point(corners[0]),
+                                to_vk_point(corners[1]),
+                                to_vk_point(corners[2]),
+                                to_vk_point(corners[3]),
+                            ];
                             let v = |p, t| Vertex {
                                 position: p,
                                 tex_coords: t,
@@ -411,30 +421,12 @@ impl Mesh {
                                 mode: MODE_TEXT,
                             };
                             let mut push_v = |p, t| vertices.push(v(p, t));
-                            push_v(
-                                [vk_rect.min.x, vk_rect.max.y],
-                                [uv_rect.min.x, uv_rect.max.y],
-                            );
-                            push_v(
-                                [vk_rect.min.x, vk_rect.min.y],
-                                [uv_rect.min.x, uv_rect.min.y],
-                            );
-                            push_v(
-                                [vk_rect.max.x, vk_rect.min.y],
-                                [uv_rect.max.x, uv_rect.min.y],
-                            );
-                            push_v(
-                                [vk_rect.max.x, vk_rect.min.y],
-                                [uv_rect.max.x, uv_rect.min.y],
-                            );
-                            push_v(
-                                [vk_rect.max.x, vk_rect.max.y],
-                                [uv_rect.max.x, uv_rect.max.y],
-                            );
-                            push_v(
-                                [vk_rect.min.x, vk_rect.max.y],
-                                [uv_rect.min.x, uv_rect.max.y],
-                            );
+                            push_v(bl, [uv_rect.min.x, uv_rect.max.y]);
+                            push_v(tl, [uv_rect.min.x, uv_rect.min.y]);
+                            push_v(tr, [uv_rect.max.x, uv_rect.min.y]);
+                            push_v(tr, [uv_rect.max.x, uv_rect.min.y]);
+                            push_v(br, [uv_rect.max.x, uv_rect.max.y]);
+                            push_v(bl, [uv_rect.min.x, uv_rect.max.y]);
                         }
                     }
                 }
@@ -443,6 +435,7 @@ impl Mesh {
                     image_id,
                     color,
                     source_rect,
+                    rotation,
                 } => {
                     let image_ref = match image_map.get(&image_id) {
                         None => continue,
@@ -496,7 +489,7 @@ impl Mesh {
                         None => (0.0, 1.0, 1.0, 0.0),
                     };
 
-                    let v = |x, y, t| {
+                    let v = |[x, y]: [Scalar; 2], t| {
                         // Convert from conrod Scalar range to normalised range -1.0 to 1.0.
                         let x = (x * dpi_factor / half_viewport_w) as f32;
                         let y = -((y * dpi_factor / half_viewport_h) as f32);
@@ -508,20 +501,20 @@ impl Mesh {
                         }
                     };
 
-                    let mut push_v = |x, y, t| vertices.push(v(x, y, t));
+                    let mut push_v = |p, t| vertices.push(v(p, t));
 
-                    // Swap bottom and top to suit reversed vulkan coords.
-                    let (l, r, b, t) = rect.l_r_b_t();
+                    // The corners of the image, rotated about its middle.
+                    let [bl, tl, tr, br] = render::rotated_corners(rect, rotation);
 
                     // Bottom left triangle.
-                    push_v(l, t, [uv_l, uv_t]);
-                    push_v(r, b, [uv_r, uv_b]);
-                    push_v(l, b, [uv_l, uv_b]);
+                    push_v(tl, [uv_l, uv_t]);
+                    push_v(br, [uv_r, uv_b]);
+                    push_v(bl, [uv_l, uv_b]);
 
                     // Top right triangle.
-                    push_v(l, t, [uv_l, uv_t]);
-                    push_v(r, b, [uv_r, uv_b]);
-                    push_v(r, t, [uv_r, uv_t]);
+                    push_v(tl, [uv_l, uv_t]);
+                    push_v(br, [uv_r, uv_b]);
+                    push_v(tr, [uv_r, uv_t]);
                 }
 
                 // We have no special case widgets to handle.
@@ -554,6 +547,33 @@ impl Mesh {
         &self.glyph_cache_pixel_buffer
     }
 
+    /// The regions of the glyph cache that were written to during the last call to `fill`.
+    ///
+    /// Each region is given in pixel coordinates of the glyph cache, where `[0, 0]` is the top
+    /// left corner. Glyphs that were already cached by a previous call to `fill` are not written
+    /// again, so re-filling identical text produces no regions.
+    pub fn glyph_cache_regions(&self) -> &[rt::Rect<u32>] {
+        &self.glyph_cache_regions
+    }
+
+    /// The pixel data within the given `region` of the glyph cache, row by row.
+    ///
+    /// This is useful for uploading only the regions yielded by `glyph_cache_regions`.
+    pub fn glyph_cache_region_pixels<'a>(
+        &'a self,
+        region: rt::Rect<u32>,
+    ) -> impl Iterator<Item = u8> + 'a {
+        let (glyph_cache_w, _) = self.glyph_cache.dimensions();
+        let (glyph_cache_w, x) = (glyph_cache_w as usize, region.min.x as usize);
+        let width = region.width() as usize;
+        (region.min.y as usize..region.max.y as usize).flat_map(move |y| {
+            let start = y * glyph_cache_w + x;
+            self.glyph_cache_pixel_buffer[start..start + width]
+                .iter()
+                .cloned()
+        })
+    }
+
     /// Produce an `Iterator` yielding `Command`s.
     ///
     /// These commands describe the order in which unique draw commands and scizzor updates should
@@ -611,13 +631,6 @@ impl From<text::GlyphCache<'static>> for GlyphCache {
 }
 
 fn gamma_srgb_to_linear(c: [f32; 4]) -> [f32; 4] {
-    fn component(f: f32) -> f32 {
-        // Taken from https://github.com/PistonDevelopers/graphics/src/color.rs#L42
-        if f <= 0.04045 {
-            f / 12.92
-        } else {
-            ((f + 0.055) / 1.055).powf(2.4)
-        }
-    }
+    let component = color::srgb_to_linear;
     [component(c[0]), component(c[1]), component(c[2]), c[3]]
 }
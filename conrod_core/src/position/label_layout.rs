@@ -0,0 +1,147 @@
+//! A de-collision pass for laying out automatically positioned labels without overlap.
+
+use super::{Axis, Range, Rect, Scalar};
+
+/// Nudges a set of labels along a single **Axis** so that none of them overlap, while keeping each
+/// as near as possible to its desired position.
+///
+/// This is useful for labels that are positioned automatically relative to some anchor, e.g. the
+/// data labels of a chart, where neighbouring labels may otherwise be drawn on top of one another.
+///
+/// Labels only collide with those that they overlap along the other axis. Colliding labels keep
+/// their order along the `axis` and are separated by at least the `gap`. Each run of labels that
+/// would otherwise overlap is moved as a single block, centred so that the sum of the squared
+/// displacements of its labels is minimised.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LabelLayout {
+    /// The axis along which labels are nudged.
+    pub axis: Axis,
+    /// The minimum distance between two colliding labels along the `axis`.
+    pub gap: Scalar,
+}
+
+// A run of labels that are laid out end to end, separated by the gap.
+struct Block {
+    // The indices of the labels in the order in which they are laid out.
+    labels: Vec<usize>,
+    // The sum of each label's desired start minus its offset from the start of the block.
+    desired_starts: Scalar,
+    // The length of the block from the start of its first label to the end of its last.
+    len: Scalar,
+}
+
+impl Block {
+    // The start of the block that minimises the squared displacement of its labels.
+    fn start(&self) -> Scalar {
+        self.desired_starts / self.labels.len() as Scalar
+    }
+}
+
+impl LabelLayout {
+    /// A layout that nudges labels along the given `axis`, separating them by the given `gap`.
+    pub fn new(axis: Axis, gap: Scalar) -> Self {
+        LabelLayout {
+            axis: axis,
+            gap: gap,
+        }
+    }
+
+    /// Lay out the labels with the given `desired` rectangles.
+    ///
+    /// Returns the adjusted rectangle for each label in the same order. Labels that do not collide
+    /// with any other are left where they are.
+    pub fn layout(&self, desired: &[Rect]) -> Vec<Rect> {
+        let mut adjusted = desired.to_vec();
+
+        // Group the labels into lanes whose ranges along the other axis overlap. Labels in
+        // separate lanes can never collide.
+        let mut indices: Vec<usize> = (0..desired.len()).collect();
+        indices.sort_by(|&a, &b| {
+            let (a, b) = (self.across(&desired[a]), self.across(&desired[b]));
+            a.start.partial_cmp(&b.start).unwrap()
+        });
+        let mut lane = Vec::new();
+        let mut lane_end = None;
+        for idx in indices {
+            let across = self.across(&desired[idx]);
+            match lane_end {
+                Some(end) if across.start >= end => {
+                    self.separate(&mut lane, &mut adjusted);
+                    lane.clear();
+                    lane_end = Some(across.end);
+                }
+                Some(end) => lane_end = Some(across.end.max(end)),
+                None => lane_end = Some(across.end),
+            }
+            lane.push(idx);
+        }
+        self.separate(&mut lane, &mut adjusted);
+
+        adjusted
+    }
+
+    // The range of the given rectangle along the axis on which labels are nudged.
+    fn along(&self, rect: &Rect) -> Range {
+        match self.axis {
+            Axis::X => rect.x.undirected(),
+            Axis::Y => rect.y.undirected(),
+        }
+    }
+
+    // The range of the given rectangle along the other axis.
+    fn across(&self, rect: &Rect) -> Range {
+        match self.axis {
+            Axis::X => rect.y.undirected(),
+            Axis::Y => rect.x.undirected(),
+        }
+    }
+
+    // Separate the labels at the given indices along the axis.
+    fn separate(&self, lane: &mut [usize], rects: &mut [Rect]) {
+        lane.sort_by(|&a, &b| {
+            let (a, b) = (self.along(&rects[a]), self.along(&rects[b]));
+            a.middle().partial_cmp(&b.middle()).unwrap()
+        });
+
+        // Merge each label into the preceding block for as long as the two would overlap.
+        let mut blocks: Vec<Block> = Vec::new();
+        for &idx in lane.iter() {
+            let along = self.along(&rects[idx]);
+            let mut block = Block {
+                labels: vec![idx],
+                desired_starts: along.start,
+                len: along.len(),
+            };
+            while let Some(prev) = blocks.pop() {
+                if prev.start() + prev.len + self.gap <= block.start() {
+                    blocks.push(prev);
+                    break;
+                }
+                let offset = prev.len + self.gap;
+                let mut labels = prev.labels;
+                labels.extend(block.labels.iter().cloned());
+                block = Block {
+                    desired_starts: prev.desired_starts + block.desired_starts
+                        - offset * block.labels.len() as Scalar,
+                    labels: labels,
+                    len: offset + block.len,
+                };
+            }
+            blocks.push(block);
+        }
+
+        // Lay out the labels of each block end to end.
+        for block in blocks {
+            let mut start = block.start();
+            for idx in block.labels {
+                let along = self.along(&rects[idx]);
+                let shift = start - along.start;
+                rects[idx] = match self.axis {
+                    Axis::X => rects[idx].shift_x(shift),
+                    Axis::Y => rects[idx].shift_y(shift),
+                };
+                start += along.len() + self.gap;
+            }
+        }
+    }
+}
@@ -3,10 +3,12 @@
 use widget;
 use Ui;
 
+pub use self::label_layout::LabelLayout;
 pub use self::range::{Edge, Range};
 pub use self::rect::{Corner, Rect};
 //pub use self::matrix::Matrix;
 
+pub mod label_layout;
 //pub mod matrix;
 pub mod range;
 pub mod rect;
@@ -42,6 +44,16 @@ pub enum Axis {
     Y,
 }
 
+/// The direction in which some widget or its parts run, e.g. a `Slider`'s track, a `ProgressBar`'s
+/// fill, a `Splitter`'s divider or the lines of a `Text`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    /// Running along the *x* axis.
+    Horizontal,
+    /// Running along the *y* axis.
+    Vertical,
+}
+
 /// Some **Position** of some **Widget** along a single axis.
 ///
 /// **Position**s for both the *x* and *y* axes are stored internally within the
@@ -137,6 +149,27 @@ pub enum Dimension {
     KidAreaOf(widget::Id, Option<Scalar>),
 }
 
+impl Dimension {
+    /// Resolve the **Dimension** to an absolute length along the given axis.
+    ///
+    /// Returns `None` if the **Dimension** is relative to a widget that does not exist.
+    pub fn length(self, axis: Axis, ui: &Ui) -> Option<Scalar> {
+        let len = |rect: Rect| match axis {
+            Axis::X => rect.w(),
+            Axis::Y => rect.h(),
+        };
+        match self {
+            Dimension::Absolute(length) => Some(length),
+            Dimension::Of(idx, maybe_pad) => ui
+                .rect_of(idx)
+                .map(|rect| len(rect) - maybe_pad.unwrap_or(0.0) * 2.0),
+            Dimension::KidAreaOf(idx, maybe_pad) => ui
+                .kid_area_of(idx)
+                .map(|rect| len(rect) - maybe_pad.unwrap_or(0.0) * 2.0),
+        }
+    }
+}
+
 /// Widgets that are positionable.
 ///
 /// A **Position** is stored internally within the **widget::CommonBuilder** type, allowing all
@@ -693,6 +726,10 @@ pub trait Positionable: Sized {
     ///// Rendering Depth (aka Z axis) /////
 
     /// The depth at which the widget should be rendered relatively to its sibling widgets.
+    ///
+    /// Widgets with a lesser depth are drawn in front of (and picked before) siblings with a
+    /// greater depth. Siblings of equal depth are drawn in the order in which they were
+    /// instantiated, so that the last is in front. The default depth is `0.0`.
     fn depth(self, depth: Depth) -> Self;
 
     /// Return the depth.
@@ -717,6 +754,55 @@ pub trait Sizeable: Sized {
 
     // Provided defaults.
 
+    /// Maintain the given ratio of width over height, e.g. `16.0 / 9.0` for widescreen video.
+    ///
+    /// When only one dimension is given, the other is computed from it. When neither is given, the
+    /// height is computed from the default width. If both the width and height are given, they
+    /// take precedence, the ratio is ignored and a warning is printed when the widget is first
+    /// instantiated.
+    ///
+    /// By default the ratio is ignored. All **Widget**s maintain it, panicking if it is not a
+    /// finite number greater than `0.0`.
+    fn aspect_ratio(self, _w_over_h: Scalar) -> Self {
+        self
+    }
+
+    /// Limit the resolved width to no less than the given length.
+    ///
+    /// The limit is applied after the width is resolved, e.g. from the width of another widget.
+    ///
+    /// By default the limit is ignored. All **Widget**s apply it.
+    fn min_width(self, _min: Scalar) -> Self {
+        self
+    }
+
+    /// Limit the resolved width to no greater than the given length.
+    ///
+    /// The limit is applied after the width is resolved, e.g. from the width of another widget.
+    ///
+    /// By default the limit is ignored. All **Widget**s apply it.
+    fn max_width(self, _max: Scalar) -> Self {
+        self
+    }
+
+    /// Limit the resolved height to no less than the given length.
+    ///
+    /// The limit is applied after the height is resolved, e.g. from the height of another widget.
+    ///
+    /// By default the limit is ignored. All **Widget**s apply it.
+    fn min_height(self, _min: Scalar) -> Self {
+        self
+    }
+
+    /// Limit the resolved height to no greater than the given length.
+    ///
+    /// The limit is applied after the height is resolved, e.g. from the height of another widget.
+    ///
+    /// By default the limit is ignored. All **Widget**s apply it.
+    fn max_height(self, _max: Scalar) -> Self {
+        self
+    }
+
     /// Set the absolute width for the widget.
     fn w(self, w: Scalar) -> Self {
         self.x_dimension(Dimension::Absolute(w))
@@ -806,24 +892,12 @@ pub trait Sizeable: Sized {
 
     /// Get the absolute width of the widget as a Scalar value.
     fn get_w(&self, ui: &Ui) -> Option<Scalar> {
-        match self.get_x_dimension(ui) {
-            Dimension::Absolute(width) => Some(width),
-            Dimension::Of(idx, None) => ui.w_of(idx),
-            Dimension::Of(idx, Some(pad)) => ui.w_of(idx).map(|w| w - pad * 2.0),
-            Dimension::KidAreaOf(idx, None) => ui.kid_area_of(idx).map(|r| r.w()),
-            Dimension::KidAreaOf(idx, Some(pad)) => ui.kid_area_of(idx).map(|r| r.w() - pad * 2.0),
-        }
+        self.get_x_dimension(ui).length(Axis::X, ui)
     }
 
     /// Get the height of the widget.
     fn get_h(&self, ui: &Ui) -> Option<Scalar> {
-        match self.get_y_dimension(ui) {
-            Dimension::Absolute(height) => Some(height),
-            Dimension::Of(idx, None) => ui.h_of(idx),
-            Dimension::Of(idx, Some(pad)) => ui.h_of(idx).map(|w| w - pad * 2.0),
-            Dimension::KidAreaOf(idx, None) => ui.kid_area_of(idx).map(|r| r.h()),
-            Dimension::KidAreaOf(idx, Some(pad)) => ui.kid_area_of(idx).map(|r| r.h() - pad * 2.0),
-        }
+        self.get_y_dimension(ui).length(Axis::Y, ui)
     }
 
     /// The dimensions for the widget.
@@ -11,7 +11,7 @@
 use color;
 use graph::{self, Graph};
 use image;
-use position::{Align, Dimensions};
+use position::{Align, Dimensions, Orientation, Range};
 use std;
 use text;
 use theme::Theme;
@@ -36,6 +36,10 @@ pub struct Primitives<'a> {
     window_rect: Rect,
     /// A buffer to use for triangulating polygons and lines for the `Triangles`.
     triangles: Vec<Triangle<Point>>,
+    /// A buffer to use for applying opacity to multi-colored `Triangles`.
+    colored_triangles: Vec<Triangle<ColoredPoint>>,
+    /// The remaining slices of the most recent nine-slice or tiled `Image`, in reverse order.
+    image_slices: Vec<Primitive<'a>>,
 }
 
 /// An owned alternative to the `Primitives` type.
@@ -72,9 +76,21 @@ impl<'a> PrimitiveWalker for WalkOwnedPrimitives<'a> {
     }
 }
 
+impl<'a> Iterator for WalkOwnedPrimitives<'a> {
+    type Item = Primitive<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        WalkOwnedPrimitives::next(self)
+    }
+}
+
 /// Data required for rendering a single primitive widget.
 pub struct Primitive<'a> {
     /// The id of the widget within the widget graph.
+    ///
+    /// This is the id of the primitive widget from which this `Primitive` originates, allowing
+    /// backends to map each drawn `Primitive` back to its widget, e.g. by rendering
+    /// `id.index()` to an offscreen buffer for pixel-perfect picking. The widget for which the
+    /// primitive provides graphics (if any) may be found via `Graph::graphic_parent`.
     pub id: widget::Id,
     /// State and style for this primitive widget.
     pub kind: PrimitiveKind<'a>,
@@ -122,6 +138,11 @@ pub enum PrimitiveKind<'a> {
         color: Option<Color>,
         /// The area of the texture that will be drawn to the `Image`'s `Rect`.
         source_rect: Option<Rect>,
+        /// The anticlockwise rotation of the `Image` about the middle of its `Rect` in radians.
+        ///
+        /// Backends should draw the `Image` at the `rotated_corners` of its `Rect`. Note that the
+        /// primitive's `rect` and `scizzor`, along with culling, are those of the unrotated `Rect`.
+        rotation: Scalar,
     },
 
     /// A single block of `Text`, produced by the primitive `Text` widget.
@@ -153,6 +174,7 @@ pub enum PrimitiveKind<'a> {
 ///
 /// We produce this type rather than the `&[PositionedGlyph]`s directly so that we can properly
 /// handle "HiDPI" scales when caching glyphs.
+#[derive(Copy, Clone)]
 pub struct Text<'a> {
     window_dim: Dimensions,
     text: &'a str,
@@ -163,6 +185,7 @@ pub struct Text<'a> {
     justify: text::Justify,
     y_align: Align,
     line_spacing: Scalar,
+    orientation: Orientation,
 }
 
 #[derive(Clone)]
@@ -189,6 +212,7 @@ enum OwnedPrimitiveKind {
         image_id: image::Id,
         color: Option<Color>,
         source_rect: Option<Rect>,
+        rotation: Scalar,
     },
     Text {
         color: Color,
@@ -208,6 +232,7 @@ struct OwnedText {
     justify: text::Justify,
     y_align: Align,
     line_spacing: Scalar,
+    orientation: Orientation,
 }
 
 /// An iterator-like type for yielding `Primitive`s from an `OwnedPrimitives`.
@@ -269,6 +294,45 @@ impl<'a> Text<'a> {
             font.layout(line, scale, point)
         })
     }
+
+    /// The direction in which the lines of text run.
+    ///
+    /// The `PositionedGlyph`s are always laid out horizontally. For `Vertical` text, backends
+    /// should draw each glyph at the `glyph_corners` produced for its screen rect.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// The corners of the quad at which a glyph with the given pixel `screen_rect` should be
+    /// drawn, in the order bottom left, top left, top right, bottom right of the glyph.
+    ///
+    /// For `Vertical` text, the corners are rotated a quarter turn anticlockwise about the middle
+    /// of the text.
+    pub fn glyph_corners(
+        &self,
+        dpi_factor: f32,
+        screen_rect: text::rt::Rect<i32>,
+    ) -> [[f32; 2]; 4] {
+        let (l, r) = (screen_rect.min.x as f32, screen_rect.max.x as f32);
+        let (t, b) = (screen_rect.min.y as f32, screen_rect.max.y as f32);
+        let corners = [[l, b], [l, t], [r, t], [r, b]];
+        match self.orientation {
+            Orientation::Horizontal => corners,
+            Orientation::Vertical => {
+                // Pixel coordinates begin at the top left of the window with *y* pointing down.
+                let dpi_factor = dpi_factor as Scalar;
+                let x = ((self.rect.x() + self.window_dim[0] / 2.0) * dpi_factor) as f32;
+                let y = ((-self.rect.y() + self.window_dim[1] / 2.0) * dpi_factor) as f32;
+                let rotate = |[px, py]: [f32; 2]| [x + (py - y), y - (px - x)];
+                [
+                    rotate(corners[0]),
+                    rotate(corners[1]),
+                    rotate(corners[2]),
+                    rotate(corners[3]),
+                ]
+            }
+        }
+    }
 }
 
 impl<'a> Primitives<'a> {
@@ -288,6 +352,8 @@ impl<'a> Primitives<'a> {
             fonts: fonts,
             window_rect: Rect::from_xy_dim([0.0, 0.0], window_dim),
             triangles: Vec::new(),
+            colored_triangles: Vec::new(),
+            image_slices: Vec::new(),
         }
     }
 
@@ -297,13 +363,29 @@ impl<'a> Primitives<'a> {
             ref mut crop_stack,
             ref mut depth_order,
             ref mut triangles,
+            ref mut colored_triangles,
+            ref mut image_slices,
             graph,
             theme,
             fonts,
             window_rect,
         } = *self;
 
+        // Yield the remaining slices of a nine-slice `Image` before moving on to the next widget.
+        if let Some(slice) = image_slices.pop() {
+            return Some(slice);
+        }
+
         while let Some(widget) = next_widget(depth_order, graph, crop_stack, window_rect) {
+            let (id, scizzor, container) = widget;
+
+            // Skip widgets that are fully transparent, whether by their own opacity or that of a
+            // parent, as there would be nothing to draw.
+            let opacity = graph::algo::cascaded_opacity(graph, id);
+            if opacity <= 0.0 {
+                continue;
+            }
+
             use widget::primitive::point_path::{State as PointPathState, Style as PointPathStyle};
             use widget::primitive::shape::polygon::State as PolygonState;
             use widget::primitive::shape::Style as ShapeStyle;
@@ -313,7 +395,6 @@ impl<'a> Primitives<'a> {
             type TrianglesMultiColorState =
                 widget::triangles::State<Vec<widget::triangles::Triangle<(Point, color::Rgba)>>>;
 
-            let (id, scizzor, container) = widget;
             let rect = container.rect;
 
             fn state_type_id<W>() -> std::any::TypeId
@@ -331,7 +412,7 @@ impl<'a> Primitives<'a> {
                     match *style {
                         ShapeStyle::Fill(_) => {
                             let kind = PrimitiveKind::Rectangle { color: color };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
                         ShapeStyle::Outline(ref line_style) => {
                             let (l, r, b, t) = rect.l_r_b_t();
@@ -351,7 +432,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
                     }
                 }
@@ -368,16 +449,29 @@ impl<'a> Primitives<'a> {
                         color: color,
                         triangles: &state.triangles,
                     };
-                    return Some(new_primitive(id, kind, scizzor, rect));
+                    return Some(new_primitive(id, kind, scizzor, rect, opacity));
                 }
             } else if container.type_id == std::any::TypeId::of::<TrianglesMultiColorState>() {
                 type Style = widget::triangles::MultiColor;
                 if let Some(tris) = container.state_and_style::<TrianglesMultiColorState, Style>() {
                     let graph::UniqueWidgetState { ref state, .. } = *tris;
+                    let triangles = if opacity < 1.0 {
+                        colored_triangles.clear();
+                        colored_triangles.extend(state.triangles.iter().map(|tri| {
+                            let mut tri = *tri;
+                            for &mut (_, ref mut color) in tri.0.iter_mut() {
+                                color.3 *= opacity;
+                            }
+                            tri
+                        }));
+                        &colored_triangles[..]
+                    } else {
+                        &state.triangles[..]
+                    };
                     let kind = PrimitiveKind::TrianglesMultiColor {
-                        triangles: &state.triangles,
+                        triangles: triangles,
                     };
-                    return Some(new_primitive(id, kind, scizzor, rect));
+                    return Some(new_primitive(id, kind, scizzor, rect, opacity));
                 }
             } else if container.type_id == state_type_id::<widget::Oval<widget::oval::Full>>() {
                 if let Some(oval) =
@@ -391,6 +485,20 @@ impl<'a> Primitives<'a> {
                     let points = widget::oval::circumference(rect, state.resolution);
                     let color = style.get_color(theme);
                     match *style {
+                        ShapeStyle::Fill(_) if state.maybe_edge_color.is_some() => {
+                            let edge_color = state.maybe_edge_color.unwrap();
+                            colored_triangles.clear();
+                            colored_triangles.extend(gradient_triangles(
+                                points.triangles(),
+                                color.alpha(opacity).to_rgb(),
+                                edge_color.alpha(opacity).to_rgb(),
+                            ));
+                            let kind = PrimitiveKind::TrianglesMultiColor {
+                                triangles: &colored_triangles[..],
+                            };
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
+                        }
+
                         ShapeStyle::Fill(_) => {
                             let triangles = {
                                 triangles.extend(points.triangles());
@@ -400,7 +508,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
 
                         ShapeStyle::Outline(ref line_style) => {
@@ -418,7 +526,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
                     }
                 }
@@ -438,6 +546,20 @@ impl<'a> Primitives<'a> {
                         .offset_radians(state.section.offset_radians);
                     let color = style.get_color(theme);
                     match *style {
+                        ShapeStyle::Fill(_) if state.maybe_edge_color.is_some() => {
+                            let edge_color = state.maybe_edge_color.unwrap();
+                            colored_triangles.clear();
+                            colored_triangles.extend(gradient_triangles(
+                                points.triangles(),
+                                color.alpha(opacity).to_rgb(),
+                                edge_color.alpha(opacity).to_rgb(),
+                            ));
+                            let kind = PrimitiveKind::TrianglesMultiColor {
+                                triangles: &colored_triangles[..],
+                            };
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
+                        }
+
                         ShapeStyle::Fill(_) => {
                             let triangles = {
                                 triangles.extend(points.triangles());
@@ -447,7 +569,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
 
                         ShapeStyle::Outline(ref line_style) => {
@@ -468,7 +590,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
                     }
                 }
@@ -496,7 +618,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
 
                         ShapeStyle::Outline(ref line_style) => {
@@ -514,7 +636,7 @@ impl<'a> Primitives<'a> {
                                 color: color.to_rgb(),
                                 triangles: &triangles,
                             };
-                            return Some(new_primitive(id, kind, scizzor, rect));
+                            return Some(new_primitive(id, kind, scizzor, rect, opacity));
                         }
                     }
                 }
@@ -540,7 +662,7 @@ impl<'a> Primitives<'a> {
                         color: color.to_rgb(),
                         triangles: triangles,
                     };
-                    return Some(new_primitive(id, kind, scizzor, rect));
+                    return Some(new_primitive(id, kind, scizzor, rect, opacity));
                 }
             } else if container.type_id == std::any::TypeId::of::<PointPathState>() {
                 if let Some(point_path) =
@@ -566,7 +688,7 @@ impl<'a> Primitives<'a> {
                         color: color.to_rgb(),
                         triangles: triangles,
                     };
-                    return Some(new_primitive(id, kind, scizzor, rect));
+                    return Some(new_primitive(id, kind, scizzor, rect, opacity));
                 }
             } else if container.type_id == state_type_id::<widget::Text>() {
                 if let Some(text) = container.unique_widget_state::<widget::Text>() {
@@ -588,7 +710,8 @@ impl<'a> Primitives<'a> {
                     let font_size = style.font_size(theme);
                     let line_spacing = style.line_spacing(theme);
                     let justify = style.justify(theme);
-                    let y_align = Align::End;
+                    let y_align = style.y_align(theme);
+                    let orientation = style.orientation(theme);
 
                     let text = Text {
                         window_dim: window_rect.dim(),
@@ -596,10 +719,11 @@ impl<'a> Primitives<'a> {
                         line_infos: &state.line_infos,
                         font: font,
                         font_size: font_size,
-                        rect: rect,
+                        rect: widget::text::layout_rect(orientation, rect),
                         justify: justify,
                         y_align: y_align,
                         line_spacing: line_spacing,
+                        orientation: orientation,
                     };
 
                     let kind = PrimitiveKind::Text {
@@ -607,7 +731,7 @@ impl<'a> Primitives<'a> {
                         text: text,
                         font_id: font_id,
                     };
-                    return Some(new_primitive(id, kind, scizzor, rect));
+                    return Some(new_primitive(id, kind, scizzor, rect, opacity));
                 }
             } else if container.type_id == state_type_id::<widget::Image>() {
                 use widget::primitive::image::{State, Style};
@@ -617,18 +741,51 @@ impl<'a> Primitives<'a> {
                         ref style,
                     } = *image;
                     let color = style.maybe_color(theme);
+
+                    // Nine-slice and tiled images yield one primitive for each of their slices.
+                    let maybe_slices = match (state.nine_slice, state.tiled, state.src_rect) {
+                        (Some(insets), _, Some(src_rect)) => {
+                            Some(nine_slices(rect, src_rect, insets).to_vec())
+                        }
+                        (None, true, Some(src_rect)) => Some(tiles(rect, src_rect)),
+                        _ => None,
+                    };
+                    if let Some(slices) = maybe_slices {
+                        let mut slices =
+                            slices
+                                .into_iter()
+                                .rev()
+                                .map(|(slice_rect, slice_src_rect)| {
+                                    let kind = PrimitiveKind::Image {
+                                        color: color,
+                                        image_id: state.image_id,
+                                        source_rect: Some(slice_src_rect),
+                                        rotation: 0.0,
+                                    };
+                                    new_primitive(id, kind, scizzor, slice_rect, opacity)
+                                });
+                        match slices.next_back() {
+                            Some(first) => {
+                                image_slices.extend(slices);
+                                return Some(first);
+                            }
+                            None => continue,
+                        }
+                    }
+
                     let kind = PrimitiveKind::Image {
                         color: color,
                         image_id: state.image_id,
                         source_rect: state.src_rect,
+                        rotation: state.rotation,
                     };
-                    return Some(new_primitive(id, kind, scizzor, rect));
+                    return Some(new_primitive(id, kind, scizzor, rect, opacity));
                 }
 
             // Return an `Other` variant for all non-primitive widgets.
             } else {
                 let kind = PrimitiveKind::Other(container);
-                return Some(new_primitive(id, kind, scizzor, rect));
+                return Some(new_primitive(id, kind, scizzor, rect, opacity));
             }
         }
 
@@ -638,6 +795,13 @@ impl<'a> Primitives<'a> {
     /// Collect the `Primitives` list into an owned collection.
     ///
     /// This is useful for sending `Ui` rendering data across threads in an efficient manner.
+    ///
+    /// `Primitives` reuses a single buffer for the triangles of every primitive, so the slices of
+    /// each `Primitive` it yields are only valid until the next call to `next`. The primitives
+    /// yielded by walking the `OwnedPrimitives` instead borrow from the `OwnedPrimitives` itself
+    /// and remain valid as the walk advances, allowing them to be submitted in a deferred or
+    /// threaded manner. The cost is that the triangles and text of every primitive are copied
+    /// into newly allocated buffers each time this is called.
     pub fn owned(mut self) -> OwnedPrimitives {
         let mut primitives = Vec::with_capacity(self.depth_order.len());
         let mut primitive_triangles_multi_color = Vec::new();
@@ -690,11 +854,13 @@ impl<'a> Primitives<'a> {
                     image_id,
                     color,
                     source_rect,
+                    rotation,
                 } => {
                     let kind = OwnedPrimitiveKind::Image {
                         image_id: image_id,
                         color: color,
                         source_rect: source_rect,
+                        rotation: rotation,
                     };
                     primitives.push(new(kind));
                 }
@@ -714,7 +880,7 @@ impl<'a> Primitives<'a> {
                         justify,
                         y_align,
                         line_spacing,
-                        ..
+                        orientation,
                     } = text;
 
                     // Pack the `texts_string`.
@@ -737,6 +903,7 @@ impl<'a> Primitives<'a> {
                         justify: justify,
                         y_align: y_align,
                         line_spacing: line_spacing,
+                        orientation: orientation,
                     };
 
                     let kind = OwnedPrimitiveKind::Text {
@@ -784,7 +951,10 @@ impl OwnedPrimitives {
 
 impl<'a> WalkOwnedPrimitives<'a> {
     /// Yield the next `Primitive` in order or rendering depth, bottom to top.
-    pub fn next(&mut self) -> Option<Primitive> {
+    ///
+    /// The yielded `Primitive` borrows from the `OwnedPrimitives` rather than the walker, so it
+    /// remains valid after the walk advances.
+    pub fn next(&mut self) -> Option<Primitive<'a>> {
         let WalkOwnedPrimitives {
             ref mut primitives,
             triangles_single_color,
@@ -846,6 +1016,7 @@ impl<'a> WalkOwnedPrimitives<'a> {
                             justify,
                             y_align,
                             line_spacing,
+                            orientation,
                         } = *text;
 
                         let text_str = &texts_str[str_byte_range.clone()];
@@ -861,6 +1032,7 @@ impl<'a> WalkOwnedPrimitives<'a> {
                             justify: justify,
                             y_align: y_align,
                             line_spacing: line_spacing,
+                            orientation: orientation,
                         };
 
                         let kind = PrimitiveKind::Text {
@@ -875,11 +1047,13 @@ impl<'a> WalkOwnedPrimitives<'a> {
                         image_id,
                         color,
                         source_rect,
+                        rotation,
                     } => {
                         let kind = PrimitiveKind::Image {
                             image_id: image_id,
                             color: color,
                             source_rect: source_rect,
+                            rotation: rotation,
                         };
                         new(kind)
                     }
@@ -889,8 +1063,188 @@ impl<'a> WalkOwnedPrimitives<'a> {
     }
 }
 
+/// Colors the triangles of an oval so that they blend from the `center` color at the middle of the
+/// oval to the `edge` color at its circumference.
+///
+/// The first point of each triangle yielded by the oval is its middle.
+fn gradient_triangles<I>(
+    triangles: I,
+    center: color::Rgba,
+    edge: color::Rgba,
+) -> impl Iterator<Item = Triangle<ColoredPoint>>
+where
+    I: Iterator<Item = Triangle<Point>>,
+{
+    triangles.map(move |Triangle([a, b, c])| Triangle([(a, center), (b, edge), (c, edge)]))
+}
+
+/// The destination and source `Rect`s of each of the nine slices of a nine-slice `Image`, row by
+/// row from the top left.
+///
+/// The `insets` are given in the order left, right, top, bottom. Insets that exceed the dimensions
+/// of either `Rect` are scaled down to fit.
+fn nine_slices(rect: Rect, source_rect: Rect, insets: [Scalar; 4]) -> [(Rect, Rect); 9] {
+    // Split the given range into three at the given insets from its start and end.
+    fn split(range: Range, start: Scalar, end: Scalar) -> [Range; 3] {
+        let range = range.undirected();
+        let (start, end) = (start.max(0.0), end.max(0.0));
+        let scale = if start + end > range.len() {
+            range.len() / (start + end)
+        } else {
+            1.0
+        };
+        let (a, b) = (range.start + start * scale, range.end - end * scale);
+        [
+            Range::new(range.start, a),
+            Range::new(a, b),
+            Range::new(b, range.end),
+        ]
+    }
+
+    let [left, right, top, bottom] = insets;
+    let (xs, ys) = (split(rect.x, left, right), split(rect.y, bottom, top));
+    let (src_xs, src_ys) = (
+        split(source_rect.x, left, right),
+        split(source_rect.y, bottom, top),
+    );
+    let mut slices = [(rect, source_rect); 9];
+    for (i, slice) in slices.iter_mut().enumerate() {
+        // The rows begin at the top, whereas the split ranges begin at the bottom.
+        let (col, row) = (i % 3, 2 - i / 3);
+        let slice_rect = Rect {
+            x: xs[col],
+            y: ys[row],
+        };
+        let slice_src_rect = Rect {
+            x: src_xs[col],
+            y: src_ys[row],
+        };
+        *slice = (slice_rect, slice_src_rect);
+    }
+    slices
+}
+
+/// The destination and source `Rect`s of each tile of a tiled `Image`, row by row from the top
+/// left.
+///
+/// Each tile has the dimensions of the `source_rect`, with those of the final row and column
+/// cropped to fit within `rect`. No tiles are returned for a zero-sized `source_rect`. Should
+/// more than `widget::image::MAX_TILES` be required, the tiles are enlarged by a power of two until they
+/// fit.
+fn tiles(rect: Rect, source_rect: Rect) -> Vec<(Rect, Rect)> {
+    let (w, h) = rect.w_h();
+    let (src_w, src_h) = source_rect.w_h();
+    if src_w <= 0.0 || src_h <= 0.0 {
+        return Vec::new();
+    }
+    let mut scale = 1.0;
+    let (mut cols, mut rows);
+    loop {
+        cols = (w / (src_w * scale)).ceil() as usize;
+        rows = (h / (src_h * scale)).ceil() as usize;
+        if cols.saturating_mul(rows) <= widget::image::MAX_TILES {
+            break;
+        }
+        scale *= 2.0;
+    }
+    let (tile_w, tile_h) = (src_w * scale, src_h * scale);
+    let [l, t] = rect.top_left();
+    let [src_l, src_t] = source_rect.top_left();
+    let mut tiles = Vec::with_capacity(cols * rows);
+    for row in 0..rows {
+        let top = t - row as Scalar * tile_h;
+        // The final row only shows the top of the image.
+        let h = tile_h.min(top - rect.bottom());
+        for col in 0..cols {
+            let left = l + col as Scalar * tile_w;
+            // The final column only shows the left of the image.
+            let w = tile_w.min(rect.right() - left);
+            let tile_rect = Rect {
+                x: Range::new(left, left + w),
+                y: Range::new(top - h, top),
+            };
+            let tile_src_rect = Rect {
+                x: Range::new(src_l, src_l + w / scale),
+                y: Range::new(src_t - h / scale, src_t),
+            };
+            tiles.push((tile_rect, tile_src_rect));
+        }
+    }
+    tiles
+}
+
+/// The corners of the given `rect` once rotated anticlockwise by `rotation` radians about its
+/// middle, in the order bottom left, top left, top right, bottom right of the unrotated `rect`.
+///
+/// This is useful to backends for drawing the rotated quad of a `PrimitiveKind::Image`.
+pub fn rotated_corners(rect: Rect, rotation: Scalar) -> [Point; 4] {
+    let (l, r, b, t) = rect.l_r_b_t();
+    let corners = [[l, b], [l, t], [r, t], [r, b]];
+    if rotation == 0.0 {
+        return corners;
+    }
+    let [x, y] = rect.xy();
+    let (sin, cos) = rotation.sin_cos();
+    let rotate = |[px, py]: Point| {
+        let (dx, dy) = (px - x, py - y);
+        [x + dx * cos - dy * sin, y + dx * sin + dy * cos]
+    };
+    [
+        rotate(corners[0]),
+        rotate(corners[1]),
+        rotate(corners[2]),
+        rotate(corners[3]),
+    ]
+}
+
 /// Simplify the constructor for a `Primitive`.
-fn new_primitive(id: widget::Id, kind: PrimitiveKind, scizzor: Rect, rect: Rect) -> Primitive {
+fn new_primitive(
+    id: widget::Id,
+    kind: PrimitiveKind,
+    scizzor: Rect,
+    rect: Rect,
+    opacity: f32,
+) -> Primitive {
+    // Multiply the alpha of the primitive's color by the widget's cascaded opacity.
+    //
+    // `TrianglesMultiColor` is handled within `Primitives::next` as it requires a buffer.
+    let kind = if opacity < 1.0 {
+        match kind {
+            PrimitiveKind::Rectangle { color } => PrimitiveKind::Rectangle {
+                color: color.alpha(opacity),
+            },
+            PrimitiveKind::TrianglesSingleColor { color, triangles } => {
+                let color::Rgba(r, g, b, a) = color;
+                PrimitiveKind::TrianglesSingleColor {
+                    color: color::Rgba(r, g, b, a * opacity),
+                    triangles: triangles,
+                }
+            }
+            PrimitiveKind::Image {
+                image_id,
+                color,
+                source_rect,
+                rotation,
+            } => PrimitiveKind::Image {
+                image_id: image_id,
+                color: Some(color.unwrap_or(color::WHITE).alpha(opacity)),
+                source_rect: source_rect,
+                rotation: rotation,
+            },
+            PrimitiveKind::Text {
+                color,
+                text,
+                font_id,
+            } => PrimitiveKind::Text {
+                color: color.alpha(opacity),
+                text: text,
+                font_id: font_id,
+            },
+            kind => kind,
+        }
+    } else {
+        kind
+    };
     Primitive {
         id: id,
         kind: kind,
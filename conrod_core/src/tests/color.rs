@@ -1,4 +1,4 @@
-use color::{hsl_to_rgb, rgb_to_hsl};
+use color::{self, hsl_to_rgb, rgb_to_hsl, Color, Rgba};
 use std::cmp::Ordering::Equal;
 
 ///// Test assist code.
@@ -58,3 +58,37 @@ fn rgb_to_hsl_silver() {
     let actual = convert_rgb_to_hsl_to_rgb(r, g, b);
     assert!(compare_rgb_pairs((r, g, b), actual));
 }
+
+#[test]
+fn lerp_should_interpolate_each_channel_in_linear_light() {
+    let approx_eq = |a: Color, b: Color| {
+        let (Rgba(ar, ag, ab, aa), Rgba(br, bg, bb, ba)) = (a.to_rgb(), b.to_rgb());
+        [ar - br, ag - bg, ab - bb, aa - ba]
+            .iter()
+            .all(|d| d.abs() < 1e-5)
+    };
+    let from = color::rgba(0.0, 0.25, 1.0, 1.0);
+    let to = color::rgba(1.0, 0.75, 0.0, 0.0);
+    assert!(approx_eq(color::lerp(from, to, 0.0), from));
+    assert!(approx_eq(color::lerp(from, to, 1.0), to));
+
+    // Halfway between black and white in linear light is roughly 0.735 once sRGB encoded, while
+    // the alpha channel is interpolated as is.
+    let black = color::rgba(0.0, 0.0, 0.0, 1.0);
+    let white = color::rgba(1.0, 1.0, 1.0, 0.0);
+    let Rgba(r, g, b, alpha) = color::lerp(black, white, 0.5).to_rgb();
+    for &channel in &[r, g, b] {
+        assert!((channel - 0.735).abs() < 0.001, "{}", channel);
+    }
+    assert_eq!(alpha, 0.5);
+
+    // Colors described via HSL are interpolated through RGBA too.
+    let red = color::hsl(0.0, 1.0, 0.5);
+    let blue = color::rgb(0.0, 0.0, 1.0);
+    let Rgba(r, g, b, alpha) = color::lerp(red, blue, 0.5).to_rgb();
+    assert!((r - 0.735).abs() < 0.001 && (b - 0.735).abs() < 0.001);
+    assert_eq!((g, alpha), (0.0, 1.0));
+
+    // `t` is clamped.
+    assert!(approx_eq(color::lerp(from, to, 2.0), to));
+}
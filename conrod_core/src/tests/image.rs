@@ -0,0 +1,80 @@
+use image::{AtlasPacker, PackError, Placement};
+
+fn overlaps(a: &Placement, b: &Placement) -> bool {
+    a.atlas == b.atlas && a.x < b.x + b.w && b.x < a.x + a.w && a.y < b.y + b.h && b.y < a.y + a.h
+}
+
+#[test]
+fn packed_images_should_not_overlap_and_should_be_within_the_atlas() {
+    let mut packer = AtlasPacker::new([128, 128]);
+    let sizes = [
+        [16, 16],
+        [8, 24],
+        [32, 8],
+        [20, 12],
+        [16, 16],
+        [10, 30],
+        [40, 6],
+        [12, 12],
+    ];
+    let placements: Vec<_> = sizes.iter().map(|&dim| packer.pack(dim).unwrap()).collect();
+
+    assert_eq!(packer.atlases().len(), 1);
+    for (placement, &[w, h]) in placements.iter().zip(sizes.iter()) {
+        assert_eq!([placement.w, placement.h], [w, h]);
+        assert!(placement.x + placement.w <= 128);
+        assert!(placement.y + placement.h <= 128);
+    }
+    for (i, a) in placements.iter().enumerate() {
+        for b in &placements[i + 1..] {
+            assert!(!overlaps(a, b), "{:?} overlaps {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn packing_should_overflow_to_a_new_atlas_or_error_if_too_large() {
+    let mut packer = AtlasPacker::new([32, 32]);
+    assert_eq!(packer.pack([32, 32]).unwrap().atlas, 0);
+    assert_eq!(packer.pack([8, 8]).unwrap().atlas, 1);
+    assert_eq!(packer.atlases().len(), 2);
+    assert_eq!(
+        packer.pack([33, 8]),
+        Err(PackError::TooLarge {
+            dimensions: [33, 8]
+        })
+    );
+}
+
+#[test]
+fn packed_rgba_pixels_should_be_copied_into_the_atlas() {
+    let mut packer = AtlasPacker::new([4, 4]);
+    packer.pack([4, 1]).unwrap();
+    let red = [255, 0, 0, 255];
+    let pixels: Vec<u8> = red.iter().cloned().cycle().take(2 * 2 * 4).collect();
+    let placement = packer.pack_rgba([2, 2], &pixels).unwrap();
+    assert_eq!((placement.x, placement.y), (0, 1));
+
+    let atlas = packer.atlases()[0].pixels();
+    let pixel = |x: usize, y: usize| &atlas[(y * 4 + x) * 4..(y * 4 + x) * 4 + 4];
+    assert_eq!(pixel(0, 1), &red);
+    assert_eq!(pixel(1, 2), &red);
+    assert_eq!(pixel(2, 1), &[0, 0, 0, 0]);
+
+    // The source rectangle is given with the origin at the bottom left of the atlas.
+    let rect = packer.source_rectangle(placement);
+    assert_eq!(rect.l_r_b_t(), (0.0, 2.0, 1.0, 3.0));
+}
+
+#[test]
+fn packing_images_without_area_should_produce_empty_placements() {
+    let mut packer = AtlasPacker::new([4, 4]);
+    let placement = packer.pack_rgba([0, 3], &[]).unwrap();
+    assert_eq!([placement.w, placement.h], [0, 3]);
+    let placement = packer.pack_rgba([2, 0], &[]).unwrap();
+    assert_eq!([placement.w, placement.h], [2, 0]);
+    assert_eq!(packer.atlases().len(), 1);
+
+    // No space is reserved for them.
+    assert_eq!(packer.pack([4, 4]).unwrap().atlas, 0);
+}
@@ -0,0 +1,132 @@
+use image;
+use mesh::{self, ImageDimensions, Mesh};
+use position::{Rect, Scalar};
+use widget;
+use {Positionable, Sizeable, UiBuilder, Widget};
+
+struct NoImage;
+
+impl ImageDimensions for NoImage {
+    fn dimensions(&self) -> [u32; 2] {
+        [0, 0]
+    }
+}
+
+#[test]
+fn refilling_identical_text_should_not_write_to_the_glyph_cache() {
+    let mut ui = UiBuilder::new([800.0, 600.0]).build();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let text = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Text::new("Hello, glyph cache!")
+            .x_y(0.0, 0.0)
+            .set(text, ui);
+    }
+
+    let mut mesh = Mesh::new();
+    let viewport = Rect::from_xy_dim([0.0, 0.0], [800.0, 600.0]);
+    let image_map = image::Map::<NoImage>::new();
+
+    // The first fill caches each of the glyphs.
+    let fill = mesh.fill(viewport, 1.0, &image_map, ui.draw()).unwrap();
+    assert!(fill.glyph_cache_requires_upload);
+    assert!(!mesh.glyph_cache_regions().is_empty());
+    for &region in mesh.glyph_cache_regions() {
+        let len = (region.width() * region.height()) as usize;
+        assert_eq!(mesh.glyph_cache_region_pixels(region).count(), len);
+    }
+
+    // The glyphs are already cached, so there is nothing to upload.
+    let fill = mesh.fill(viewport, 1.0, &image_map, ui.draw()).unwrap();
+    assert!(!fill.glyph_cache_requires_upload);
+    assert!(mesh.glyph_cache_regions().is_empty());
+}
+
+#[test]
+fn vertical_text_should_be_drawn_rotated_within_its_rect() {
+    // The pixel dimensions of the area covered by the glyphs of the given text.
+    fn text_extent(orientation: widget::text::Orientation) -> ([f32; 2], Rect) {
+        let mut ui = UiBuilder::new([800.0, 600.0]).build();
+        let font_path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+        );
+        ui.fonts.insert_from_file(font_path).unwrap();
+        let text = ui.widget_id_generator().next();
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Text::new("Hello, vertical text!")
+                .orientation(orientation)
+                .x_y(0.0, 0.0)
+                .set(text, ui);
+        }
+
+        let mut mesh = Mesh::new();
+        let viewport = Rect::from_xy_dim([0.0, 0.0], [800.0, 600.0]);
+        let image_map = image::Map::<NoImage>::new();
+        mesh.fill(viewport, 1.0, &image_map, ui.draw()).unwrap();
+        let (mut min, mut max) = ([1.0f32, 1.0], [-1.0f32, -1.0]);
+        for v in mesh.vertices().iter().filter(|v| v.mode == mesh::MODE_TEXT) {
+            for i in 0..2 {
+                min[i] = min[i].min(v.position[i]);
+                max[i] = max[i].max(v.position[i]);
+            }
+        }
+        let extent = [(max[0] - min[0]) * 400.0, (max[1] - min[1]) * 300.0];
+        (extent, ui.rect_of(text).unwrap())
+    }
+
+    let ([w, h], rect) = text_extent(widget::text::Orientation::Horizontal);
+    assert!(w > h && rect.w() > rect.h());
+    let ([vw, vh], vertical_rect) = text_extent(widget::text::Orientation::Vertical);
+    assert!(vh > vw && vertical_rect.h() > vertical_rect.w());
+    assert_eq!(vertical_rect.w_h(), (rect.h(), rect.w()));
+    assert!((vw - h).abs() <= 1.0 && (vh - w).abs() <= 1.0);
+}
+
+#[test]
+fn rotated_images_should_be_drawn_rotated_about_their_middle() {
+    // The pixel dimensions of the area covered by an image rotated by the given radians.
+    fn image_extent(rotation: Scalar) -> [f32; 2] {
+        let mut ui = UiBuilder::new([800.0, 600.0]).build();
+        let mut image_map = image::Map::new();
+        let image_id = image_map.insert(NoImage);
+        let image = ui.widget_id_generator().next();
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Image::new(image_id)
+                .w_h(100.0, 50.0)
+                .x_y(0.0, 0.0)
+                .rotation(rotation)
+                .set(image, ui);
+        }
+
+        let mut mesh = Mesh::new();
+        let viewport = Rect::from_xy_dim([0.0, 0.0], [800.0, 600.0]);
+        mesh.fill(viewport, 1.0, &image_map, ui.draw()).unwrap();
+        let (mut min, mut max) = ([1.0f32, 1.0], [-1.0f32, -1.0]);
+        for v in mesh
+            .vertices()
+            .iter()
+            .filter(|v| v.mode == mesh::MODE_IMAGE)
+        {
+            for i in 0..2 {
+                min[i] = min[i].min(v.position[i]);
+                max[i] = max[i].max(v.position[i]);
+            }
+        }
+        [(max[0] - min[0]) * 400.0, (max[1] - min[1]) * 300.0]
+    }
+
+    let round = |[w, h]: [f32; 2]| [w.round(), h.round()];
+    assert_eq!(round(image_extent(0.0)), [100.0, 50.0]);
+    assert_eq!(
+        round(image_extent(::std::f64::consts::FRAC_PI_2)),
+        [50.0, 100.0]
+    );
+}
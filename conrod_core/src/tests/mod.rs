@@ -1,4 +1,8 @@
 mod color;
 mod global_input;
+mod image;
+mod mesh;
+mod position;
+mod text;
 mod ui;
 mod widget_input;
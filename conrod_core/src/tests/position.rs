@@ -0,0 +1,50 @@
+use position::{Axis, LabelLayout, Range, Rect};
+
+fn rect(x: (f64, f64), y: (f64, f64)) -> Rect {
+    Rect {
+        x: Range::new(x.0, x.1),
+        y: Range::new(y.0, y.1),
+    }
+}
+
+#[test]
+fn overlapping_labels_should_be_separated_by_the_gap_with_minimal_displacement() {
+    let desired = [
+        rect((0.0, 50.0), (10.0, 30.0)),
+        rect((0.0, 50.0), (0.0, 20.0)),
+    ];
+    let adjusted = LabelLayout::new(Axis::Y, 4.0).layout(&desired);
+
+    // The labels keep their order and are separated by exactly the gap.
+    assert_eq!(adjusted[0].bottom() - adjusted[1].top(), 4.0);
+    // The necessary displacement of 14 is shared equally, and neither moves along the x axis.
+    assert_eq!(adjusted[0], desired[0].shift_y(7.0));
+    assert_eq!(adjusted[1], desired[1].shift_y(-7.0));
+}
+
+#[test]
+fn labels_that_do_not_collide_should_be_left_in_place() {
+    let desired = [
+        // Apart along the axis.
+        rect((0.0, 50.0), (0.0, 20.0)),
+        rect((0.0, 50.0), (30.0, 50.0)),
+        // Overlapping along the axis but apart along the other.
+        rect((100.0, 150.0), (0.0, 20.0)),
+        rect((160.0, 210.0), (10.0, 30.0)),
+    ];
+    let adjusted = LabelLayout::new(Axis::Y, 4.0).layout(&desired);
+    assert_eq!(&adjusted[..], &desired[..]);
+}
+
+#[test]
+fn chains_of_colliding_labels_should_be_moved_as_a_single_block() {
+    let desired = [
+        rect((0.0, 20.0), (0.0, 10.0)),
+        rect((10.0, 30.0), (0.0, 10.0)),
+        rect((20.0, 40.0), (0.0, 10.0)),
+    ];
+    let adjusted = LabelLayout::new(Axis::X, 2.0).layout(&desired);
+    assert_eq!(adjusted[0].x, Range::new(-12.0, 8.0));
+    assert_eq!(adjusted[1].x, Range::new(10.0, 30.0));
+    assert_eq!(adjusted[2].x, Range::new(32.0, 52.0));
+}
@@ -0,0 +1,132 @@
+use position::{Align, Rect};
+use text::{self, line};
+use widget;
+
+fn two_line_infos() -> Vec<line::Info> {
+    vec![
+        line::Info {
+            start_byte: 0,
+            start_char: 0,
+            end_break: line::Break::Newline {
+                byte: 3,
+                char: 3,
+                len_bytes: 1,
+            },
+            width: 30.0,
+        },
+        line::Info {
+            start_byte: 4,
+            start_char: 4,
+            end_break: line::Break::End { byte: 7, char: 7 },
+            width: 30.0,
+        },
+    ]
+}
+
+#[test]
+fn vertically_centered_text_block_should_be_in_middle_of_rect() {
+    let font_size = 10;
+    let line_spacing = 2.0;
+    let rect = Rect::from_xy_dim([0.0, 0.0], [100.0, 100.0]);
+    let infos = two_line_infos();
+    let rects: Vec<Rect> = line::rects(
+        infos.into_iter(),
+        font_size,
+        rect,
+        text::Justify::Left,
+        Align::Middle,
+        line_spacing,
+    )
+    .collect();
+
+    assert_eq!(rects.len(), 2);
+    let block_height = text::height(2, font_size, line_spacing);
+    assert_eq!(rects[0].top(), block_height / 2.0);
+    assert_eq!(rects[1].bottom(), -block_height / 2.0);
+}
+
+#[test]
+fn text_widget_y_align_should_default_to_top() {
+    let text = widget::Text::new("foo\nbar");
+    assert_eq!(text.style.y_align, None);
+    let text = text.align_text_y_middle();
+    assert_eq!(text.style.y_align, Some(Align::Middle));
+    let text = text.align_text_bottom();
+    assert_eq!(text.style.y_align, Some(Align::Start));
+}
+
+fn noto_sans() -> text::Font {
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    text::font::from_file(font_path).unwrap()
+}
+
+#[test]
+fn wrapping_by_character_should_break_text_without_whitespace() {
+    let font = noto_sans();
+    let font_size = 16;
+    let max_width = 60.0;
+    let string = "abcdefghijklmnopqrstuvwxyz";
+
+    let by_character: Vec<_> = line::infos(string, &font, font_size)
+        .wrap_by_character(max_width)
+        .collect();
+    assert!(by_character.len() > 1);
+    assert!(by_character.iter().all(|info| info.width <= max_width));
+    let rejoined: String = by_character
+        .iter()
+        .map(|info| &string[info.byte_range()])
+        .collect();
+    assert_eq!(rejoined, string);
+
+    // Each line is given its own rect for laying out its glyphs.
+    let rect = Rect::from_xy_dim([0.0, 0.0], [max_width, 200.0]);
+    let rects = line::rects(
+        by_character.iter().cloned(),
+        font_size,
+        rect,
+        text::Justify::Left,
+        Align::End,
+        1.0,
+    );
+    assert_eq!(rects.count(), by_character.len());
+}
+
+#[test]
+fn wrapping_empty_text_by_character_should_yield_a_single_empty_line() {
+    let font = noto_sans();
+    let infos: Vec<_> = line::infos("", &font, 16).wrap_by_character(60.0).collect();
+    assert_eq!(infos.len(), 1);
+    assert_eq!(infos[0].byte_range(), 0..0);
+    assert_eq!(infos[0].width, 0.0);
+}
+
+#[test]
+fn measure_should_cover_the_widest_line_and_all_lines() {
+    let font = noto_sans();
+    let font_size = 16;
+    let line_spacing = 4.0;
+
+    let [w, h] = text::measure("Hello", &font, font_size, None, line_spacing);
+    assert_eq!(w, line::width("Hello", &font, font_size));
+    assert_eq!(h, text::height(1, font_size, line_spacing));
+
+    let [w, h] = text::measure("foo\nbar baz", &font, font_size, None, line_spacing);
+    assert_eq!(w, line::width("bar baz", &font, font_size));
+    assert_eq!(h, text::height(2, font_size, line_spacing));
+
+    let [_, h] = text::measure("", &font, font_size, None, line_spacing);
+    assert_eq!(h, text::height(1, font_size, line_spacing));
+}
+
+#[test]
+fn measure_should_wrap_by_whitespace_within_the_max_width() {
+    let font = noto_sans();
+    let font_size = 16;
+    let max_width = line::width("Hello,", &font, font_size) + 1.0;
+    let [w, h] = text::measure("Hello, world", &font, font_size, Some(max_width), 0.0);
+    assert_eq!(w, line::width("Hello,", &font, font_size));
+    assert_eq!(h, text::height(2, font_size, 0.0));
+}
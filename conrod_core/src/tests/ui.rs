@@ -1,9 +1,14 @@
+use color;
+use cursor;
 use event::{self, Input};
+use graph;
+use image;
 use input::keyboard::ModifierKey;
 use input::{self, Button, Key, Motion, MouseButton};
-use position::Point;
+use position::{Depth, Point, Rect, Scalar};
+use render;
 use widget;
-use {Color, Colorable, Labelable, Positionable, Sizeable, Ui, UiBuilder, Widget};
+use {Borderable, Color, Colorable, Labelable, Positionable, Sizeable, Ui, UiBuilder, Widget};
 
 ///// Test assist code.
 
@@ -56,10 +61,42 @@ fn to_window_coordinates(xy: Point, ui: &Ui) -> Point {
     [x, y]
 }
 
+// Asserts that the last layout positioned each of the given widgets at the expected rect.
+fn assert_layout_matches(ui: &Ui, expected: &[(widget::Id, Rect)]) {
+    let snapshot = ui.layout_snapshot();
+    let mismatches: Vec<_> = expected
+        .iter()
+        .filter(|&&(id, rect)| snapshot.get(&id) != Some(&rect))
+        .map(|&(id, rect)| (id, rect, snapshot.get(&id).cloned()))
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "layout mismatches (id, expected, actual): {:?}",
+        mismatches
+    );
+}
+
 fn windowless_ui() -> Ui {
     UiBuilder::new([800.0, 600.0]).build()
 }
 
+// Generates `n` new widget ids from the given `Ui`.
+fn new_ids(ui: &mut Ui, n: usize) -> Vec<widget::Id> {
+    let mut id_generator = ui.widget_id_generator();
+    (0..n).map(|_| id_generator.next()).collect()
+}
+
+// The given widgets ordered from back to front, as of the last update.
+fn depth_order_of(ui: &Ui, ids: &[widget::Id]) -> Vec<widget::Id> {
+    let mut depth_order = graph::DepthOrder::new();
+    depth_order.update(ui.widget_graph(), ui.window, ui.updated_widgets());
+    depth_order
+        .indices
+        .into_iter()
+        .filter(|id| ids.contains(id))
+        .collect()
+}
+
 ///// Actual tests.
 
 #[test]
@@ -152,6 +189,45 @@ fn drag_delta_xy_should_add_up_to_total_delta_xy() {
     }
 }
 
+#[test]
+fn simultaneous_drags_should_be_tracked_separately_for_each_button() {
+    let ui = &mut windowless_ui();
+    let move_to = |ui: &mut Ui, x: f64| {
+        test_handling_basic_input_event(ui, Input::Motion(Motion::MouseCursor { x: x, y: 0.0 }));
+    };
+    move_to(ui, 0.0);
+    press_mouse_button(MouseButton::Left, ui);
+    move_to(ui, 5.0);
+    press_mouse_button(MouseButton::Right, ui);
+    move_to(ui, 15.0);
+    release_mouse_button(MouseButton::Left, ui);
+    release_mouse_button(MouseButton::Right, ui);
+
+    // The last drag of each button spans from where that button was pressed.
+    let last_drag = |button: MouseButton| {
+        ui.global_input()
+            .events()
+            .ui()
+            .filter_map(|event| match *event {
+                event::Ui::Drag(_, ref drag) if drag.button == button => Some(*drag),
+                _ => None,
+            })
+            .last()
+            .expect("no drag for button")
+    };
+    let left = last_drag(MouseButton::Left);
+    assert_eq!(
+        (left.origin, left.total_delta_xy),
+        ([0.0, 0.0], [15.0, 0.0])
+    );
+    let right = last_drag(MouseButton::Right);
+    assert_eq!(
+        (right.origin, right.total_delta_xy),
+        ([5.0, 0.0], [10.0, 0.0])
+    );
+    assert_eq!(right.delta_xy, [10.0, 0.0]);
+}
+
 #[test]
 fn ui_should_push_input_events_to_aggregator() {
     let ui = &mut windowless_ui();
@@ -182,3 +258,4064 @@ fn high_level_scroll_event_should_be_created_from_a_raw_mouse_scroll() {
         assert_eq!(expected_scroll, scroll);
     }
 }
+
+#[test]
+fn ui_should_not_wait_for_events_while_a_widget_is_animating() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+
+    for _ in 0..5 {
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Button::new().w_h(100.0, 50.0).set(button, ui);
+            ui.request_continuous_updates();
+        }
+        ui.draw();
+        assert!(!ui.should_wait_for_events());
+    }
+}
+
+#[test]
+fn ui_should_wait_for_events_once_static_widgets_are_drawn() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new().w_h(100.0, 50.0).set(button, ui);
+    }
+    assert!(!ui.should_wait_for_events());
+
+    // The first update may produce capturing events, so update once more to deliver them.
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new().w_h(100.0, 50.0).set(button, ui);
+    }
+
+    // Draw until the redraw count has been exhausted.
+    while ui.draw_if_changed().is_some() {}
+    assert!(ui.should_wait_for_events());
+}
+
+#[test]
+fn reset_widget_state_should_reinitialise_state_on_next_update() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+
+    let set_button = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 50.0)
+            .label("Reset")
+            .set(button, ui);
+    };
+
+    // The button generates the `widget::Id`s for its graphics within `init_state`.
+    set_button(ui);
+    let node_count = ui.widget_graph().node_count();
+    set_button(ui);
+    assert_eq!(ui.widget_graph().node_count(), node_count);
+
+    // After a reset, the button should generate a new set of ids rather than re-using the old.
+    ui.reset_widget_state(button);
+    assert!(ui.widget_graph().widget(button).unwrap().maybe_state.is_none());
+    set_button(ui);
+    assert!(ui.widget_graph().node_count() > node_count);
+    assert!(ui.widget_graph().widget(button).unwrap().maybe_state.is_some());
+}
+
+/// A widget whose `update` always panics, used to test panic isolation.
+#[derive(WidgetCommon_)]
+struct Panicking {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+}
+
+impl Widget for Panicking {
+    type State = ();
+    type Style = ();
+    type Event = ();
+
+    fn init_state(&self, _: widget::id::Generator) -> Self::State {}
+
+    fn style(&self) -> Self::Style {}
+
+    fn update(self, _: widget::UpdateArgs<Self>) -> Self::Event {
+        panic!("this widget always panics");
+    }
+}
+
+#[test]
+fn panicking_widget_should_not_prevent_siblings_from_being_set_and_drawn() {
+    let ui = &mut windowless_ui();
+    let (panicking, button) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    for _ in 0..2 {
+        let ui = &mut ui.set_widgets();
+        let event = Panicking {
+            common: widget::CommonBuilder::default(),
+        }
+        .w_h(10.0, 10.0)
+        .try_set(panicking, ui);
+        assert!(event.is_none());
+        widget::Button::new()
+            .w_h(100.0, 50.0)
+            .x_y(0.0, 0.0)
+            .set(button, ui);
+    }
+
+    assert!(ui.updated_widgets().contains(&panicking));
+    assert!(ui.updated_widgets().contains(&button));
+    let mut primitives = ui.draw();
+    let mut drew_button = false;
+    while let Some(primitive) = primitives.next() {
+        if ui
+            .widget_graph()
+            .does_recursive_graphic_edge_exist(button, primitive.id)
+        {
+            drew_button = true;
+        }
+    }
+    assert!(drew_button);
+}
+
+#[test]
+fn opacity_should_cascade_from_parents_to_children() {
+    let ui = &mut windowless_ui();
+    let (parent, child) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([200.0, 200.0])
+            .x_y(0.0, 0.0)
+            .color(Color::Rgba(1.0, 0.0, 0.0, 1.0))
+            .opacity(0.5)
+            .set(parent, ui);
+        widget::Rectangle::fill([100.0, 100.0])
+            .middle_of(parent)
+            .color(Color::Rgba(0.0, 0.0, 1.0, 0.8))
+            .opacity(0.5)
+            .set(child, ui);
+    }
+
+    let mut primitives = ui.draw();
+    let mut alphas = Vec::new();
+    while let Some(primitive) = primitives.next() {
+        if primitive.id != parent && primitive.id != child {
+            continue;
+        }
+        if let render::PrimitiveKind::Rectangle { color } = primitive.kind {
+            alphas.push((primitive.id, color.to_rgb().3));
+        }
+    }
+    assert_eq!(alphas, vec![(parent, 0.5), (child, 0.2)]);
+}
+
+#[test]
+fn opacity_should_be_clamped_and_skip_fully_transparent_subtrees() {
+    fn drawn_alphas(ui: &mut Ui, ids: &[widget::Id], opacity: f32) -> Vec<(widget::Id, f32)> {
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Rectangle::fill([200.0, 200.0])
+                .x_y(0.0, 0.0)
+                .color(Color::Rgba(1.0, 0.0, 0.0, 0.5))
+                .opacity(opacity)
+                .set(ids[0], ui);
+            widget::Rectangle::fill([100.0, 100.0])
+                .middle_of(ids[0])
+                .color(Color::Rgba(0.0, 0.0, 1.0, 0.5))
+                .set(ids[1], ui);
+            widget::Rectangle::fill([100.0, 100.0])
+                .x_y(300.0, 0.0)
+                .color(Color::Rgba(0.0, 1.0, 0.0, 0.5))
+                .opacity(-1.0)
+                .set(ids[2], ui);
+        }
+        let mut primitives = ui.draw();
+        let mut alphas = Vec::new();
+        while let Some(primitive) = primitives.next() {
+            if !ids.contains(&primitive.id) {
+                continue;
+            }
+            if let render::PrimitiveKind::Rectangle { color } = primitive.kind {
+                alphas.push((primitive.id, color.to_rgb().3));
+            }
+        }
+        alphas
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 3);
+
+    // Opacities beyond `1.0` are clamped, while those beneath `0.0` draw nothing.
+    let alphas = drawn_alphas(ui, &ids, 2.0);
+    assert_eq!(alphas, vec![(ids[0], 0.5), (ids[1], 0.5)]);
+
+    // Nothing is drawn for the subtree of a fully transparent widget.
+    let alphas = drawn_alphas(ui, &ids, 0.0);
+    assert_eq!(alphas, vec![]);
+}
+
+#[test]
+fn nine_slice_images_should_be_drawn_as_nine_slices() {
+    let ui = &mut windowless_ui();
+    let id = ui.widget_id_generator().next();
+    let image_id = image::Map::<()>::new().insert(());
+
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Image::new(image_id)
+            .source_rectangle(Rect::from_corners([0.0, 0.0], [3.0, 3.0]))
+            .nine_slice([1.0, 1.0, 1.0, 1.0])
+            .w_h(30.0, 20.0)
+            .x_y(0.0, 0.0)
+            .set(id, ui);
+    }
+
+    let mut primitives = ui.draw();
+    let mut slices = Vec::new();
+    while let Some(primitive) = primitives.next() {
+        if primitive.id != id {
+            continue;
+        }
+        if let render::PrimitiveKind::Image { source_rect, .. } = primitive.kind {
+            let src = source_rect.expect("slices should have a source rect");
+            slices.push((primitive.rect.l_r_b_t(), src.l_r_b_t()));
+        }
+    }
+
+    // The corners keep their size while the edges and centre stretch, row by row from the top.
+    let (xs, ys) = ([-15.0, -14.0, 14.0, 15.0], [10.0, 9.0, -9.0, -10.0]);
+    let (src_xs, src_ys) = ([0.0, 1.0, 2.0, 3.0], [3.0, 2.0, 1.0, 0.0]);
+    let mut expected = Vec::new();
+    for row in 0..3 {
+        for col in 0..3 {
+            let rect = (xs[col], xs[col + 1], ys[row + 1], ys[row]);
+            let src = (src_xs[col], src_xs[col + 1], src_ys[row + 1], src_ys[row]);
+            expected.push((rect, src));
+        }
+    }
+    assert_eq!(slices, expected);
+}
+
+#[test]
+fn tiled_images_should_repeat_and_crop_the_final_row_and_column() {
+    fn drawn_tiles(ui: &mut Ui, id: widget::Id, src: Rect) -> Vec<(Rect, Rect)> {
+        let image_id = image::Map::<()>::new().insert(());
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Image::new(image_id)
+                .source_rectangle(src)
+                .tiled(true)
+                .w_h(25.0, 15.0)
+                .x_y(0.0, 0.0)
+                .set(id, ui);
+        }
+        let mut primitives = ui.draw();
+        let mut tiles = Vec::new();
+        while let Some(primitive) = primitives.next() {
+            if primitive.id != id {
+                continue;
+            }
+            if let render::PrimitiveKind::Image { source_rect, .. } = primitive.kind {
+                tiles.push((
+                    primitive.rect,
+                    source_rect.expect("tiles should have a source rect"),
+                ));
+            }
+        }
+        tiles
+    }
+
+    let ui = &mut windowless_ui();
+    let id = ui.widget_id_generator().next();
+
+    // Three columns by two rows of 10x10 tiles, row by row from the top left.
+    let tiles = drawn_tiles(ui, id, Rect::from_corners([0.0, 0.0], [10.0, 10.0]));
+    let rects: Vec<_> = tiles
+        .iter()
+        .map(|&(r, s)| (r.l_r_b_t(), s.l_r_b_t()))
+        .collect();
+    let (full, right, bottom, corner) = (
+        (0.0, 10.0, 0.0, 10.0),
+        (0.0, 5.0, 0.0, 10.0),
+        (0.0, 10.0, 5.0, 10.0),
+        (0.0, 5.0, 5.0, 10.0),
+    );
+    let expected = vec![
+        ((-12.5, -2.5, -2.5, 7.5), full),
+        ((-2.5, 7.5, -2.5, 7.5), full),
+        ((7.5, 12.5, -2.5, 7.5), right),
+        ((-12.5, -2.5, -7.5, -2.5), bottom),
+        ((-2.5, 7.5, -7.5, -2.5), bottom),
+        ((7.5, 12.5, -7.5, -2.5), corner),
+    ];
+    assert_eq!(rects, expected);
+
+    // Nothing is drawn for a zero-sized image.
+    let tiles = drawn_tiles(ui, id, Rect::from_corners([0.0, 0.0], [0.0, 10.0]));
+    assert!(tiles.is_empty());
+
+    // Tiles too small to fit within the limit are enlarged, still covering the whole `Rect`.
+    let src = Rect::from_corners([0.0, 0.0], [0.01, 0.01]);
+    let tiles = drawn_tiles(ui, id, src);
+    assert!(!tiles.is_empty() && tiles.len() <= widget::image::MAX_TILES);
+    let area: Scalar = tiles.iter().map(|&(r, _)| r.w() * r.h()).sum();
+    assert!((area - 25.0 * 15.0).abs() < 1e-6);
+    let (tile, tile_src) = tiles[0];
+    assert!(tile.w() > src.w() && (tile_src.w() - src.w()).abs() < 1e-9);
+}
+
+#[test]
+fn interaction_log_should_record_widget_interactions_in_order() {
+    let ui = &mut windowless_ui();
+    let (button, slider) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    let mut value = 0.5;
+    let mut set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        for _ in widget::Button::new()
+            .w_h(100.0, 50.0)
+            .x_y(-200.0, 0.0)
+            .set(button, ui)
+        {}
+        if let Some(new_value) = widget::Slider::new(value, 0.0, 1.0)
+            .w_h(200.0, 30.0)
+            .x_y(200.0, 0.0)
+            .set(slider, ui)
+        {
+            value = new_value;
+        }
+    };
+
+    set_widgets(ui);
+    assert!(ui.interaction_log().is_empty());
+
+    // Click the button, then drag the slider towards its right edge.
+    move_mouse_to_abs_coordinates(-200.0, 0.0, ui);
+    left_click_mouse(ui);
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(250.0, 0.0, ui);
+    set_widgets(ui);
+
+    let log = ui.interaction_log();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].widget, button);
+    assert_eq!(log[0].widget_kind, "Button");
+    assert_eq!(log[0].kind, event::InteractionKind::Click);
+    assert_eq!(log[1].widget, slider);
+    assert_eq!(log[1].widget_kind, "Slider");
+    assert_eq!(log[1].kind, event::InteractionKind::Drag);
+
+    // The log only covers the most recent call to `set_widgets`.
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui);
+    assert!(ui.interaction_log().is_empty());
+}
+
+#[test]
+fn canvas_should_not_scroll_when_its_children_fit_within_the_kid_area() {
+    use position::Range;
+
+    // Returns the `y` scroll state of a scrollable canvas after scrolling over it.
+    fn scroll_canvas_with_kid_of_height(kid_h: f64) -> widget::scroll::StateY {
+        let ui = &mut windowless_ui();
+        let (canvas, kid) = {
+            let mut id_generator = ui.widget_id_generator();
+            (id_generator.next(), id_generator.next())
+        };
+        let set_widgets = |ui: &mut Ui| {
+            let ui = &mut ui.set_widgets();
+            widget::Canvas::new()
+                .w_h(200.0, 200.0)
+                .x_y(0.0, 0.0)
+                .scroll_kids_vertically()
+                .set(canvas, ui);
+            widget::Rectangle::fill([50.0, kid_h])
+                .mid_top_of(canvas)
+                .set(kid, ui);
+        };
+
+        set_widgets(ui);
+        set_widgets(ui);
+        move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+        ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: 50.0 }));
+        set_widgets(ui);
+        set_widgets(ui);
+        ui.widget_graph()
+            .widget(canvas)
+            .and_then(|container| container.maybe_y_scroll_state)
+            .expect("no y scroll state for canvas")
+    }
+
+    let fits = scroll_canvas_with_kid_of_height(50.0);
+    assert_eq!(fits.offset_bounds, Range::new(0.0, 0.0));
+    assert_eq!(fits.offset, 0.0);
+
+    let overflows = scroll_canvas_with_kid_of_height(400.0);
+    assert!(overflows.offset_bounds.len() > 0.0);
+    assert!(overflows.offset != 0.0);
+}
+
+#[test]
+fn repeating_button_should_trigger_while_held_rather_than_upon_release() {
+    use std::time::Duration;
+
+    let ui = &mut windowless_ui();
+    let (plain, repeating) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+
+    // An initial delay of zero with an interval that is never reached triggers exactly once.
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        let plain_clicks = widget::Button::new()
+            .w_h(100.0, 50.0)
+            .x_y(-200.0, 0.0)
+            .set(plain, ui)
+            .count();
+        let repeating_clicks = widget::Button::new()
+            .w_h(100.0, 50.0)
+            .x_y(200.0, 0.0)
+            .repeat(Duration::from_secs(0), Duration::from_secs(3600))
+            .set(repeating, ui)
+            .count();
+        (plain_clicks, repeating_clicks)
+    };
+
+    set_widgets(ui);
+
+    // The plain button only triggers upon release.
+    move_mouse_to_abs_coordinates(-200.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), (0, 0));
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), (1, 0));
+
+    // The repeating button triggers while held and not again upon release.
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), (0, 1));
+    assert!(!ui.should_wait_for_events());
+    assert_eq!(set_widgets(ui), (0, 0));
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), (0, 0));
+
+    // Moving the cursor off the button stops the repeat and resets it for the next press.
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), (0, 1));
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    assert_eq!(set_widgets(ui), (0, 0));
+    release_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), (0, 1));
+}
+
+#[test]
+fn image_button_should_swap_images_with_its_interaction_and_trigger_upon_release() {
+    let ui = &mut windowless_ui();
+    let (plain, hovered) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    let mut image_map = image::Map::<()>::new();
+    let (normal, hover, press) = (
+        image_map.insert(()),
+        image_map.insert(()),
+        image_map.insert(()),
+    );
+
+    // Set the widgets, returning the times each was clicked along with the images drawn.
+    let set_widgets = |ui: &mut Ui| {
+        let clicks = {
+            let ui = &mut ui.set_widgets();
+            let plain_clicks = widget::Button::image(normal)
+                .w_h(100.0, 50.0)
+                .x_y(-200.0, 0.0)
+                .set(plain, ui)
+                .count();
+            let hovered_clicks = widget::Button::image(normal)
+                .hover_image(hover)
+                .w_h(100.0, 50.0)
+                .x_y(200.0, 0.0)
+                .set(hovered, ui)
+                .count();
+            (plain_clicks, hovered_clicks)
+        };
+        let mut primitives = ui.draw();
+        let mut image_ids = Vec::new();
+        while let Some(primitive) = primitives.next() {
+            if let render::PrimitiveKind::Image { image_id, .. } = primitive.kind {
+                image_ids.push(image_id);
+            }
+        }
+        (clicks, image_ids)
+    };
+
+    assert_eq!(set_widgets(ui), ((0, 0), vec![normal, normal]));
+
+    // Without a hover or press image, the normal image is used throughout.
+    move_mouse_to_abs_coordinates(-200.0, 0.0, ui);
+    assert_eq!(set_widgets(ui), ((0, 0), vec![normal, normal]));
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), ((0, 0), vec![normal, normal]));
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), ((1, 0), vec![normal, normal]));
+
+    // Without a press image, the hover image is used while pressed.
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    assert_eq!(set_widgets(ui), ((0, 0), vec![normal, hover]));
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), ((0, 0), vec![normal, hover]));
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui), ((0, 1), vec![normal, hover]));
+
+    // With a press image, it is used while pressed.
+    let set_pressable = |ui: &mut Ui| {
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Button::image(normal)
+                .hover_image(hover)
+                .press_image(press)
+                .w_h(100.0, 50.0)
+                .x_y(200.0, 0.0)
+                .set(hovered, ui);
+        }
+        let mut primitives = ui.draw();
+        let mut image_ids = Vec::new();
+        while let Some(primitive) = primitives.next() {
+            if let render::PrimitiveKind::Image { image_id, .. } = primitive.kind {
+                image_ids.push(image_id);
+            }
+        }
+        image_ids
+    };
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_pressable(ui), vec![press]);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_pressable(ui), vec![hover]);
+}
+
+#[test]
+fn crop_rect_should_maintain_aspect_ratio_and_clamp_to_image_bounds() {
+    use position::Rect;
+
+    let ui = &mut windowless_ui();
+    let crop = ui.widget_id_generator().next();
+
+    // A 200x100 pixel image displayed at twice its size, centred within the window.
+    let mut selection = Rect::from_corners([50.0, 25.0], [150.0, 75.0]);
+    let mut set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        if let Some(new_selection) = widget::CropRect::new([200.0, 100.0], selection)
+            .lock_aspect(2.0)
+            .w_h(400.0, 200.0)
+            .x_y(0.0, 0.0)
+            .set(crop, ui)
+        {
+            selection = new_selection;
+        }
+        selection
+    };
+    set_widgets(ui);
+
+    // Drag the top right corner, which lies at `[100.0, 50.0]` within the window, to the right.
+    move_mouse_to_abs_coordinates(100.0, 50.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(140.0, 50.0, ui);
+    let resized = set_widgets(ui);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui);
+    assert_eq!(resized, Rect::from_corners([50.0, 25.0], [170.0, 85.0]));
+    assert_eq!(resized.w() / resized.h(), 2.0);
+
+    // Drag the top right corner beyond the image bounds. The height reaches the top of the image
+    // first, so the width is limited in order to maintain the aspect ratio.
+    move_mouse_to_abs_coordinates(140.0, 70.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(400.0, 300.0, ui);
+    let resized = set_widgets(ui);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui);
+    assert_eq!(resized, Rect::from_corners([50.0, 25.0], [200.0, 100.0]));
+    assert_eq!(resized.w() / resized.h(), 2.0);
+
+    // Drag the interior of the selection beyond the bottom left of the image.
+    move_mouse_to_abs_coordinates(50.0, 25.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(-300.0, -250.0, ui);
+    let moved = set_widgets(ui);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui);
+    assert_eq!(moved, Rect::from_corners([0.0, 0.0], [150.0, 75.0]));
+}
+
+#[test]
+fn switch_toggle_knob_should_slide_towards_the_side_for_its_value() {
+    use position::Rect;
+    use std::time::Duration;
+
+    // The knob sits at the right of the track when on and at the left when off.
+    let rect = Rect::from_xy_dim([0.0, 0.0], [60.0, 30.0]);
+    let (track, on_knob) = widget::toggle::switch_rects(rect, 1.0);
+    let (_, off_knob) = widget::toggle::switch_rects(rect, 0.0);
+    assert_eq!(track, rect);
+    assert_eq!(on_knob, Rect::from_xy_dim([15.0, 0.0], [24.0, 24.0]));
+    assert_eq!(off_knob, Rect::from_xy_dim([-15.0, 0.0], [24.0, 24.0]));
+
+    let ui = &mut windowless_ui();
+    let toggle = ui.widget_id_generator().next();
+    let mut value = false;
+    let mut set_widgets = |ui: &mut Ui| {
+        {
+            let ui = &mut ui.set_widgets();
+            let event = widget::Toggle::new(value)
+                .style_as_switch(true)
+                .w_h(60.0, 30.0)
+                .x_y(0.0, 0.0)
+                .set(toggle, ui);
+            if let Some(new_value) = event.last() {
+                value = new_value;
+            }
+        }
+
+        // Find the x position of the knob.
+        let mut primitives = ui.draw();
+        let mut knob_x = None;
+        while let Some(primitive) = primitives.next() {
+            let is_toggle_graphic = ui
+                .widget_graph()
+                .does_recursive_graphic_edge_exist(toggle, primitive.id);
+            if is_toggle_graphic && primitive.rect.w() == 24.0 {
+                knob_x = Some(primitive.rect.x());
+            }
+        }
+        knob_x.expect("no knob was drawn")
+    };
+
+    let start = instant::Instant::now();
+    ui.set_now(Some(start));
+    assert_eq!(set_widgets(ui), -15.0);
+
+    // Toggling on begins sliding the knob, requiring continuous updates.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui), -15.0);
+
+    // Half way through the 0.15 second slide the knob sits in the middle of the track.
+    let half_slide = Duration::from_millis(75);
+    ui.set_now(Some(start + half_slide));
+    assert_eq!(set_widgets(ui), 0.0);
+    assert!(!ui.should_wait_for_events());
+
+    // Once the slide duration has elapsed, the knob rests on the right.
+    ui.set_now(Some(start + half_slide * 2));
+    assert_eq!(set_widgets(ui), 15.0);
+}
+
+#[test]
+fn indeterminate_toggle_should_draw_a_dash_and_resolve_when_clicked() {
+    fn set_widgets(ui: &mut Ui, toggle: widget::Id, resolves_to: bool) -> (Vec<bool>, bool) {
+        let clicks = {
+            let ui = &mut ui.set_widgets();
+            widget::Toggle::new(false)
+                .tri_state(None)
+                .resolve_indeterminate_to(resolves_to)
+                .w_h(50.0, 50.0)
+                .x_y(0.0, 0.0)
+                .set(toggle, ui)
+                .collect()
+        };
+
+        // Check for the 25x10 dash drawn across the middle of the toggle.
+        let mut primitives = ui.draw();
+        let mut drew_dash = false;
+        while let Some(primitive) = primitives.next() {
+            let is_toggle_graphic = ui
+                .widget_graph()
+                .does_recursive_graphic_edge_exist(toggle, primitive.id);
+            if is_toggle_graphic && primitive.rect.w_h() == (25.0, 10.0) {
+                drew_dash = true;
+            }
+        }
+        (clicks, drew_dash)
+    }
+
+    let ui = &mut windowless_ui();
+    let toggle = ui.widget_id_generator().next();
+    assert_eq!(set_widgets(ui, toggle, true), (vec![], true));
+
+    // Clicking an indeterminate toggle resolves it to `true` by default.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, toggle, true), (vec![true], false));
+
+    // The resolved value is configurable, and further clicks within the update toggle it.
+    left_click_mouse(ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, toggle, false), (vec![false, true], false));
+}
+
+#[test]
+fn property_grid_should_report_changes_for_the_toggled_row() {
+    use widget::property_grid::{Change, PropertyValue};
+
+    let ui = &mut windowless_ui();
+    let grid = ui.widget_id_generator().next();
+    let mut visible = false;
+    let mut set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        let properties = [
+            ("Name", PropertyValue::Text("conrod")),
+            ("Visible", PropertyValue::Bool(visible)),
+        ];
+        let changes = widget::PropertyGrid::new(&properties)
+            .w_h(300.0, 60.0)
+            .x_y(0.0, 0.0)
+            .set(grid, ui);
+        for &(row, ref change) in &changes {
+            if let (1, &Change::Bool(new_visible)) = (row, change) {
+                visible = new_visible;
+            }
+        }
+        changes
+    };
+    set_widgets(ui);
+    set_widgets(ui);
+
+    // The editor of the second row's bool property sits at the left of the editor column.
+    move_mouse_to_abs_coordinates(-15.0, -15.0, ui);
+    let under_mouse = ui
+        .global_input()
+        .current
+        .widget_under_mouse
+        .and_then(|id| ui.widget_graph().widget(id))
+        .map(|container| container.type_id);
+    assert_eq!(
+        under_mouse,
+        Some(std::any::TypeId::of::<widget::toggle::State>())
+    );
+
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui), vec![(1, Change::Bool(true))]);
+    assert!(set_widgets(ui).is_empty());
+}
+
+#[test]
+fn background_color_should_be_read_by_backends_and_drawn_by_the_window() {
+    let ui = &mut windowless_ui();
+    assert_eq!(ui.background_color(), ::color::TRANSPARENT);
+
+    let color = Color::Rgba(0.2, 0.2, 0.2, 1.0);
+    ui.set_background_color(color);
+    assert_eq!(ui.background_color(), color);
+    assert_eq!(ui.background_color().to_fsa(), [0.2, 0.2, 0.2, 1.0]);
+
+    // No root `Canvas` is required for the background to be drawn.
+    ui.set_widgets();
+    let mut primitives = ui.draw();
+    let mut background = None;
+    while let Some(primitive) = primitives.next() {
+        if let render::PrimitiveKind::Rectangle { color } = primitive.kind {
+            background = Some((primitive.rect.dim(), color));
+            break;
+        }
+    }
+    assert_eq!(background, Some(([800.0, 600.0], color)));
+}
+
+#[test]
+fn uis_built_with_a_shared_font_map_should_resolve_ids_to_the_same_font() {
+    let mut fonts = ::text::font::Map::new();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    let font_id = fonts.insert_from_file(font_path).unwrap();
+
+    let main = UiBuilder::new([800.0, 600.0]).fonts(fonts.clone()).build();
+    let palette = UiBuilder::new([200.0, 400.0]).fonts(fonts).build();
+
+    let main_font = main.fonts.get(font_id).unwrap();
+    let palette_font = palette.fonts.get(font_id).unwrap();
+    assert!(::std::ptr::eq(main_font, palette_font));
+}
+
+#[test]
+fn fonts_inserted_into_clones_of_a_font_map_should_receive_distinct_ids() {
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    let mut main = ::text::font::Map::new();
+    main.insert_from_file(font_path).unwrap();
+    let mut palette = main.clone();
+
+    let main_id = main.insert_from_file(font_path).unwrap();
+    let palette_id = palette.insert_from_file(font_path).unwrap();
+    assert_ne!(main_id, palette_id);
+    assert!(main.get(palette_id).is_none());
+    assert!(palette.get(main_id).is_none());
+
+    let builder = UiBuilder::new([200.0, 400.0]).fonts(palette);
+    assert!(builder.maybe_fonts().unwrap().get(palette_id).is_some());
+}
+
+#[test]
+fn drop_down_list_should_be_operable_via_the_keyboard() {
+    fn set_list(ui: &mut Ui, list: widget::Id, selected: Option<usize>) -> Option<usize> {
+        let ui = &mut ui.set_widgets();
+        widget::DropDownList::new(&["Apple", "Banana", "Cherry", "Date"], selected)
+            .w_h(150.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(list, ui)
+    }
+    fn press_key(key: Key, ui: &mut Ui) {
+        ui.handle_event(Input::Press(Button::Keyboard(key)));
+    }
+
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+    set_list(ui, list, Some(0));
+    ui.keyboard_capture(list);
+
+    // `Down` opens the list, then moves the highlight to the next item.
+    press_key(Key::Down, ui);
+    assert_eq!(set_list(ui, list, Some(0)), None);
+    press_key(Key::Down, ui);
+    assert_eq!(set_list(ui, list, Some(0)), None);
+    press_key(Key::Return, ui);
+    assert_eq!(set_list(ui, list, Some(0)), Some(1));
+
+    // Typing a character jumps to the next item starting with it.
+    press_key(Key::Return, ui);
+    assert_eq!(set_list(ui, list, Some(1)), None);
+    ui.handle_event(Input::Text("c".to_string()));
+    assert_eq!(set_list(ui, list, Some(1)), None);
+    press_key(Key::Return, ui);
+    assert_eq!(set_list(ui, list, Some(1)), Some(2));
+
+    // `Escape` closes the list without selecting the highlighted item, so the following `Return`
+    // opens the list rather than selecting.
+    press_key(Key::Down, ui);
+    assert_eq!(set_list(ui, list, Some(2)), None);
+    press_key(Key::Down, ui);
+    assert_eq!(set_list(ui, list, Some(2)), None);
+    press_key(Key::Escape, ui);
+    assert_eq!(set_list(ui, list, Some(2)), None);
+    press_key(Key::Return, ui);
+    assert_eq!(set_list(ui, list, Some(2)), None);
+    press_key(Key::Return, ui);
+    assert_eq!(set_list(ui, list, Some(2)), Some(2));
+}
+
+#[test]
+fn open_drop_down_list_should_be_an_overlay_dismissed_by_pressing_outside_of_it() {
+    let ui = &mut windowless_ui();
+    let (list, beneath) = {
+        let mut ids = ui.widget_id_generator();
+        (ids.next(), ids.next())
+    };
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        let beneath_clicks = widget::Button::new()
+            .w_h(150.0, 30.0)
+            .x_y(0.0, -60.0)
+            .set(beneath, ui)
+            .count();
+        let selected = widget::DropDownList::new(&["Apple", "Banana", "Cherry", "Date"], None)
+            .w_h(150.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(list, ui);
+        (selected, beneath_clicks)
+    };
+    set_widgets(ui);
+
+    // Once open, the list covering the button receives the mouse instead.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui);
+    set_widgets(ui);
+    assert_eq!(ui.overlays().len(), 1);
+    move_mouse_to_abs_coordinates(0.0, -60.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui), (Some(2), 0));
+    assert!(ui.overlays().is_empty());
+
+    // Pressing outside of the list dismisses it without a selection.
+    set_widgets(ui);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui);
+    set_widgets(ui);
+    assert_eq!(ui.overlays().len(), 1);
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui), (None, 0));
+    assert!(ui.overlays().is_empty());
+}
+
+#[test]
+fn hovering_a_widget_with_a_tooltip_should_display_it_in_the_shared_overlay() {
+    let ui = &mut windowless_ui();
+    ui.theme.tooltip_delay = ::std::time::Duration::from_millis(50);
+    let start = instant::Instant::now();
+    ui.set_now(Some(start));
+    let (button, other) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    fn set_widgets(ui: &mut Ui, button: widget::Id, other: widget::Id, tooltip: &str) {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 40.0)
+            .x_y(0.0, 0.0)
+            .set(button, ui);
+        ui.tooltip(button, tooltip);
+        widget::Button::new()
+            .w_h(100.0, 40.0)
+            .x_y(200.0, 0.0)
+            .set(other, ui);
+    }
+
+    let tooltip = String::from("Saves the document");
+    set_widgets(ui, button, other, &tooltip);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    set_widgets(ui, button, other, &tooltip);
+    assert_eq!(ui.displayed_tooltip(), None);
+    assert!(!ui.should_wait_for_events());
+
+    // Just before the delay has passed the overlay remains hidden.
+    ui.set_now(Some(start + ::std::time::Duration::from_millis(49)));
+    set_widgets(ui, button, other, &tooltip);
+    assert_eq!(ui.displayed_tooltip(), None);
+
+    // Once hovered for the delay, the overlay displays the text.
+    ui.set_now(Some(start + ::std::time::Duration::from_millis(50)));
+    set_widgets(ui, button, other, &tooltip);
+    assert_eq!(ui.displayed_tooltip(), Some("Saves the document"));
+
+    // The overlay follows changes to the text.
+    set_widgets(ui, button, other, "Saves the document as...");
+    assert_eq!(ui.displayed_tooltip(), Some("Saves the document as..."));
+
+    // Hovering a widget without a tooltip hides the overlay.
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    set_widgets(ui, button, other, &tooltip);
+    assert_eq!(ui.displayed_tooltip(), None);
+}
+
+#[test]
+fn owned_primitives_should_remain_valid_after_the_walk_advances() {
+    let ui = &mut windowless_ui();
+    let (oval, line) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Oval::fill([100.0, 50.0])
+            .x_y(0.0, 0.0)
+            .set(oval, ui);
+        widget::Line::abs([-50.0, -50.0], [50.0, 50.0]).set(line, ui);
+    }
+
+    let owned = ui.draw().owned();
+    let primitives: Vec<render::Primitive> = owned.walk().collect();
+    let triangles: Vec<_> = primitives
+        .iter()
+        .filter_map(|primitive| match primitive.kind {
+            render::PrimitiveKind::TrianglesSingleColor { triangles, .. } => {
+                Some((primitive.id, triangles))
+            }
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(triangles.len(), 2);
+    let (oval_tris, line_tris) = (triangles[0].1, triangles[1].1);
+    assert_eq!((triangles[0].0, triangles[1].0), (oval, line));
+    assert!(!oval_tris.is_empty() && !line_tris.is_empty());
+    // Each primitive's slice refers to its own triangles rather than a shared, reused buffer.
+    assert_ne!(oval_tris.as_ptr(), line_tris.as_ptr());
+    assert_eq!(line_tris.len(), 2);
+}
+
+#[test]
+fn viewport_transform_should_map_world_to_screen_and_back() {
+    let transform = widget::viewport::Transform {
+        origin: [100.0, 50.0],
+        pan: [10.0, -20.0],
+        zoom: 2.0,
+    };
+    assert_eq!(transform.world_to_screen([10.0, -20.0]), [100.0, 50.0]);
+    assert_eq!(transform.world_to_screen([15.0, 0.0]), [110.0, 90.0]);
+    assert_eq!(transform.screen_to_world([110.0, 90.0]), [15.0, 0.0]);
+    assert_eq!(transform.scale([30.0, 40.0]), [60.0, 80.0]);
+}
+
+#[test]
+fn viewport_should_pan_by_dragging_and_zoom_about_the_mouse_within_limits() {
+    let ui = &mut windowless_ui();
+    let viewport = ui.widget_id_generator().next();
+    let set_viewport = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Viewport::new()
+            .w_h(400.0, 400.0)
+            .x_y(0.0, 0.0)
+            .zoom_limits(0.5, 4.0)
+            .set(viewport, ui)
+    };
+    set_viewport(ui);
+
+    // Dragging the view right by 20 pans the world left by 20.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    set_viewport(ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(20.0, 0.0, ui);
+    set_viewport(ui);
+    release_mouse_button(MouseButton::Left, ui);
+    let transform = set_viewport(ui);
+    assert_eq!(transform.pan, [-20.0, 0.0]);
+
+    // Zooming in keeps the world point beneath the mouse in place.
+    let mouse = [50.0, 50.0];
+    move_mouse_to_abs_coordinates(mouse[0], mouse[1], ui);
+    let before = set_viewport(ui).screen_to_world(mouse);
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: 100.0 }));
+    let transform = set_viewport(ui);
+    assert_eq!(transform.zoom, 2.0);
+    let after = transform.screen_to_world(mouse);
+    assert!((after[0] - before[0]).abs() < 1e-9 && (after[1] - before[1]).abs() < 1e-9);
+
+    // The zoom is clamped to the limits.
+    ui.handle_event(Input::Motion(Motion::Scroll { x: 0.0, y: 1000.0 }));
+    assert_eq!(set_viewport(ui).zoom, 4.0);
+}
+
+#[test]
+fn viewport_zoom_limits_should_reject_non_positive_or_inverted_limits() {
+    let is_rejected = |min, max| {
+        std::panic::catch_unwind(|| widget::Viewport::new().zoom_limits(min, max)).is_err()
+    };
+    assert!(is_rejected(0.0, 4.0));
+    assert!(is_rejected(-1.0, 4.0));
+    assert!(is_rejected(4.0, 0.5));
+    assert!(!is_rejected(0.5, 4.0));
+    assert!(!is_rejected(2.0, 2.0));
+}
+
+#[test]
+fn snap_guides_should_snap_a_dragged_widget_into_alignment_with_its_siblings() {
+    use position::Rect;
+    use widget::snap_guides::Guide;
+
+    let ui = &mut windowless_ui();
+    let (surface, sibling, dragged, guides) = {
+        let mut id_generator = ui.widget_id_generator();
+        (
+            id_generator.next(),
+            id_generator.next(),
+            id_generator.next(),
+            id_generator.next(),
+        )
+    };
+    // The dragged widget's left edge is 2px from the sibling's left edge of -100.
+    let proposed = Rect::from_corners([-98.0, 20.0], [-38.0, 60.0]);
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new().set(surface, ui);
+        widget::Rectangle::fill([80.0, 40.0])
+            .x_y(-60.0, -50.0)
+            .parent(surface)
+            .set(sibling, ui);
+        let snap = widget::SnapGuides::new(surface, dragged, proposed)
+            .threshold(5.0)
+            .parent(surface)
+            .set(guides, ui);
+        widget::Rectangle::fill(snap.rect.dim())
+            .xy(snap.rect.xy())
+            .parent(surface)
+            .set(dragged, ui);
+        snap
+    };
+    set_widgets(ui);
+    let snap = set_widgets(ui);
+
+    assert_eq!(snap.rect.left(), -100.0);
+    assert_eq!(snap.rect.w(), proposed.w());
+    assert_eq!(snap.rect.y, proposed.y);
+    assert_eq!(
+        snap.guides,
+        vec![Guide::Vertical {
+            x: -100.0,
+            y: ::position::Range::new(-70.0, 60.0),
+        }]
+    );
+}
+
+#[test]
+fn clear_widgets_should_leave_only_the_root_within_the_graph() {
+    let ui = &mut windowless_ui();
+    let (canvas, button) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new().set(canvas, ui);
+        widget::Button::new()
+            .w_h(100.0, 40.0)
+            .middle_of(canvas)
+            .set(button, ui);
+    }
+    assert!(ui.widget_graph().widget(button).is_some());
+
+    ui.clear_widgets();
+    assert_eq!(ui.widget_graph().widget_count(), 0);
+    assert!(ui.widget_graph().widget(canvas).is_none());
+    assert!(ui.widget_graph().widget(button).is_none());
+    assert!(ui.widget_graph().node(ui.window).is_some());
+
+    // The next screen starts out as fresh as that of a new `Ui`.
+    fn set_next_screen(ui: &mut Ui) -> widget::Id {
+        let next = ui.widget_id_generator().next();
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([10.0, 10.0]).set(next, ui);
+        next
+    }
+    let next = set_next_screen(ui);
+    let fresh = &mut windowless_ui();
+    set_next_screen(fresh);
+    let graph = ui.widget_graph();
+    assert_eq!(graph.widget_count(), fresh.widget_graph().widget_count());
+    assert!(graph.widget(ui.window).is_some() && graph.widget(next).is_some());
+}
+
+#[test]
+fn each_primitive_should_carry_the_id_of_its_originating_widget() {
+    let ui = &mut windowless_ui();
+    let (oval, button) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Oval::fill([100.0, 50.0])
+            .x_y(-100.0, 0.0)
+            .set(oval, ui);
+        widget::Button::new()
+            .w_h(80.0, 40.0)
+            .x_y(100.0, 0.0)
+            .set(button, ui);
+    }
+
+    let graph = ui.widget_graph();
+    let mut primitives = ui.draw();
+    let mut ids = vec![];
+    while let Some(primitive) = primitives.next() {
+        // Every primitive originates from a widget occupying the primitive's rect.
+        let container = graph.widget(primitive.id).expect("no widget for primitive");
+        assert_eq!(container.rect, primitive.rect);
+        ids.push(primitive.id);
+    }
+
+    assert!(ids.contains(&oval));
+    // The `Button` is drawn by its own primitive children which provide graphics for it.
+    assert!(ids
+        .iter()
+        .any(|&id| id != button && graph.graphic_parent(id) == Some(button)));
+}
+
+#[test]
+fn restoring_saved_state_should_reproduce_tab_selection_and_scroll_offset() {
+    fn set_widgets(ui: &mut Ui, ids: &[widget::Id], starting_tab_idx: Option<usize>) {
+        let (tabs, tab_a, tab_b, canvas, rect) = (ids[0], ids[1], ids[2], ids[3], ids[4]);
+        let ui = &mut ui.set_widgets();
+        let tab_list = [(tab_a, "A"), (tab_b, "B")];
+        let mut builder = widget::Tabs::new(&tab_list)
+            .w_h(200.0, 200.0)
+            .x_y(-200.0, 0.0)
+            .persist_as("tabs");
+        if let Some(idx) = starting_tab_idx {
+            builder = builder.starting_tab_idx(idx);
+        }
+        builder.set(tabs, ui);
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .x_y(200.0, 0.0)
+            .scroll_kids_vertically()
+            .persist_as("canvas")
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 1000.0])
+            .mid_top_of(canvas)
+            .set(rect, ui);
+    }
+    fn generate_ids(ui: &mut Ui) -> Vec<widget::Id> {
+        let mut id_generator = ui.widget_id_generator();
+        (0..5).map(|_| id_generator.next()).collect()
+    }
+    fn y_offset(ui: &Ui, canvas: widget::Id) -> f64 {
+        let container = ui.widget_graph().widget(canvas).unwrap();
+        container.maybe_y_scroll_state.unwrap().offset
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = generate_ids(ui);
+    let (tab_a, tab_b, canvas, rect) = (ids[1], ids[2], ids[3], ids[4]);
+    set_widgets(ui, &ids, Some(1));
+    ui.scroll_widget(canvas, [0.0, 50.0]);
+    set_widgets(ui, &ids, Some(1));
+    assert!(ui.updated_widgets().contains(&tab_b));
+    assert_eq!(y_offset(ui, canvas), 50.0);
+    let scrolled_rect = ui.rect_of(rect);
+
+    let saved = ui.save_state();
+    assert_eq!(saved.len(), 2);
+    assert_eq!(saved["tabs"].state, Some("1".to_string()));
+
+    // Restore into a fresh `Ui`, which would otherwise select the first tab.
+    let restored = &mut windowless_ui();
+    let restored_ids = generate_ids(restored);
+    restored.restore_state(saved.clone());
+    set_widgets(restored, &restored_ids, None);
+    assert!(restored.updated_widgets().contains(&tab_b));
+    assert!(!restored.updated_widgets().contains(&tab_a));
+    assert_eq!(y_offset(restored, canvas), 50.0);
+    set_widgets(restored, &restored_ids, None);
+    assert_eq!(y_offset(restored, canvas), 50.0);
+    assert_eq!(restored.rect_of(rect), scrolled_rect);
+
+    // Restoring into existing widgets positions their children within the same update.
+    let mut further = saved.clone();
+    further.get_mut("canvas").unwrap().scroll_offset = [0.0, 100.0];
+    ui.restore_state(further);
+    set_widgets(ui, &ids, Some(1));
+    assert_eq!(y_offset(ui, canvas), 100.0);
+    assert_eq!(
+        ui.rect_of(rect).map(|r| r.top()),
+        scrolled_rect.map(|r| r.top() + 50.0)
+    );
+
+    // Incompatible entries are ignored.
+    let mut incompatible = saved;
+    incompatible.get_mut("tabs").unwrap().state = Some("not an index".to_string());
+    incompatible.get_mut("canvas").unwrap().widget_type = "Unknown".to_string();
+    let fresh = &mut windowless_ui();
+    let fresh_ids = generate_ids(fresh);
+    fresh.restore_state(incompatible);
+    set_widgets(fresh, &fresh_ids, None);
+    set_widgets(fresh, &fresh_ids, None);
+    assert!(fresh.updated_widgets().contains(&tab_a));
+    assert_eq!(y_offset(fresh, canvas), 0.0);
+}
+
+#[test]
+fn clicks_within_hit_padding_should_pick_the_widget() {
+    let ui = &mut windowless_ui();
+    let (padded, unpadded) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        let padded_clicks = widget::Button::new()
+            .w_h(100.0, 2.0)
+            .x_y(-100.0, 0.0)
+            .hit_padding(10.0)
+            .set(padded, ui)
+            .was_clicked();
+        widget::Button::new()
+            .w_h(100.0, 2.0)
+            .x_y(100.0, 0.0)
+            .set(unpadded, ui);
+        padded_clicks
+    };
+    let under_mouse = |ui: &Ui| ui.global_input().current.widget_under_mouse;
+    set_widgets(ui);
+    set_widgets(ui);
+
+    // Just outside of the visual rect but within the hit padding.
+    move_mouse_to_abs_coordinates(-100.0, 8.0, ui);
+    assert_eq!(under_mouse(ui), Some(padded));
+    left_click_mouse(ui);
+    assert!(set_widgets(ui));
+    assert_eq!(ui.rect_of(padded).unwrap().h(), 2.0);
+
+    // Beyond the hit padding.
+    move_mouse_to_abs_coordinates(-100.0, 12.0, ui);
+    assert_eq!(under_mouse(ui), Some(ui.window));
+
+    // Widgets without hit padding are only hit within their rect.
+    move_mouse_to_abs_coordinates(100.0, 8.0, ui);
+    assert_eq!(under_mouse(ui), Some(ui.window));
+    move_mouse_to_abs_coordinates(100.0, 0.0, ui);
+    assert_eq!(under_mouse(ui), Some(unpadded));
+}
+
+#[test]
+fn clicks_should_pass_through_widgets_that_ignore_the_mouse() {
+    let ui = &mut windowless_ui();
+    let (button, overlay) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    let set_widgets = |ui: &mut Ui, ignore_mouse: bool| {
+        let ui = &mut ui.set_widgets();
+        let clicks = widget::Button::new()
+            .w_h(100.0, 100.0)
+            .x_y(0.0, 0.0)
+            .set(button, ui)
+            .was_clicked();
+        widget::Rectangle::fill([200.0, 200.0])
+            .x_y(0.0, 0.0)
+            .color(color::TRANSPARENT)
+            .ignore_mouse(ignore_mouse)
+            .set(overlay, ui);
+        clicks
+    };
+    let under_mouse = |ui: &Ui| ui.global_input().current.widget_under_mouse;
+    set_widgets(ui, false);
+    set_widgets(ui, false);
+
+    // The overlay intercepts the mouse by default.
+    move_mouse_to_abs_coordinates(10.0, 10.0, ui);
+    assert_eq!(under_mouse(ui), Some(overlay));
+    left_click_mouse(ui);
+    assert!(!set_widgets(ui, true));
+
+    // While ignoring the mouse, it is passed through to the button beneath.
+    move_mouse_to_abs_coordinates(20.0, 20.0, ui);
+    assert_eq!(under_mouse(ui), Some(button));
+    left_click_mouse(ui);
+    assert!(set_widgets(ui, true));
+
+    // And through to the window beyond the button.
+    move_mouse_to_abs_coordinates(80.0, 80.0, ui);
+    assert_eq!(under_mouse(ui), Some(ui.window));
+}
+
+#[test]
+fn table_columns_should_resize_by_dragging_and_sort_by_clicking() {
+    use widget::table::{Event, Sort};
+
+    let ui = &mut windowless_ui();
+    let table = ui.widget_id_generator().next();
+    let set_table = |ui: &mut Ui| -> Event {
+        let ui = &mut ui.set_widgets();
+        let event = widget::Table::new(&["A", "B"], 100)
+            .w_h(400.0, 300.0)
+            .x_y(0.0, 0.0)
+            .header_height(30.0)
+            .row_height(24.0)
+            .column_width(100.0)
+            .set(table, ui);
+        for cell in event.cells.clone() {
+            cell.set(widget::Rectangle::fill([1.0, 1.0]), ui);
+        }
+        event
+    };
+    let column_widths = |ui: &Ui| -> Vec<f64> {
+        let container = ui.widget_graph().widget(table).unwrap();
+        let unique = container.unique_widget_state::<widget::Table>().unwrap();
+        unique.state.column_widths().to_vec()
+    };
+    set_table(ui);
+    // Only the visible rows of the 270 high body are yielded.
+    assert_eq!(set_table(ui).cells.count(), 12 * 2);
+    assert_eq!(column_widths(ui), vec![100.0, 100.0]);
+
+    // Drag the divider on the right edge of the first column's header.
+    move_mouse_to_abs_coordinates(-102.0, 135.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(-62.0, 135.0, ui);
+    release_mouse_button(MouseButton::Left, ui);
+    set_table(ui);
+    assert_eq!(column_widths(ui), vec![140.0, 100.0]);
+
+    // Clicking a header cycles ascending, descending and unsorted.
+    move_mouse_to_abs_coordinates(-150.0, 135.0, ui);
+    set_table(ui);
+    let click_header = |ui: &mut Ui| {
+        left_click_mouse(ui);
+        let event = set_table(ui);
+        assert!(event.sort_changed);
+        event.sort
+    };
+    let ascending = Sort {
+        column: 0,
+        ascending: true,
+    };
+    let descending = Sort {
+        column: 0,
+        ascending: false,
+    };
+    assert_eq!(click_header(ui), Some(ascending));
+    assert_eq!(click_header(ui), Some(descending));
+    assert_eq!(click_header(ui), None);
+    assert!(!set_table(ui).sort_changed);
+}
+
+#[test]
+fn table_sort_should_be_cleared_when_its_column_is_removed() {
+    use widget::table::{Event, Sort};
+
+    let ui = &mut windowless_ui();
+    let table = ui.widget_id_generator().next();
+    let set_table = |ui: &mut Ui, columns: &[&'static str]| -> Event {
+        let ui = &mut ui.set_widgets();
+        widget::Table::new(columns, 10)
+            .w_h(400.0, 300.0)
+            .x_y(0.0, 0.0)
+            .header_height(30.0)
+            .column_width(100.0)
+            .set(table, ui)
+    };
+    set_table(ui, &["A", "B"]);
+    set_table(ui, &["A", "B"]);
+
+    // Sort by the second column.
+    move_mouse_to_abs_coordinates(-50.0, 135.0, ui);
+    set_table(ui, &["A", "B"]);
+    left_click_mouse(ui);
+    let sort = Sort {
+        column: 1,
+        ascending: true,
+    };
+    assert_eq!(set_table(ui, &["A", "B"]).sort, Some(sort));
+
+    // Removing the sorted column clears the sort rather than indexing beyond the columns.
+    let event = set_table(ui, &["A"]);
+    assert!(event.sort_changed);
+    assert_eq!(event.sort, None);
+}
+
+// The right edge of the last drawn slider's handle, i.e. the extent of its last two triangles.
+fn slider_handle_right(ui: &Ui) -> f64 {
+    let mut primitives = ui.draw();
+    let mut handle_right = None;
+    while let Some(primitive) = primitives.next() {
+        if let render::PrimitiveKind::TrianglesMultiColor { triangles } = primitive.kind {
+            let handle = &triangles[triangles.len() - 2..];
+            let xs = handle.iter().flat_map(|t| t.0.iter().map(|&(p, _)| p[0]));
+            handle_right = Some(xs.fold(f64::MIN, f64::max));
+        }
+    }
+    handle_right.unwrap()
+}
+
+// Sets the given slider with a logarithmic scale, such that its inner rect spans -99.0..99.0.
+fn set_log_slider(ui: &mut Ui, slider: widget::Slider<f64>, id: widget::Id) -> Option<f64> {
+    let ui = &mut ui.set_widgets();
+    slider
+        .scale(widget::slider::Scale::Logarithmic)
+        .w_h(200.0, 30.0)
+        .x_y(0.0, 0.0)
+        .border(1.0)
+        .set(id, ui)
+}
+
+#[test]
+fn slider_bound_to_a_field_should_update_it_and_reflect_it() {
+    use Borderable;
+
+    struct App {
+        value: f64,
+    }
+
+    let ui = &mut windowless_ui();
+    let slider = ui.widget_id_generator().next();
+    let mut app = App { value: 0.25 };
+    let set_slider = |ui: &mut Ui, app: &mut App| {
+        let ui = &mut ui.set_widgets();
+        widget::Slider::bind(&mut app.value, 0.0, 1.0)
+            .w_h(200.0, 30.0)
+            .x_y(0.0, 0.0)
+            .border(1.0)
+            .set(slider, ui);
+    };
+    set_slider(ui, &mut app);
+    // The inner rect of the slider spans -99.0..99.0.
+    assert_eq!(slider_handle_right(ui), -99.0 + 198.0 * 0.25);
+
+    // Pressing upon the slider moves the handle and updates the bound field.
+    move_mouse_to_abs_coordinates(50.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_slider(ui, &mut app);
+    let expected = (50.0 + 99.0) / 198.0;
+    assert!((app.value - expected).abs() < 1e-6);
+    release_mouse_button(MouseButton::Left, ui);
+
+    // Changes to the field are reflected by the handle.
+    app.value = 0.5;
+    set_slider(ui, &mut app);
+    assert_eq!(slider_handle_right(ui), 0.0);
+}
+
+#[test]
+fn logarithmic_slider_should_map_its_value_log_linearly() {
+    let ui = &mut windowless_ui();
+    let slider = ui.widget_id_generator().next();
+    let mut value = 20.0;
+    let set_slider = |ui: &mut Ui, value: &mut f64| {
+        set_log_slider(ui, widget::Slider::bind(value, 20.0, 20_000.0), slider);
+    };
+    set_slider(ui, &mut value);
+
+    // Pressing the middle of the track selects the geometric mean of the range.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_slider(ui, &mut value);
+    assert!((value - (20.0f64 * 20_000.0).sqrt()).abs() < 1e-3);
+    release_mouse_button(MouseButton::Left, ui);
+
+    // Each decade spans a third of the track, which spans -99.0..99.0.
+    value = 2_000.0;
+    set_slider(ui, &mut value);
+    assert!((slider_handle_right(ui) - 33.0).abs() < 1e-6);
+}
+
+#[test]
+fn virtual_keyboard_should_type_into_the_widget_capturing_the_keyboard() {
+    let ui = &mut windowless_ui();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let text_edit = ui.widget_id_generator().next();
+    let keyboard = ui.widget_id_generator().next();
+    let mut text = String::new();
+    let set_widgets = |ui: &mut Ui, text: &mut String| {
+        let ui = &mut ui.set_widgets();
+        if let Some(new_text) = widget::TextEdit::new(text)
+            .w_h(200.0, 40.0)
+            .x_y(0.0, 100.0)
+            .set(text_edit, ui)
+        {
+            *text = new_text;
+        }
+        widget::VirtualKeyboard::new()
+            .w_h(400.0, 160.0)
+            .x_y(0.0, -100.0)
+            .set(keyboard, ui)
+    };
+    set_widgets(ui, &mut text);
+
+    // Focus the `TextEdit`.
+    move_mouse_to_abs_coordinates(0.0, 100.0, ui);
+    left_click_mouse(ui);
+    set_widgets(ui, &mut text);
+
+    // Each key is 40 units wide and each row 40 units high. The 'a' key begins the second row.
+    move_mouse_to_abs_coordinates(-160.0, -80.0, ui);
+    left_click_mouse(ui);
+    let events = set_widgets(ui, &mut text);
+    assert_eq!(events, vec![Input::Text("a".to_string())]);
+    set_widgets(ui, &mut text);
+    assert_eq!(text, "a");
+
+    // The backspace key ends the third row.
+    move_mouse_to_abs_coordinates(170.0, -120.0, ui);
+    left_click_mouse(ui);
+    let events = set_widgets(ui, &mut text);
+    let backspace = Button::Keyboard(Key::Backspace);
+    assert_eq!(
+        events,
+        vec![Input::Press(backspace), Input::Release(backspace)]
+    );
+    set_widgets(ui, &mut text);
+    assert_eq!(text, "");
+}
+
+#[test]
+fn time_since_last_input_should_grow_while_idle_and_reset_upon_input() {
+    use std::time::Duration;
+
+    let ui = &mut windowless_ui();
+    ui.tick(Duration::from_millis(500));
+    move_mouse_to_abs_coordinates(10.0, 10.0, ui);
+    assert_eq!(ui.time_since_last_input(), Duration::from_secs(0));
+
+    // Events that are not user input do not reset the idle time.
+    ui.tick(Duration::from_secs(2));
+    ui.handle_event(Input::Redraw);
+    ui.tick(Duration::from_secs(1));
+    assert_eq!(ui.time_since_last_input(), Duration::from_secs(3));
+
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(ui.time_since_last_input(), Duration::from_secs(0));
+}
+
+#[test]
+fn canvas_grid_background_should_draw_lines_within_the_kid_area_offset_by_scrolling() {
+    use position::Rect;
+    use widget::canvas::grid_lines;
+
+    // A line every 20 units across -100..100 and -50..50, including those on the edges.
+    let area = Rect::from_xy_dim([0.0, 0.0], [200.0, 100.0]);
+    let (xs, ys) = grid_lines(area, [0.0, 0.0], 20.0);
+    assert_eq!(xs.len(), 11);
+    assert_eq!(ys, vec![-40.0, -20.0, 0.0, 20.0, 40.0]);
+
+    // Scrolling offsets the origin of the grid.
+    let (xs, ys) = grid_lines(area, [5.0, -10.0], 20.0);
+    assert_eq!(xs.len(), 10);
+    assert_eq!(xs[0], -95.0);
+    assert_eq!(ys, vec![-50.0, -30.0, -10.0, 10.0, 30.0, 50.0]);
+
+    // Each line is drawn as a pair of triangles.
+    let ui = &mut windowless_ui();
+    let canvas = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 100.0)
+            .x_y(0.0, 0.0)
+            .pad(0.0)
+            .grid_background(20.0, ::color::GREY)
+            .set(canvas, ui);
+    }
+    let mut primitives = ui.draw();
+    let mut num_triangles = None;
+    while let Some(primitive) = primitives.next() {
+        if let render::PrimitiveKind::TrianglesSingleColor { triangles, .. } = primitive.kind {
+            num_triangles = Some(triangles.len());
+        }
+    }
+    assert_eq!(num_triangles, Some(2 * (11 + 5)));
+}
+
+#[test]
+fn right_clicking_a_widget_should_open_its_context_menu_and_report_the_chosen_item() {
+    let items = [
+        String::from("Cut"),
+        String::from("Copy"),
+        String::from("Paste"),
+    ];
+    let items: Vec<&str> = items.iter().map(|item| &item[..]).collect();
+
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+    let other = ui.widget_id_generator().next();
+    let below = ui.widget_id_generator().next();
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 100.0)
+            .x_y(-100.0, 0.0)
+            .set(button, ui);
+        let chosen = ui.context_menu(button, &items);
+        // The menu does not affect the positioning of the widgets that follow.
+        widget::Button::new()
+            .w_h(10.0, 10.0)
+            .down(5.0)
+            .set(below, ui);
+        widget::Button::new()
+            .w_h(100.0, 100.0)
+            .x_y(100.0, 200.0)
+            .set(other, ui);
+        chosen
+    };
+    set_widgets(ui);
+
+    // Right-clicking a widget without a context menu opens nothing.
+    move_mouse_to_abs_coordinates(100.0, 200.0, ui);
+    press_mouse_button(MouseButton::Right, ui);
+    release_mouse_button(MouseButton::Right, ui);
+    assert_eq!(set_widgets(ui), None);
+    assert_eq!(ui.open_context_menu(), None);
+
+    // The menu opens at the cursor with its top left corner beneath the mouse.
+    move_mouse_to_abs_coordinates(-110.0, 20.0, ui);
+    press_mouse_button(MouseButton::Right, ui);
+    release_mouse_button(MouseButton::Right, ui);
+    assert_eq!(set_widgets(ui), None);
+    assert_eq!(ui.open_context_menu(), Some((button, [-110.0, 20.0])));
+    assert_eq!(ui.xy_of(below), Some([-145.0, -60.0]));
+
+    // The open menu is an overlay, so the widget beneath it does not receive the mouse.
+    assert_eq!(ui.overlays().len(), 1);
+    move_mouse_to_abs_coordinates(-105.0, 15.0, ui);
+    let under_mouse = ui.global_input().current.widget_under_mouse;
+    assert!(under_mouse.is_some() && under_mouse != Some(button));
+
+    // Choosing the first item reports its index during the same update and closes the menu.
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui), Some(0));
+    assert_eq!(ui.open_context_menu(), None);
+    assert!(ui.overlays().is_empty());
+    assert_eq!(set_widgets(ui), None);
+
+    // Clicking elsewhere closes the menu without a selection.
+    move_mouse_to_abs_coordinates(-110.0, 20.0, ui);
+    press_mouse_button(MouseButton::Right, ui);
+    release_mouse_button(MouseButton::Right, ui);
+    set_widgets(ui);
+    assert_eq!(ui.overlays().len(), 1);
+    move_mouse_to_abs_coordinates(100.0, 200.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui), None);
+    assert_eq!(ui.open_context_menu(), None);
+    assert!(ui.overlays().is_empty());
+}
+
+#[test]
+fn aspect_ratio_should_derive_the_missing_dimension() {
+    let ui = &mut windowless_ui();
+    let by_width = ui.widget_id_generator().next();
+    let by_height = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w(160.0)
+            .aspect_ratio(16.0 / 9.0)
+            .set(by_width, ui);
+        widget::Button::new()
+            .h(90.0)
+            .aspect_ratio(16.0 / 9.0)
+            .set(by_height, ui);
+    }
+    assert_eq!(ui.wh_of(by_width), Some([160.0, 90.0]));
+    assert_eq!(ui.wh_of(by_height), Some([160.0, 90.0]));
+}
+
+#[test]
+fn aspect_ratio_should_be_ignored_when_both_dimensions_are_given() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+    for _ in 0..2 {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 100.0)
+            .aspect_ratio(16.0 / 9.0)
+            .set(button, ui);
+    }
+    assert_eq!(ui.wh_of(button), Some([100.0, 100.0]));
+}
+
+#[test]
+fn aspect_ratio_should_reject_ratios_that_are_not_positive_and_finite() {
+    let is_rejected = |w_over_h: Scalar| {
+        std::panic::catch_unwind(|| widget::Button::new().aspect_ratio(w_over_h)).is_err()
+    };
+    assert!(is_rejected(0.0));
+    assert!(is_rejected(-1.0));
+    assert!(is_rejected(f64::INFINITY));
+    assert!(is_rejected(f64::NAN));
+    assert!(!is_rejected(16.0 / 9.0));
+}
+
+#[test]
+fn named_ids_should_map_to_the_same_widgets_regardless_of_generation_order() {
+    use std::any::TypeId;
+
+    // Construct the same named widgets, generating their ids in a different order each run.
+    fn run(names: &[&str]) -> (Ui, Vec<(String, TypeId)>) {
+        let mut ui = windowless_ui();
+        for name in names {
+            ui.widget_id_generator().named(name);
+        }
+        {
+            let ui = &mut ui.set_widgets();
+            let button = ui.widget_id_generator().named("button");
+            let slider = ui.widget_id_generator().named("slider");
+            widget::Button::new().w_h(10.0, 10.0).set(button, ui);
+            widget::Slider::new(0.5, 0.0, 1.0)
+                .w_h(10.0, 10.0)
+                .set(slider, ui);
+        }
+        let kinds = ui
+            .widget_graph()
+            .named_ids()
+            .map(|(name, &id)| {
+                let container = ui.widget_graph().widget(id).unwrap();
+                (name.clone(), container.type_id)
+            })
+            .collect();
+        (ui, kinds)
+    }
+
+    let (_, a_kinds) = run(&["button", "slider"]);
+    let (_, b_kinds) = run(&["slider", "button"]);
+    assert_eq!(a_kinds, b_kinds);
+    assert_eq!(a_kinds.len(), 2);
+}
+
+#[test]
+fn named_ids_should_be_kept_while_their_widgets_are_hidden() {
+    use std::any::TypeId;
+
+    // Request and set the named button only during the updates in which it is shown.
+    fn run(shown: &[bool]) -> (Option<widget::Id>, Vec<(widget::Id, TypeId)>) {
+        let mut ui = windowless_ui();
+        for &show_button in shown {
+            let ui = &mut ui.set_widgets();
+            if show_button {
+                let button = ui.widget_id_generator().named("button");
+                widget::Button::new().w_h(10.0, 10.0).set(button, ui);
+            }
+        }
+        let graph = ui.widget_graph();
+        let kinds = (0..graph.node_count())
+            .map(widget::Id::new)
+            .filter_map(|id| graph.widget(id).map(|container| (id, container.type_id)))
+            .collect();
+        (graph.named_id("button"), kinds)
+    }
+
+    let (a_button, a_kinds) = run(&[true, true, true, true]);
+    let (b_button, b_kinds) = run(&[true, false, false, true]);
+    assert_eq!(a_button, b_button);
+    assert_eq!(a_kinds, b_kinds);
+}
+
+#[test]
+fn min_and_max_width_should_limit_the_resolved_width() {
+    fn width_in_window(window_w: f64) -> Option<f64> {
+        let mut ui = UiBuilder::new([window_w, 400.0]).build();
+        let panel = ui.widget_id_generator().next();
+        {
+            let ui = &mut ui.set_widgets();
+            let window = ui.window;
+            widget::Canvas::new()
+                .w_of(window)
+                .h(100.0)
+                .min_width(600.0)
+                .max_width(1000.0)
+                .set(panel, ui);
+        }
+        ui.w_of(panel)
+    }
+    assert_eq!(width_in_window(500.0), Some(600.0));
+    assert_eq!(width_in_window(800.0), Some(800.0));
+    assert_eq!(width_in_window(2000.0), Some(1000.0));
+}
+
+#[test]
+fn widget_children_should_yield_the_direct_widget_children_of_a_node() {
+    let ui = &mut windowless_ui();
+    let canvas = ui.widget_id_generator().next();
+    let a = ui.widget_id_generator().next();
+    let b = ui.widget_id_generator().next();
+    let grandchild = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new().w_h(200.0, 200.0).set(canvas, ui);
+        widget::Canvas::new()
+            .w_h(50.0, 50.0)
+            .parent(canvas)
+            .set(a, ui);
+        widget::Button::new()
+            .w_h(50.0, 50.0)
+            .parent(canvas)
+            .set(b, ui);
+        widget::Button::new()
+            .w_h(10.0, 10.0)
+            .parent(a)
+            .set(grandchild, ui);
+    }
+    let graph = ui.widget_graph();
+    let children: Vec<_> = graph.widget_children(canvas).collect();
+    assert!(children.contains(&a));
+    assert!(children.contains(&b));
+    assert!(!children.contains(&grandchild));
+    assert!(children.iter().all(|&child| graph.widget(child).is_some()));
+    assert!(children
+        .iter()
+        .all(|&child| graph.depth_parent(child) == Some(canvas)));
+
+    // Placeholder nodes are skipped, even while they remain depth children of the node.
+    *::ui::widget_graph_mut(ui).node_mut(b).unwrap() = graph::Node::Placeholder;
+    let graph = ui.widget_graph();
+    assert_eq!(graph.depth_parent(b), Some(canvas));
+    let children: Vec<_> = graph.widget_children(canvas).collect();
+    assert!(children.contains(&a));
+    assert!(!children.contains(&b));
+}
+
+#[test]
+fn scrubber_should_fill_its_ranges_and_seek_upon_release() {
+    let ui = &mut windowless_ui();
+    let scrubber = ui.widget_id_generator().next();
+    let set_scrubber = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Scrubber::new(30.0, 60.0, 100.0)
+            .w_h(200.0, 20.0)
+            .x_y(0.0, 0.0)
+            .set(scrubber, ui)
+    };
+    assert_eq!(set_scrubber(ui), None);
+
+    // The track, buffered and played fills all begin at the left edge of the scrubber.
+    let fills: Vec<_> = ui
+        .widget_graph()
+        .widget_children(scrubber)
+        .filter_map(|child| ui.rect_of(child))
+        .map(|rect| (rect.left(), rect.w()))
+        .collect();
+    assert_eq!(fills.len(), 3);
+    for &w in &[200.0, 120.0, 60.0] {
+        assert!(fills.contains(&(-100.0, w)));
+    }
+
+    // Pressing upon the track only previews the position, seeking once released.
+    move_mouse_to_abs_coordinates(50.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_scrubber(ui), None);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_scrubber(ui), Some(75.0));
+    assert_eq!(set_scrubber(ui), None);
+}
+
+#[test]
+fn widget_descendants_should_yield_each_subtree_depth_first() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (root, a, a1, a2, b, b1) = (
+        ids.next(),
+        ids.next(),
+        ids.next(),
+        ids.next(),
+        ids.next(),
+        ids.next(),
+    );
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([200.0, 200.0]).set(root, ui);
+        for &(id, parent) in &[(a, root), (a1, a), (a2, a), (b, root), (b1, b)] {
+            widget::Rectangle::fill([10.0, 10.0])
+                .parent(parent)
+                .set(id, ui);
+        }
+    }
+    let graph = ui.widget_graph();
+
+    // Each child is followed by its own subtree before its next sibling. Siblings are walked in
+    // the order of `widget_children`, i.e. the most recently attached first.
+    let descendants: Vec<_> = graph.widget_descendants(root).collect();
+    assert_eq!(descendants, vec![b, b1, a, a2, a1]);
+    assert_eq!(graph.widget_descendants(a1).next(), None);
+
+    // Placeholder nodes are skipped along with their subtrees.
+    *::ui::widget_graph_mut(ui).node_mut(a).unwrap() = graph::Node::Placeholder;
+    let descendants: Vec<_> = ui.widget_graph().widget_descendants(root).collect();
+    assert_eq!(descendants, vec![b, b1]);
+}
+
+#[test]
+fn theme_spacing_should_determine_the_tab_bar_height_and_slider_label_padding() {
+    let ui = &mut windowless_ui();
+    let tabs = ui.widget_id_generator().next();
+    let tab = ui.widget_id_generator().next();
+    let slider = ui.widget_id_generator().next();
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Tabs::new(&[(tab, "Tab")])
+            .label_font_size(12)
+            .w_h(200.0, 200.0)
+            .x_y(-200.0, 0.0)
+            .set(tabs, ui);
+        widget::Slider::new(0.5, 0.0, 1.0)
+            .w_h(200.0, 30.0)
+            .x_y(200.0, 0.0)
+            .set(slider, ui);
+    };
+    // The tab bar sits above the kid area.
+    let bar_h = |ui: &Ui| ui.rect_of(tabs).unwrap().h() - ui.kid_area_of(tabs).unwrap().h();
+    let label_pad =
+        |ui: &Ui| ui.kid_area_of(slider).unwrap().left() - ui.rect_of(slider).unwrap().left();
+
+    set_widgets(ui);
+    assert_eq!(bar_h(ui), 12.0 + 4.0 * 2.0);
+    assert_eq!(label_pad(ui), 10.0);
+
+    ui.theme.spacing_xs = 8.0;
+    ui.theme.spacing_md = 2.0;
+    set_widgets(ui);
+    assert_eq!(bar_h(ui), 12.0 + 8.0 * 2.0);
+    assert_eq!(label_pad(ui), 2.0);
+}
+
+#[test]
+fn scrollable_bounding_box_should_be_unaffected_by_the_scroll_offset() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (canvas, inner, a, b) = (ids.next(), ids.next(), ids.next(), ids.next());
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .x_y(0.0, 0.0)
+            .scroll_kids_vertically()
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 300.0])
+            .mid_top_of(canvas)
+            .set(a, ui);
+        // Positioned relatively to a scrolled sibling.
+        widget::Rectangle::fill([100.0, 100.0])
+            .down_from(a, 0.0)
+            .set(b, ui);
+        // A nested scrollable canvas whose own kids are cropped to its area.
+        widget::Canvas::new()
+            .w_h(50.0, 50.0)
+            .down_from(b, 0.0)
+            .scroll_kids_vertically()
+            .parent(canvas)
+            .set(inner, ui);
+    };
+    set_widgets(ui);
+    set_widgets(ui);
+    let unscrolled = ui.kids_bounding_box(canvas).unwrap();
+    assert_eq!(ui.scrollable_bounding_box(canvas), Some(unscrolled));
+    assert_eq!(unscrolled.top(), 100.0);
+    assert_eq!(unscrolled.bottom(), 100.0 - 450.0);
+
+    ui.scroll_widget(canvas, [0.0, 50.0]);
+    set_widgets(ui);
+    set_widgets(ui);
+    let scrolled = ui.kids_bounding_box(canvas).unwrap();
+    assert_ne!(scrolled, unscrolled);
+    assert_eq!(ui.scrollable_bounding_box(canvas), Some(unscrolled));
+}
+
+#[test]
+fn focused_splitter_divider_should_step_its_ratio_with_the_arrow_keys() {
+    let ui = &mut windowless_ui();
+    let splitter = ui.widget_id_generator().next();
+    let set_splitter = |ui: &mut Ui, ratio: f64| {
+        let ui = &mut ui.set_widgets();
+        widget::Splitter::new(ratio)
+            .ratio_limits(0.1, 0.6)
+            .keyboard_step(0.05)
+            .divider_thickness(6.0)
+            .w_h(400.0, 200.0)
+            .x_y(0.0, 0.0)
+            .set(splitter, ui)
+    };
+    let press_right = |ui: &mut Ui| {
+        ui.handle_event(Input::Press(Button::Keyboard(Key::Right)));
+        ui.handle_event(Input::Release(Button::Keyboard(Key::Right)));
+    };
+
+    // Clicking the divider in the middle of the splitter focuses it.
+    let event = set_splitter(ui, 0.5);
+    assert_eq!(event.new_ratio, None);
+    assert_eq!(event.first.right(), -3.0);
+    assert_eq!(event.second.left(), 3.0);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_splitter(ui, 0.5);
+
+    press_right(ui);
+    let ratio = set_splitter(ui, 0.5).new_ratio.unwrap();
+    assert!((ratio - 0.55).abs() < 1e-9);
+
+    // The ratio is clamped to the maximum.
+    press_right(ui);
+    press_right(ui);
+    assert_eq!(set_splitter(ui, ratio).new_ratio, Some(0.6));
+    press_right(ui);
+    assert_eq!(set_splitter(ui, 0.6).new_ratio, None);
+}
+
+#[test]
+fn slider_orientation_should_override_the_inferred_orientation() {
+    use widget::slider::Orientation;
+    use Borderable;
+
+    let ui = &mut windowless_ui();
+    let slider = ui.widget_id_generator().next();
+    let press_square_slider = |ui: &mut Ui, maybe_orientation: Option<Orientation>| {
+        move_mouse_to_abs_coordinates(25.0, 0.0, ui);
+        press_mouse_button(MouseButton::Left, ui);
+        let value = {
+            let ui = &mut ui.set_widgets();
+            let mut builder = widget::Slider::new(0.0, 0.0, 1.0)
+                .w_h(100.0, 100.0)
+                .x_y(0.0, 0.0)
+                .border(0.0);
+            if let Some(orientation) = maybe_orientation {
+                builder = builder.orientation(orientation);
+            }
+            builder.set(slider, ui)
+        };
+        release_mouse_button(MouseButton::Left, ui);
+        value
+    };
+    widget::Slider::new(0.0, 0.0, 1.0)
+        .w_h(100.0, 100.0)
+        .x_y(0.0, 0.0)
+        .set(slider, &mut ui.set_widgets());
+
+    // A square slider is vertical by default.
+    assert_eq!(press_square_slider(ui, None), Some(0.5));
+    assert_eq!(
+        press_square_slider(ui, Some(Orientation::Horizontal)),
+        Some(0.75)
+    );
+}
+
+#[test]
+fn markdown_should_enlarge_headings_and_indent_bullets_behind_a_marker() {
+    let ui = &mut windowless_ui();
+    ui.fonts
+        .insert_from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+        ))
+        .unwrap();
+    let markdown = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Markdown::new("# Help\n\nSome **bold** text.\n\n- An `item`\n")
+            .w_h(300.0, 300.0)
+            .x_y(0.0, 0.0)
+            .set(markdown, ui);
+    }
+
+    // Find each `Text` by its string, along with its font size and rect.
+    let text = |string: &str| {
+        let graph = ui.widget_graph();
+        graph
+            .widget_descendants(markdown)
+            .find_map(|id| {
+                let container = graph.widget(id)?;
+                let unique = container.unique_widget_state::<widget::Text>()?;
+                if unique.state.string == string {
+                    Some((unique.style.font_size.unwrap(), container.rect))
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    };
+    let (heading_size, heading) = text("Help");
+    let (paragraph_size, paragraph) = text("Some bold text.");
+    let (bullet_size, bullet) = text("An item");
+    let (_, marker) = text("\u{2022}");
+    assert!(heading_size > paragraph_size);
+    assert!(heading.h() > paragraph.h());
+    assert_eq!(bullet_size, paragraph_size);
+
+    // The bullet is indented, with the marker to its left on the same line.
+    let left = ui.kid_area_of(markdown).unwrap().left();
+    assert_eq!(paragraph.left(), left);
+    assert_eq!(bullet.left(), left + ui.theme.spacing_lg);
+    assert!(marker.left() > left && marker.right() <= bullet.left());
+    assert_eq!(marker.top(), bullet.top());
+    assert!(heading.bottom() > paragraph.top() && paragraph.bottom() > bullet.top());
+}
+
+#[test]
+fn logarithmic_slider_should_fall_back_to_linear_for_a_non_positive_range() {
+    let ui = &mut windowless_ui();
+    let slider = ui.widget_id_generator().next();
+    let set_slider = |ui: &mut Ui, min: f64, max: f64| {
+        set_log_slider(ui, widget::Slider::new(min, min, max), slider)
+    };
+    set_slider(ui, 0.0, 100.0);
+    assert_eq!(slider_handle_right(ui), -99.0);
+
+    // Pressing the middle of the track selects the linear midpoint rather than `NaN`.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_slider(ui, 0.0, 100.0), Some(50.0));
+    release_mouse_button(MouseButton::Left, ui);
+
+    // An empty range places the handle at the start of the track.
+    set_slider(ui, 10.0, 10.0);
+    assert_eq!(slider_handle_right(ui), -99.0);
+}
+
+#[test]
+fn overlays_should_route_clicks_to_the_topmost_and_dismiss_from_the_top_down() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (button, menu, submenu) = (ids.next(), ids.next(), ids.next());
+    let set_widgets = |ui: &mut Ui, opening: bool| {
+        let ui = &mut ui.set_widgets();
+        let clicks = widget::Button::new()
+            .w_h(100.0, 100.0)
+            .x_y(-200.0, 0.0)
+            .set(button, ui)
+            .was_clicked();
+        // Each overlay remains instantiated while it is open.
+        let open = ui.overlays().to_vec();
+        for &(id, x) in &[(menu, 0.0), (submenu, 100.0)] {
+            if opening || open.contains(&id) {
+                widget::Rectangle::fill([100.0, 100.0])
+                    .x_y(x, 0.0)
+                    .floating(true)
+                    .set(id, ui);
+                ui.open_overlay(id);
+            }
+        }
+        clicks
+    };
+    let click_at = |ui: &mut Ui, x: f64| {
+        move_mouse_to_abs_coordinates(x, 0.0, ui);
+        left_click_mouse(ui);
+    };
+    set_widgets(ui, true);
+    set_widgets(ui, false);
+    assert_eq!(ui.overlays(), &[menu, submenu][..]);
+
+    // Clicking within the submenu keeps both open.
+    click_at(ui, 100.0);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(submenu));
+    assert!(ui.dismissed_overlays().is_empty());
+    set_widgets(ui, false);
+    assert_eq!(ui.overlays(), &[menu, submenu][..]);
+
+    // Clicking outside of both dismisses both from the top down, without clicking the button.
+    click_at(ui, -200.0);
+    assert_eq!(ui.dismissed_overlays(), &[submenu, menu][..]);
+    assert!(ui.overlays().is_empty());
+    assert!(!set_widgets(ui, false));
+    assert!(ui.overlays().is_empty());
+}
+
+#[test]
+fn tab_should_move_the_keyboard_focus_between_widgets_that_accept_it() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (button, text_edit, unfocusable, rectangle, text_box) =
+        (ids.next(), ids.next(), ids.next(), ids.next(), ids.next());
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 30.0)
+            .x_y(-150.0, 0.0)
+            .set(button, ui);
+        widget::TextEdit::new("text")
+            .w_h(100.0, 30.0)
+            .x_y(-50.0, 0.0)
+            .set(text_edit, ui);
+        widget::Button::new()
+            .w_h(100.0, 30.0)
+            .x_y(50.0, 0.0)
+            .focusable(false)
+            .set(unfocusable, ui);
+        widget::Rectangle::fill([100.0, 30.0])
+            .x_y(150.0, 0.0)
+            .focusable(true)
+            .set(rectangle, ui);
+        // Opting out also opts out the `TextEdit` within the `TextBox`.
+        widget::TextBox::new("text")
+            .w_h(100.0, 30.0)
+            .x_y(250.0, 0.0)
+            .focusable(false)
+            .set(text_box, ui);
+    }
+    let press_tab = |ui: &mut Ui| {
+        ui.handle_event(Input::Press(Button::Keyboard(Key::Tab)));
+        ui.handle_event(Input::Release(Button::Keyboard(Key::Tab)));
+        ui.global_input().current.widget_capturing_keyboard
+    };
+
+    assert_eq!(press_tab(ui), Some(button));
+    assert_eq!(press_tab(ui), Some(text_edit));
+    assert_eq!(press_tab(ui), Some(rectangle));
+    assert_eq!(press_tab(ui), Some(button));
+
+    // The `Tab` press is consumed by the navigation rather than reaching the focused widget.
+    assert_eq!(ui.widget_input(text_edit).presses().key().count(), 0);
+    assert_eq!(ui.widget_input(rectangle).presses().key().count(), 0);
+
+    // `Shift+Tab` moves the focus backwards, wrapping around the start.
+    ui.handle_event(Input::Press(Button::Keyboard(Key::LShift)));
+    assert_eq!(press_tab(ui), Some(rectangle));
+    assert_eq!(press_tab(ui), Some(text_edit));
+    ui.handle_event(Input::Release(Button::Keyboard(Key::LShift)));
+    assert_eq!(press_tab(ui), Some(rectangle));
+}
+
+#[test]
+fn focused_button_should_be_triggered_by_return_and_space() {
+    fn press_key(key: Key, ui: &mut Ui) {
+        ui.handle_event(Input::Press(Button::Keyboard(key)));
+        ui.handle_event(Input::Release(Button::Keyboard(key)));
+    }
+
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+    let set_button = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(button, ui)
+            .was_clicked()
+    };
+
+    // Keys pressed without focus do not trigger the button.
+    assert!(!set_button(ui));
+    press_key(Key::Return, ui);
+    assert!(!set_button(ui));
+
+    press_key(Key::Tab, ui);
+    assert_eq!(
+        ui.global_input().current.widget_capturing_keyboard,
+        Some(button)
+    );
+    assert!(!set_button(ui));
+    press_key(Key::Return, ui);
+    assert!(set_button(ui));
+    press_key(Key::Space, ui);
+    assert!(set_button(ui));
+    press_key(Key::A, ui);
+    assert!(!set_button(ui));
+}
+
+#[test]
+fn polygon_with_bounding_rect_should_occupy_the_given_rect() {
+    let ui = &mut windowless_ui();
+    let polygon = ui.widget_id_generator().next();
+    let rect = Rect::from_corners([-20.0, -10.0], [40.0, 30.0]);
+    {
+        let ui = &mut ui.set_widgets();
+        let points = [[-20.0, -10.0], [40.0, -10.0], [10.0, 30.0]];
+        widget::Polygon::fill(points.iter().cloned())
+            .with_bounding_rect(rect)
+            .set(polygon, ui);
+    }
+    assert_eq!(ui.rect_of(polygon), Some(rect));
+}
+
+#[test]
+fn hovering_a_text_edit_should_request_the_text_cursor() {
+    let ui = &mut windowless_ui();
+    let text_edit = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::TextEdit::new("text")
+            .w_h(100.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(text_edit, ui);
+    }
+
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    assert_eq!(ui.mouse_cursor(), cursor::MouseCursor::Text);
+    move_mouse_to_abs_coordinates(200.0, 200.0, ui);
+    assert_eq!(ui.mouse_cursor(), cursor::MouseCursor::Arrow);
+}
+
+#[test]
+fn buttons_should_only_request_a_hover_cursor_when_given_one() {
+    let ui = &mut windowless_ui();
+    let (plain, hand) = {
+        let mut ids = ui.widget_id_generator();
+        (ids.next(), ids.next())
+    };
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(plain, ui);
+        widget::Button::new()
+            .hover_cursor(cursor::MouseCursor::Hand)
+            .w_h(100.0, 30.0)
+            .x_y(0.0, 100.0)
+            .set(hand, ui);
+    }
+
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    assert_eq!(ui.mouse_cursor(), cursor::MouseCursor::Arrow);
+    move_mouse_to_abs_coordinates(0.0, 100.0, ui);
+    assert_eq!(ui.mouse_cursor(), cursor::MouseCursor::Hand);
+}
+
+#[test]
+fn layout_snapshot_should_contain_the_rect_of_each_set_widget() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (canvas, button) = (ids.next(), ids.next());
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(400.0, 300.0)
+            .x_y(50.0, 20.0)
+            .set(canvas, ui);
+        widget::Button::new()
+            .w_h(100.0, 40.0)
+            .middle_of(canvas)
+            .set(button, ui);
+    }
+
+    assert_layout_matches(
+        ui,
+        &[
+            (canvas, Rect::from_xy_dim([50.0, 20.0], [400.0, 300.0])),
+            (button, Rect::from_xy_dim([50.0, 20.0], [100.0, 40.0])),
+        ],
+    );
+    let snapshot = ui.layout_snapshot();
+    assert_eq!(snapshot.len(), ui.updated_widgets().len());
+    assert!(snapshot
+        .keys()
+        .zip(snapshot.keys().skip(1))
+        .all(|(a, b)| a < b));
+}
+
+#[test]
+fn gradient_oval_should_blend_from_the_center_color_to_the_edge_color() {
+    let ui = &mut windowless_ui();
+    let oval = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Oval::gradient([100.0, 100.0], color::RED, color::BLUE)
+            .x_y(0.0, 0.0)
+            .set(oval, ui);
+    }
+
+    let mut primitives = ui.draw();
+    let primitive = loop {
+        match primitives.next() {
+            Some(primitive) if primitive.id == oval => break primitive,
+            Some(_) => (),
+            None => panic!("no primitive for the oval"),
+        }
+    };
+    match primitive.kind {
+        render::PrimitiveKind::TrianglesMultiColor { triangles } => {
+            assert!(!triangles.is_empty());
+            for triangle in triangles {
+                let [(center, center_color), (_, a), (_, b)] = triangle.0;
+                assert_eq!(center, [0.0, 0.0]);
+                assert_eq!(center_color, color::RED.to_rgb());
+                assert_eq!((a, b), (color::BLUE.to_rgb(), color::BLUE.to_rgb()));
+            }
+        }
+        _ => panic!("expected the gradient oval to be drawn with multi-colored triangles"),
+    }
+}
+
+#[test]
+fn button_with_corner_radius_should_not_fill_its_corners() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .w_h(100.0, 40.0)
+            .x_y(0.0, 0.0)
+            .border(2.0)
+            .corner_radius(10.0)
+            .set(button, ui);
+    }
+
+    let graph = ui.widget_graph();
+    let mut primitives = ui.draw();
+    let mut triangles = vec![];
+    while let Some(primitive) = primitives.next() {
+        let mut graphic_parent = graph.graphic_parent(primitive.id);
+        while graphic_parent.is_some() && graphic_parent != Some(button) {
+            graphic_parent = graph.graphic_parent(graphic_parent.unwrap());
+        }
+        if graphic_parent.is_none() {
+            continue;
+        }
+        match primitive.kind {
+            render::PrimitiveKind::Rectangle { .. } => panic!("the button should be rounded"),
+            render::PrimitiveKind::TrianglesSingleColor {
+                triangles: tris, ..
+            } => triangles.extend(tris.iter().cloned()),
+            _ => (),
+        }
+    }
+
+    let over = |p: Point| widget::triangles::is_over(triangles.iter().cloned(), p);
+    assert!(over([0.0, 0.0]));
+    assert!(over([-49.0, 0.0]));
+    assert!(!over([-49.0, 19.0]));
+    assert!(!over([49.0, -19.0]));
+}
+
+#[test]
+fn oval_resolution_should_determine_the_number_of_edge_points() {
+    let ui = &mut windowless_ui();
+    let oval = ui.widget_id_generator().next();
+    for &resolution in &[3, 8, widget::oval::DEFAULT_RESOLUTION, 128] {
+        {
+            let ui = &mut ui.set_widgets();
+            widget::Oval::fill([100.0, 100.0])
+                .x_y(0.0, 0.0)
+                .resolution(resolution)
+                .set(oval, ui);
+        }
+
+        let mut primitives = ui.draw();
+        let mut edge_points = None;
+        while let Some(primitive) = primitives.next() {
+            if primitive.id != oval {
+                continue;
+            }
+            if let render::PrimitiveKind::TrianglesSingleColor { triangles, .. } = primitive.kind {
+                // Each triangle joins the middle to an edge between two consecutive edge points.
+                assert!(triangles.iter().all(|tri| tri.0[0] == [0.0, 0.0]));
+                edge_points = Some(triangles.len() + 1);
+            }
+        }
+        assert_eq!(edge_points, Some(resolution + 1));
+    }
+}
+
+#[test]
+fn clicking_text_should_produce_the_byte_index_of_the_clicked_character() {
+    fn set_text(ui: &mut Ui, id: widget::Id) -> Option<usize> {
+        let ui = &mut ui.set_widgets();
+        widget::Text::new("Hello\nworld")
+            .font_size(20)
+            .x_y(0.0, 0.0)
+            .set(id, ui)
+    }
+
+    let ui = &mut windowless_ui();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    let font_id = ui.fonts.insert_from_file(font_path).unwrap();
+    let id = ui.widget_id_generator().next();
+    assert_eq!(set_text(ui, id), None);
+
+    let rect = ui.rect_of(id).unwrap();
+    let font = ui.fonts.get(font_id).unwrap().clone();
+    let x_of_char = |line: &str, idx: usize| {
+        let start = ::text::line::width(&line[..idx], &font, 20);
+        let end = ::text::line::width(&line[..idx + 1], &font, 20);
+        rect.left() + (start + end) / 2.0
+    };
+    let first_line_y = rect.top() - 10.0;
+    let second_line_y = rect.bottom() + 10.0;
+
+    // The "e" in "Hello".
+    move_mouse_to_abs_coordinates(x_of_char("Hello", 1), first_line_y, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_text(ui, id), Some(1));
+
+    // The "r" in "world" is preceded by "Hello\nwo".
+    move_mouse_to_abs_coordinates(x_of_char("world", 2), second_line_y, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_text(ui, id), Some(8));
+
+    // Without a click there is no event.
+    assert_eq!(set_text(ui, id), None);
+}
+
+#[test]
+fn dragging_a_reorderable_tab_should_move_it_and_clamp_to_the_ends() {
+    fn set_tabs(
+        ui: &mut Ui,
+        tabs: widget::Id,
+        tab_list: &[(widget::Id, &str)],
+    ) -> Vec<widget::tabs::Event> {
+        let ui = &mut ui.set_widgets();
+        widget::Tabs::new(tab_list)
+            .w_h(300.0, 200.0)
+            .x_y(0.0, 0.0)
+            .bar_thickness(40.0)
+            .reorderable(true)
+            .set(tabs, ui)
+    }
+
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (tabs, a, b, c) = (ids.next(), ids.next(), ids.next(), ids.next());
+    let mut tab_list = vec![(a, "A"), (b, "B"), (c, "C")];
+    assert_eq!(set_tabs(ui, tabs, &tab_list), vec![]);
+
+    // Drag the first tab beyond the right end of the tab bar.
+    move_mouse_to_abs_coordinates(-100.0, 80.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(400.0, 80.0, ui);
+    let reorder = widget::tabs::Reorder { from: 0, to: 2 };
+    let events = set_tabs(ui, tabs, &tab_list);
+    assert_eq!(events, vec![widget::tabs::Event::Reorder(reorder)]);
+    reorder.apply(&mut tab_list);
+    assert_eq!(tab_list, vec![(b, "B"), (c, "C"), (a, "A")]);
+    assert_eq!(set_tabs(ui, tabs, &tab_list), vec![]);
+
+    // Dragging the same tab back beyond the left end moves it to the front.
+    move_mouse_to_abs_coordinates(-400.0, 80.0, ui);
+    let reorder = widget::tabs::Reorder { from: 2, to: 0 };
+    let events = set_tabs(ui, tabs, &tab_list);
+    assert_eq!(events, vec![widget::tabs::Event::Reorder(reorder)]);
+    reorder.apply(&mut tab_list);
+    assert_eq!(tab_list, vec![(a, "A"), (b, "B"), (c, "C")]);
+    release_mouse_button(MouseButton::Left, ui);
+}
+
+#[test]
+fn dragging_a_tab_should_do_nothing_unless_the_tabs_are_reorderable() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (tabs, a, b) = (ids.next(), ids.next(), ids.next());
+    let tab_list = [(a, "A"), (b, "B")];
+    let set_tabs = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Tabs::new(&tab_list)
+            .w_h(300.0, 200.0)
+            .x_y(0.0, 0.0)
+            .bar_thickness(40.0)
+            .set(tabs, ui)
+    };
+    set_tabs(ui);
+    move_mouse_to_abs_coordinates(-75.0, 80.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(75.0, 80.0, ui);
+    assert_eq!(set_tabs(ui), vec![]);
+}
+
+#[test]
+fn clicking_the_close_button_of_a_closable_tab_should_not_select_it() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (tabs, a, b) = (ids.next(), ids.next(), ids.next());
+    let tab_list = [(a, "A"), (b, "B")];
+    let set_tabs = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Tabs::new(&tab_list)
+            .w_h(300.0, 200.0)
+            .x_y(0.0, 0.0)
+            .bar_thickness(40.0)
+            .closable(true)
+            .set(tabs, ui)
+    };
+    set_tabs(ui);
+    assert!(ui.updated_widgets().contains(&a));
+
+    // The close button of the second tab sits at the right edge of the tab bar.
+    move_mouse_to_abs_coordinates(130.0, 80.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_tabs(ui), vec![widget::tabs::Event::Close(b)]);
+    assert!(ui.updated_widgets().contains(&a));
+    assert!(!ui.updated_widgets().contains(&b));
+
+    // Clicking the rest of the tab selects it.
+    move_mouse_to_abs_coordinates(50.0, 80.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_tabs(ui), vec![]);
+    assert!(ui.updated_widgets().contains(&b));
+}
+
+#[test]
+fn vertical_tab_rects_should_step_downwards_from_the_top_of_the_tab_bar() {
+    use widget::tabs::{Layout, TabRects};
+
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let tabs = [(ids.next(), "a"), (ids.next(), "b")];
+    let tab_bar = Rect::from_corners([0.0, 0.0], [50.0, 100.0]);
+    let mut rects = TabRects::new(&tabs, Layout::Vertical, tab_bar);
+    let mut tab_rects = Vec::new();
+    while let Some((rect, _, _)) = rects.next_with_id_and_label() {
+        tab_rects.push(rect);
+    }
+    assert_eq!(
+        tab_rects,
+        vec![
+            Rect::from_corners([0.0, 50.0], [50.0, 100.0]),
+            Rect::from_corners([0.0, 0.0], [50.0, 50.0]),
+        ]
+    );
+}
+
+#[test]
+fn rotated_vertical_tab_labels_should_only_need_the_height_of_a_line() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (tabs, a, b) = (ids.next(), ids.next(), ids.next());
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Tabs::new(&[(a, "Some long label"), (b, "Another long label")])
+            .w_h(300.0, 200.0)
+            .x_y(0.0, 0.0)
+            .layout_vertically()
+            .rotate_vertical_labels(true)
+            .pad(0.0)
+            .set(tabs, ui);
+    }
+
+    // The selected tab's canvas fills the area to the right of the tab bar.
+    let bar_w = ui.theme.font_size_medium as f64 + ui.theme.spacing_xs * 2.0;
+    let canvas = ui.rect_of(a).unwrap();
+    assert_eq!(canvas.w(), 300.0 - bar_w);
+    assert_eq!(canvas.left(), -150.0 + bar_w);
+}
+
+#[test]
+fn continuous_number_dialer_should_scrub_the_value_with_a_horizontal_drag() {
+    fn set_dialer(
+        ui: &mut Ui,
+        id: widget::Id,
+        mode: widget::number_dialer::DragMode,
+    ) -> Option<f64> {
+        let ui = &mut ui.set_widgets();
+        widget::NumberDialer::new(50.0, 0.0, 100.0, 0)
+            .w_h(200.0, 40.0)
+            .x_y(0.0, 0.0)
+            .drag_mode(mode)
+            .set(id, ui)
+    }
+
+    let ui = &mut windowless_ui();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let id = ui.widget_id_generator().next();
+    let (per_digit, continuous) = (
+        widget::number_dialer::DragMode::PerDigit,
+        widget::number_dialer::DragMode::Continuous,
+    );
+
+    // By default a horizontal drag leaves the value untouched.
+    assert_eq!(set_dialer(ui, id, per_digit), None);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(20.0, 0.0, ui);
+    assert_eq!(set_dialer(ui, id, per_digit), None);
+    release_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_dialer(ui, id, per_digit), None);
+
+    // A continuous drag of 20 pixels scrubs 2% of the range, accelerated threefold.
+    press_mouse_button(MouseButton::Left, ui);
+    move_mouse_to_abs_coordinates(40.0, 0.0, ui);
+    let value = set_dialer(ui, id, continuous).unwrap();
+    assert!((value - 56.0).abs() < 1e-9);
+
+    // Dragging back to the left decreases the value and clamps it to the minimum.
+    move_mouse_to_abs_coordinates(-400.0, 0.0, ui);
+    assert_eq!(set_dialer(ui, id, continuous), Some(0.0));
+    release_mouse_button(MouseButton::Left, ui);
+}
+
+#[test]
+fn scrubbing_an_integer_number_dialer_should_accumulate_fractional_steps() {
+    fn set_dialer(ui: &mut Ui, id: widget::Id, value: &mut i32) {
+        let ui = &mut ui.set_widgets();
+        widget::NumberDialer::bind(value, 0, 100, 0)
+            .w_h(200.0, 40.0)
+            .x_y(0.0, 0.0)
+            .drag_mode(widget::number_dialer::DragMode::Continuous)
+            .set(id, ui);
+    }
+
+    let ui = &mut windowless_ui();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let id = ui.widget_id_generator().next();
+    let mut value = 50;
+    set_dialer(ui, id, &mut value);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_dialer(ui, id, &mut value);
+
+    // Each 2 pixel drag scrubs 0.24 of a step, which accumulates rather than being truncated.
+    let mut values = vec![];
+    for i in 1..6 {
+        move_mouse_to_abs_coordinates(i as f64 * 2.0, 0.0, ui);
+        set_dialer(ui, id, &mut value);
+        values.push(value);
+    }
+    assert_eq!(values, vec![50, 50, 51, 51, 51]);
+
+    // Scrubbing back by the same distance returns to the original value.
+    values.clear();
+    for i in (0..5).rev() {
+        move_mouse_to_abs_coordinates(i as f64 * 2.0, 0.0, ui);
+        set_dialer(ui, id, &mut value);
+        values.push(value);
+    }
+    assert_eq!(values, vec![51, 51, 50, 50, 50]);
+    release_mouse_button(MouseButton::Left, ui);
+}
+
+#[test]
+fn typing_into_a_clicked_number_dialer_should_commit_on_return_and_revert_on_escape() {
+    fn set_dialer(ui: &mut Ui, id: widget::Id) -> Option<f64> {
+        let ui = &mut ui.set_widgets();
+        widget::NumberDialer::new(50.0, 0.0, 100.0, 1)
+            .w_h(200.0, 40.0)
+            .x_y(0.0, 0.0)
+            .set(id, ui)
+    }
+    fn press_key(key: Key, ui: &mut Ui) {
+        ui.handle_event(Input::Press(Button::Keyboard(key)));
+        ui.handle_event(Input::Release(Button::Keyboard(key)));
+    }
+
+    let ui = &mut windowless_ui();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let id = ui.widget_id_generator().next();
+    assert_eq!(set_dialer(ui, id), None);
+
+    // Clicking the dialer captures the keyboard.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_dialer(ui, id), None);
+
+    // Non-numeric characters are ignored and backspace removes the last character.
+    ui.handle_event(Input::Text("7a39".to_string()));
+    press_key(Key::Backspace, ui);
+    ui.handle_event(Input::Text(".5".to_string()));
+    assert_eq!(set_dialer(ui, id), None);
+    press_key(Key::Return, ui);
+    assert_eq!(set_dialer(ui, id), Some(73.5));
+
+    // Escape discards the entry.
+    ui.handle_event(Input::Text("20".to_string()));
+    press_key(Key::Escape, ui);
+    press_key(Key::Return, ui);
+    assert_eq!(set_dialer(ui, id), None);
+
+    // Committed values are clamped to the range of the dialer.
+    ui.handle_event(Input::Text("-20".to_string()));
+    press_key(Key::Return, ui);
+    assert_eq!(set_dialer(ui, id), Some(0.0));
+
+    // The entry is limited to the length of the widest displayable value, e.g. "100.0", and the
+    // committed value is rounded to the precision of the dialer.
+    ui.handle_event(Input::Text("12.345".to_string()));
+    press_key(Key::Return, ui);
+    assert_eq!(set_dialer(ui, id), Some(12.3));
+    ui.handle_event(Input::Text("9.96".to_string()));
+    press_key(Key::Return, ui);
+    assert_eq!(set_dialer(ui, id), Some(10.0));
+
+    // A disabled dialer does not accept the keyboard focus.
+    {
+        let ui = &mut ui.set_widgets();
+        widget::NumberDialer::new(50.0, 0.0, 100.0, 1)
+            .w_h(200.0, 40.0)
+            .x_y(0.0, 0.0)
+            .enabled(false)
+            .set(id, ui);
+    }
+    assert!(!ui.widget_graph().widget(id).unwrap().accepts_focus);
+}
+
+#[test]
+fn slider_steps_should_snap_the_value_to_the_nearest_detent() {
+    use Borderable;
+
+    let ui = &mut windowless_ui();
+    let slider = ui.widget_id_generator().next();
+    let press_slider = |ui: &mut Ui, x: f64, steps: usize, snap_strength: f32| {
+        move_mouse_to_abs_coordinates(x, 0.0, ui);
+        press_mouse_button(MouseButton::Left, ui);
+        let value = widget::Slider::new(0.0, 0.0, 1.0)
+            .w_h(200.0, 30.0)
+            .x_y(0.0, 0.0)
+            .border(0.0)
+            .steps(steps)
+            .snap_strength(snap_strength)
+            .set(slider, &mut ui.set_widgets());
+        release_mouse_button(MouseButton::Left, ui);
+        value
+    };
+    widget::Slider::new(0.0, 0.0, 1.0)
+        .w_h(200.0, 30.0)
+        .x_y(0.0, 0.0)
+        .set(slider, &mut ui.set_widgets());
+
+    // Five steps place a detent every quarter of the range.
+    assert_eq!(press_slider(ui, -43.75, 5, 1.0), Some(0.25));
+    assert_eq!(press_slider(ui, -18.75, 5, 1.0), Some(0.5));
+
+    // Soft snapping only applies near a detent.
+    assert_eq!(press_slider(ui, -43.75, 5, 0.5), Some(0.25));
+    assert_eq!(press_slider(ui, -18.75, 5, 0.5), Some(0.40625));
+
+    // Fewer than two steps leave the slider continuous.
+    assert_eq!(press_slider(ui, -43.75, 1, 1.0), Some(0.28125));
+    assert_eq!(press_slider(ui, -18.75, 0, 1.0), Some(0.40625));
+}
+
+#[test]
+fn range_slider_edges_dragged_past_each_other_should_clamp_or_swap() {
+    use widget::range_slider::Edge;
+    use Borderable;
+
+    let ui = &mut windowless_ui();
+    let slider = ui.widget_id_generator().next();
+    let drag_start_edge = |ui: &mut Ui, clamp_handles: bool| {
+        let (mut start, mut end) = (0.25, 0.5);
+        let mut set_slider = |ui: &mut Ui| {
+            let event = widget::RangeSlider::new(start, end, 0.0, 1.0)
+                .w_h(200.0, 30.0)
+                .x_y(0.0, 0.0)
+                .border(0.0)
+                .clamp_handles(clamp_handles)
+                .set(slider, &mut ui.set_widgets());
+            for (edge, value) in event {
+                match edge {
+                    Edge::Start => start = value,
+                    Edge::End => end = value,
+                }
+            }
+            (start, end)
+        };
+        set_slider(ui);
+
+        // Grab the start edge and drag it beyond the end edge.
+        move_mouse_to_abs_coordinates(-50.0, 0.0, ui);
+        press_mouse_button(MouseButton::Left, ui);
+        set_slider(ui);
+        move_mouse_to_abs_coordinates(50.0, 0.0, ui);
+        let range = set_slider(ui);
+        release_mouse_button(MouseButton::Left, ui);
+        set_slider(ui);
+        range
+    };
+
+    assert_eq!(drag_start_edge(ui, true), (0.5, 0.5));
+    assert_eq!(drag_start_edge(ui, false), (0.5, 0.75));
+}
+
+#[test]
+fn progress_bar_should_fill_in_proportion_to_its_clamped_value() {
+    use daggy::Walker;
+    use widget::slider::Orientation;
+
+    let ui = &mut windowless_ui();
+    let bar = ui.widget_id_generator().next();
+    let fill_rect = |ui: &mut Ui, value: f64, orientation: Orientation| {
+        widget::ProgressBar::new(value, 4.0)
+            .w_h(200.0, 100.0)
+            .x_y(0.0, 0.0)
+            .border(0.0)
+            .orientation(orientation)
+            .set(bar, &mut ui.set_widgets());
+        let graph = ui.widget_graph();
+        let background = graph
+            .depth_children(bar)
+            .iter(graph)
+            .nodes()
+            .next()
+            .unwrap();
+        let fill = graph
+            .depth_children(background)
+            .iter(graph)
+            .nodes()
+            .next()
+            .unwrap();
+        ui.rect_of(fill).unwrap()
+    };
+
+    let rect = fill_rect(ui, 1.0, Orientation::Horizontal);
+    assert_eq!(rect, Rect::from_corners([-100.0, -50.0], [-50.0, 50.0]));
+    let rect = fill_rect(ui, 3.0, Orientation::Vertical);
+    assert_eq!(rect, Rect::from_corners([-100.0, -50.0], [100.0, 25.0]));
+    let rect = fill_rect(ui, 9.0, Orientation::Horizontal);
+    assert_eq!(rect, Rect::from_corners([-100.0, -50.0], [100.0, 50.0]));
+    let rect = fill_rect(ui, -1.0, Orientation::Horizontal);
+    assert_eq!(rect.w(), 0.0);
+}
+
+#[test]
+fn circular_progress_should_draw_a_section_proportional_to_its_fraction() {
+    use widget::circular_progress::Kind;
+
+    let ui = &mut windowless_ui();
+    let progress = ui.widget_id_generator().next();
+    let draw_triangles = |ui: &mut Ui, fraction: f64, kind: Kind| {
+        widget::CircularProgress::new(fraction)
+            .kind(kind)
+            .thickness(10.0)
+            .w_h(100.0, 100.0)
+            .x_y(0.0, 0.0)
+            .set(progress, &mut ui.set_widgets());
+        let mut primitives = ui.draw();
+        let mut triangles = vec![];
+        while let Some(primitive) = primitives.next() {
+            if let render::PrimitiveKind::TrianglesSingleColor {
+                triangles: tris, ..
+            } = primitive.kind
+            {
+                triangles.extend(tris.iter().cloned());
+            }
+        }
+        triangles
+    };
+
+    // Nothing is drawn without progress.
+    assert!(draw_triangles(ui, 0.0, Kind::Ring).is_empty());
+
+    // A quarter ring spans a quarter of the circle's resolution along the edge of the circle.
+    let ring = draw_triangles(ui, 0.25, Kind::Ring);
+    assert_eq!(ring.len(), 2 * 13);
+    for point in ring.iter().flat_map(|tri| tri.points().to_vec()) {
+        let distance = (point[0] * point[0] + point[1] * point[1]).sqrt();
+        assert!(distance > 39.99 && distance < 50.01);
+    }
+
+    // A complete pie is a full circle.
+    assert_eq!(draw_triangles(ui, 1.0, Kind::Pie).len(), 50);
+}
+
+#[test]
+fn mouse_entered_and_exited_should_only_be_true_for_the_update_in_which_the_mouse_crosses() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+    let set_button = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        let crossing = {
+            let input = ui.widget_input(button);
+            (input.mouse_entered(), input.mouse_exited())
+        };
+        widget::Button::new()
+            .w_h(100.0, 100.0)
+            .x_y(0.0, 0.0)
+            .set(button, ui);
+        crossing
+    };
+    set_button(ui);
+
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    assert_eq!(set_button(ui), (true, false));
+    assert_eq!(set_button(ui), (false, false));
+
+    move_mouse_to_abs_coordinates(200.0, 0.0, ui);
+    assert_eq!(set_button(ui), (false, true));
+    assert_eq!(set_button(ui), (false, false));
+}
+
+#[test]
+fn graph_to_dot_should_describe_every_node_and_edge() {
+    let ui = &mut windowless_ui();
+    let canvas = ui.widget_id_generator().next();
+    let button = ui.widget_id_generator().named("ok");
+    let _placeholder = ui.widget_id_generator().next();
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new().w_h(200.0, 200.0).set(canvas, ui);
+        widget::Button::new()
+            .w_h(50.0, 20.0)
+            .middle_of(canvas)
+            .set(button, ui);
+    }
+
+    let graph = ui.widget_graph();
+    let dot = graph.to_dot();
+    let lines: Vec<&str> = dot.lines().collect();
+    assert_eq!(lines.first(), Some(&"digraph {"));
+    assert_eq!(lines.last(), Some(&"}"));
+
+    // Parse the statements back into nodes and edges.
+    let statements = &lines[1..lines.len() - 1];
+    let (edges, nodes): (Vec<&str>, Vec<&str>) = statements
+        .iter()
+        .cloned()
+        .partition(|statement| statement.contains(" -> "));
+    assert_eq!(nodes.len(), graph.node_count());
+    assert_eq!(edges.len(), graph.edge_count());
+
+    // Placeholders are drawn distinctly and widgets are labelled with their type.
+    let num_placeholders = graph.node_count() - graph.widget_count();
+    let dashed = nodes.iter().filter(|n| n.contains("style=dashed")).count();
+    assert!(num_placeholders > 0);
+    assert_eq!(dashed, num_placeholders);
+    let button_node = format!(
+        "    {} [label=\"{} \\\"ok\\\": Button",
+        button.index(),
+        button.index()
+    );
+    assert!(nodes.iter().any(|n| n.starts_with(&button_node)));
+    let depth_edge = format!(
+        "    {} -> {} [label=\"Depth\"];",
+        canvas.index(),
+        button.index()
+    );
+    assert!(edges.contains(&depth_edge.as_str()));
+}
+
+#[test]
+fn pick_widgets_should_yield_every_widget_under_the_point_from_the_top_down() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (canvas, button) = (ids.next(), ids.next());
+    {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .x_y(0.0, 0.0)
+            .set(canvas, ui);
+        widget::Button::new()
+            .w_h(50.0, 20.0)
+            .middle_of(canvas)
+            .set(button, ui);
+    }
+
+    // The button's graphic elements are represented by the button itself.
+    let picked = ui.pick_widgets([0.0, 0.0]);
+    assert_eq!(&picked[..2], &[button, canvas]);
+    assert_eq!(picked.iter().filter(|&&id| id == button).count(), 1);
+
+    let picked = ui.pick_widgets([80.0, 80.0]);
+    assert_eq!(picked.first(), Some(&canvas));
+    assert!(!ui.pick_widgets([150.0, 0.0]).contains(&canvas));
+}
+
+#[test]
+fn canvas_with_scroll_momentum_should_keep_scrolling_until_it_comes_to_rest() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (canvas, kid) = (ids.next(), ids.next());
+    // Each update occurs one 50ms frame after the last.
+    ui.set_now(Some(instant::Instant::now()));
+    let set_widgets = |ui: &mut Ui, momentum: bool| {
+        let now = ui.now() + std::time::Duration::from_millis(50);
+        ui.set_now(Some(now));
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .x_y(0.0, 0.0)
+            .scroll_kids_vertically()
+            .scroll_momentum(momentum)
+            .set(canvas, ui);
+        widget::Rectangle::fill([100.0, 2000.0])
+            .mid_top_of(canvas)
+            .set(kid, ui);
+    };
+    let y_scroll = |ui: &Ui| {
+        ui.widget_graph()
+            .widget(canvas)
+            .and_then(|container| container.maybe_y_scroll_state)
+            .expect("no y scroll state for canvas")
+    };
+    // Scroll the canvas while the left mouse button is held, as when dragging a `Scrollbar`.
+    let fling = |ui: &mut Ui, momentum: bool, distance: f64| {
+        press_mouse_button(MouseButton::Left, ui);
+        ui.scroll_widget(canvas, [0.0, distance]);
+        set_widgets(ui, momentum);
+        release_mouse_button(MouseButton::Left, ui);
+        y_scroll(ui)
+    };
+    let come_to_rest = |ui: &mut Ui| {
+        for _ in 0..100 {
+            set_widgets(ui, true);
+            if y_scroll(ui).velocity == 0.0 {
+                break;
+            }
+        }
+        y_scroll(ui)
+    };
+
+    // Without momentum, the offset stops as soon as the scrolling does.
+    set_widgets(ui, false);
+    set_widgets(ui, false);
+    let flung = fling(ui, false, 20.0);
+    set_widgets(ui, false);
+    assert_eq!(flung.velocity, 0.0);
+    assert_eq!(y_scroll(ui).offset, flung.offset);
+
+    // With momentum, scrolling without a drag, e.g. via the mouse wheel, still stops immediately.
+    set_widgets(ui, true);
+    ui.scroll_widget(canvas, [0.0, 20.0]);
+    set_widgets(ui, true);
+    let scrolled = y_scroll(ui);
+    assert_eq!(scrolled.velocity, 0.0);
+    set_widgets(ui, true);
+    assert_eq!(y_scroll(ui).offset, scrolled.offset);
+
+    // Releasing a drag continues the offset in its direction at a decaying velocity.
+    let flung = fling(ui, true, 20.0);
+    assert!(flung.velocity > 0.0);
+    set_widgets(ui, true);
+    let coasting = y_scroll(ui);
+    assert!(coasting.offset > flung.offset);
+    assert!(coasting.velocity > 0.0 && coasting.velocity < flung.velocity);
+    assert!(!ui.should_wait_for_events());
+
+    // Eventually the scrolling comes to rest within the scrollable range.
+    let rest = come_to_rest(ui);
+    assert_eq!(rest.velocity, 0.0);
+    assert!(rest.offset > coasting.offset && rest.offset < rest.offset_bounds.start);
+
+    // Pressing the left mouse button while the offset is in motion stops it.
+    let flung = fling(ui, true, 20.0);
+    assert!(flung.velocity > 0.0);
+    set_widgets(ui, true);
+    assert!(y_scroll(ui).velocity > 0.0);
+    press_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, true);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, true);
+    assert_eq!(y_scroll(ui).velocity, 0.0);
+
+    // An update without motion before the release carries the velocity measured during the drag.
+    press_mouse_button(MouseButton::Left, ui);
+    ui.scroll_widget(canvas, [0.0, 20.0]);
+    set_widgets(ui, true);
+    let dragged = y_scroll(ui);
+    set_widgets(ui, true);
+    let paused = y_scroll(ui);
+    assert_eq!(paused.offset, dragged.offset);
+    assert!(paused.velocity > 0.0 && paused.velocity < dragged.velocity);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, true);
+    assert!(y_scroll(ui).offset > paused.offset);
+    come_to_rest(ui);
+
+    // As does releasing the drag within the same update as its final motion.
+    press_mouse_button(MouseButton::Left, ui);
+    ui.scroll_widget(canvas, [0.0, 20.0]);
+    set_widgets(ui, true);
+    let dragged = y_scroll(ui);
+    ui.scroll_widget(canvas, [0.0, 20.0]);
+    release_mouse_button(MouseButton::Left, ui);
+    set_widgets(ui, true);
+    let released = y_scroll(ui);
+    assert!(released.offset > dragged.offset);
+    assert_eq!(released.velocity, dragged.velocity);
+    come_to_rest(ui);
+
+    // A fling past the end of the scrollable range stops at its bound.
+    fling(ui, true, 500.0);
+    let rest = come_to_rest(ui);
+    assert_eq!(rest.velocity, 0.0);
+    assert_eq!(rest.offset, rest.offset_bounds.start);
+}
+
+#[test]
+fn canvas_scrollbars_should_follow_the_visibility_of_each_axis() {
+    use position::Axis;
+    use widget::scroll::{Visibility, X, Y};
+
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (canvas, kid) = (ids.next(), ids.next());
+    let set_widgets = |ui: &mut Ui, x: Visibility, y: Visibility| {
+        // Set twice so that the scroll state reflects the size of the kid.
+        for _ in 0..2 {
+            let ui = &mut ui.set_widgets();
+            // The kid fits horizontally but overflows vertically.
+            widget::Canvas::new()
+                .w_h(200.0, 200.0)
+                .x_y(0.0, 0.0)
+                .scroll_kids()
+                .scroll_bar_visibility(Axis::X, x)
+                .scroll_bar_visibility(Axis::Y, y)
+                .set(canvas, ui);
+            widget::Rectangle::fill([100.0, 400.0])
+                .mid_top_of(canvas)
+                .set(kid, ui);
+        }
+    };
+    let find_scrollbars = |ui: &Ui, type_name: &str| -> Vec<widget::Id> {
+        ui.updated_widgets()
+            .iter()
+            .cloned()
+            .filter(|&id| ui.widget_graph().widget(id).unwrap().type_name == type_name)
+            .collect()
+    };
+    let x_name = std::any::type_name::<widget::Scrollbar<X>>();
+    let y_name = std::any::type_name::<widget::Scrollbar<Y>>();
+    let num_visible = |ui: &Ui| {
+        (
+            find_scrollbars(ui, x_name).len(),
+            find_scrollbars(ui, y_name).len(),
+        )
+    };
+
+    set_widgets(ui, Visibility::Never, Visibility::Never);
+    assert_eq!(num_visible(ui), (0, 0));
+
+    set_widgets(ui, Visibility::Auto, Visibility::Auto);
+    assert_eq!(num_visible(ui), (0, 1));
+
+    set_widgets(ui, Visibility::Always, Visibility::Auto);
+    assert_eq!(num_visible(ui), (1, 1));
+
+    // The canvas remains scrollable without a scrollbar, and a scrollbar is not scrolled along
+    // with the children of the canvas.
+    set_widgets(ui, Visibility::Never, Visibility::Never);
+    ui.scroll_widget(canvas, [0.0, 50.0]);
+    set_widgets(ui, Visibility::Never, Visibility::Always);
+    let y_scroll = ui
+        .widget_graph()
+        .widget(canvas)
+        .unwrap()
+        .maybe_y_scroll_state;
+    assert_eq!(y_scroll.unwrap().offset, 50.0);
+    let scrollbar = find_scrollbars(ui, y_name)[0];
+    assert_eq!(
+        ui.rect_of(scrollbar).unwrap().y,
+        ui.rect_of(canvas).unwrap().y
+    );
+}
+
+#[test]
+fn scroll_to_widget_should_bring_the_child_fully_into_the_kid_area() {
+    let ui = &mut windowless_ui();
+    let canvas = ui.widget_id_generator().next();
+    let items: Vec<widget::Id> = (0..10).map(|_| ui.widget_id_generator().next()).collect();
+    let set_widgets = |ui: &mut Ui| {
+        let ui = &mut ui.set_widgets();
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .x_y(0.0, 0.0)
+            .pad(0.0)
+            .scroll_kids_vertically()
+            .set(canvas, ui);
+        for (i, &item) in items.iter().enumerate() {
+            let rect = widget::Rectangle::fill([100.0, 50.0]);
+            match i {
+                0 => rect.mid_top_of(canvas),
+                _ => rect.down_from(items[i - 1], 0.0),
+            }
+            .set(item, ui);
+        }
+    };
+    let y_offset = |ui: &Ui| {
+        let canvas = ui.widget_graph().widget(canvas).unwrap();
+        canvas.maybe_y_scroll_state.unwrap().offset
+    };
+    set_widgets(ui);
+    set_widgets(ui);
+
+    // The offset is written directly and is reflected by the children upon the next update.
+    ui.scroll_to_widget(canvas, items[7]);
+    assert_eq!(y_offset(ui), 200.0);
+    let offset = graph::algo::scroll_offset(ui.widget_graph(), items[0]);
+    assert_eq!(offset, [0.0, 200.0]);
+    set_widgets(ui);
+    assert_eq!(ui.rect_of(items[7]).unwrap().bottom(), -100.0);
+
+    // A child that is already visible requires no scrolling.
+    ui.scroll_to_widget(canvas, items[6]);
+    assert_eq!(y_offset(ui), 200.0);
+
+    ui.scroll_to_widget(canvas, items[0]);
+    assert_eq!(y_offset(ui), 0.0);
+
+    // The offset is clamped to the scrollable range.
+    ui.set_scroll_offset(canvas, [0.0, 1000.0]);
+    assert_eq!(y_offset(ui), 300.0);
+    set_widgets(ui);
+    assert_eq!(y_offset(ui), 300.0);
+    assert_eq!(ui.rect_of(items[9]).unwrap().bottom(), -100.0);
+}
+
+#[test]
+fn disabled_button_should_ignore_input_and_blend_towards_the_disabled_color() {
+    let ui = &mut windowless_ui();
+    let button = ui.widget_id_generator().next();
+    let red = ::color::RED;
+    let set_button = |ui: &mut Ui, enabled: bool| {
+        let ui = &mut ui.set_widgets();
+        widget::Button::new()
+            .enabled(enabled)
+            .color(red)
+            .border(0.0)
+            .w_h(100.0, 40.0)
+            .x_y(0.0, 0.0)
+            .set(button, ui)
+            .0
+    };
+    let button_color = |ui: &mut Ui| {
+        let mut primitives = ui.draw();
+        while let Some(primitive) = primitives.next() {
+            if let render::PrimitiveKind::Rectangle { color } = primitive.kind {
+                if primitive.rect.dim() == [100.0, 40.0] {
+                    return Some(color);
+                }
+            }
+        }
+        None
+    };
+
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    set_button(ui, false);
+    left_click_mouse(ui);
+    assert_eq!(set_button(ui, false), 0);
+    assert_eq!(button_color(ui), Some(ui.theme.disabled(red)));
+    assert_ne!(ui.theme.disabled(red), red);
+
+    left_click_mouse(ui);
+    assert_eq!(set_button(ui, true), 1);
+    assert_eq!(button_color(ui), Some(red.highlighted()));
+}
+
+#[test]
+fn disabled_slider_and_toggle_should_ignore_clicks() {
+    let ui = &mut windowless_ui();
+    let mut ids = ui.widget_id_generator();
+    let (slider, toggle) = (ids.next(), ids.next());
+    let set_widgets = |ui: &mut Ui, enabled: bool| {
+        let ui = &mut ui.set_widgets();
+        let value = widget::Slider::new(0.0, 0.0, 1.0)
+            .enabled(enabled)
+            .w_h(100.0, 20.0)
+            .x_y(0.0, 100.0)
+            .set(slider, ui);
+        let clicks = widget::Toggle::new(false)
+            .enabled(enabled)
+            .w_h(20.0, 20.0)
+            .x_y(0.0, -100.0)
+            .set(toggle, ui)
+            .count();
+        (value, clicks)
+    };
+    set_widgets(ui, false);
+
+    move_mouse_to_abs_coordinates(40.0, 100.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    assert_eq!(set_widgets(ui, false).0, None);
+    assert!(set_widgets(ui, true).0.is_some());
+    release_mouse_button(MouseButton::Left, ui);
+
+    move_mouse_to_abs_coordinates(0.0, -100.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, false).1, 0);
+    left_click_mouse(ui);
+    assert_eq!(set_widgets(ui, true).1, 1);
+}
+
+#[test]
+fn multi_select_drop_down_list_should_toggle_items_without_closing() {
+    fn set_list(ui: &mut Ui, list: widget::Id, selected: &mut Vec<bool>) -> Option<usize> {
+        let ui = &mut ui.set_widgets();
+        widget::DropDownList::bind_multi(&["Apple", "Banana", "Cherry", "Date"], selected)
+            .w_h(150.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(list, ui)
+    }
+
+    let ui = &mut windowless_ui();
+    let list = ui.widget_id_generator().next();
+    let mut selected = vec![];
+    set_list(ui, list, &mut selected);
+
+    // Open the list.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list, &mut selected), None);
+    set_list(ui, list, &mut selected);
+
+    // Each click toggles an item while leaving the list open.
+    move_mouse_to_abs_coordinates(0.0, -30.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list, &mut selected), Some(1));
+    assert_eq!(selected, vec![false, true, false, false]);
+
+    move_mouse_to_abs_coordinates(0.0, -60.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list, &mut selected), Some(2));
+    assert_eq!(selected, vec![false, true, true, false]);
+
+    move_mouse_to_abs_coordinates(0.0, -30.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list, &mut selected), Some(1));
+    assert_eq!(selected, vec![false, false, true, false]);
+}
+
+#[test]
+fn drop_down_list_type_ahead_should_jump_to_the_first_item_starting_with_the_typed_text() {
+    fn set_list(ui: &mut Ui, list: widget::Id, type_ahead: bool) -> Option<usize> {
+        let ui = &mut ui.set_widgets();
+        let items = ["Apple", "Banana", "Cherry", "Citrus", "Date"];
+        widget::DropDownList::new(&items, Some(0))
+            .type_ahead(type_ahead)
+            .type_ahead_timeout(::std::time::Duration::from_millis(200))
+            .w_h(150.0, 30.0)
+            .x_y(0.0, 0.0)
+            .set(list, ui)
+    }
+    fn type_text(text: &str, ui: &mut Ui) {
+        ui.handle_event(Input::Text(text.to_string()));
+    }
+    fn press_return(ui: &mut Ui) {
+        ui.handle_event(Input::Press(Button::Keyboard(Key::Return)));
+    }
+
+    let ui = &mut windowless_ui();
+    let start = instant::Instant::now();
+    ui.set_now(Some(start));
+    let list = ui.widget_id_generator().next();
+    set_list(ui, list, true);
+
+    // Opening the list gives it keyboard focus.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    set_list(ui, list, true);
+    set_list(ui, list, true);
+    assert_eq!(ui.global_input().current.widget_capturing_keyboard, Some(list));
+
+    // Consecutive characters accumulate, ignoring case.
+    type_text("C", ui);
+    set_list(ui, list, true);
+    type_text("i", ui);
+    set_list(ui, list, true);
+    press_return(ui);
+    assert_eq!(set_list(ui, list, true), Some(3));
+
+    // Once the timeout has elapsed, the search text starts over.
+    press_return(ui);
+    set_list(ui, list, true);
+    type_text("c", ui);
+    set_list(ui, list, true);
+    ui.set_now(Some(start + ::std::time::Duration::from_millis(200)));
+    set_list(ui, list, true);
+    type_text("d", ui);
+    set_list(ui, list, true);
+    press_return(ui);
+    assert_eq!(set_list(ui, list, true), Some(4));
+
+    // Text that matches nothing is dropped rather than blocking further searches.
+    press_return(ui);
+    set_list(ui, list, true);
+    type_text("x", ui);
+    set_list(ui, list, true);
+    type_text("b", ui);
+    set_list(ui, list, true);
+    press_return(ui);
+    assert_eq!(set_list(ui, list, true), Some(1));
+
+    // Text that stops matching starts over from the last typed character.
+    press_return(ui);
+    set_list(ui, list, true);
+    type_text("a", ui);
+    set_list(ui, list, true);
+    type_text("c", ui);
+    set_list(ui, list, true);
+    press_return(ui);
+    assert_eq!(set_list(ui, list, true), Some(2));
+
+    // Typing is ignored when type-ahead is disabled.
+    press_return(ui);
+    set_list(ui, list, false);
+    type_text("b", ui);
+    set_list(ui, list, false);
+    press_return(ui);
+    assert_eq!(set_list(ui, list, false), Some(0));
+}
+
+#[test]
+fn filter_list_should_only_show_the_items_containing_the_filter() {
+    fn set_list(ui: &mut Ui, list: widget::Id) -> Option<(usize, String)> {
+        let ui = &mut ui.set_widgets();
+        widget::FilterList::new(&["Apple", "Banana", "Cherry", "Grape"], None)
+            .w_h(200.0, 150.0)
+            .x_y(0.0, 0.0)
+            .set(list, ui)
+            .map(|(i, label)| (i, label.to_string()))
+    }
+
+    let ui = &mut windowless_ui();
+    let font_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/fonts/NotoSans/NotoSans-Regular.ttf"
+    );
+    ui.fonts.insert_from_file(font_path).unwrap();
+    let list = ui.widget_id_generator().next();
+    set_list(ui, list);
+
+    // An empty filter shows all items.
+    move_mouse_to_abs_coordinates(0.0, -60.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list), Some((3, "Grape".to_string())));
+
+    // Focus the text box and filter the items, ignoring case.
+    move_mouse_to_abs_coordinates(0.0, 60.0, ui);
+    left_click_mouse(ui);
+    set_list(ui, list);
+    ui.handle_event(Input::Text("AP".to_string()));
+    set_list(ui, list);
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list), Some((3, "Grape".to_string())));
+
+    // `Return` within the text box chooses the first shown item.
+    move_mouse_to_abs_coordinates(0.0, 60.0, ui);
+    left_click_mouse(ui);
+    set_list(ui, list);
+    ui.handle_event(Input::Press(Button::Keyboard(Key::Return)));
+    assert_eq!(set_list(ui, list), Some((0, "Apple".to_string())));
+
+    // No items are shown when none match.
+    ui.handle_event(Input::Text("x".to_string()));
+    set_list(ui, list);
+    move_mouse_to_abs_coordinates(0.0, 30.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_list(ui, list), None);
+}
+
+#[test]
+fn dragging_the_edge_of_a_resizable_split_should_reapportion_the_split_lengths() {
+    use std::cell::{Cell, RefCell};
+
+    fn set_canvas(ui: &mut Ui, ids: &[widget::Id]) -> Option<Scalar> {
+        let ui = &mut ui.set_widgets();
+        let resized = Cell::new(None);
+        let on_resize = RefCell::new(|ratio| resized.set(Some(ratio)));
+        let splits = [
+            (
+                ids[1],
+                widget::Canvas::new()
+                    .resizable(true)
+                    .min_length(100.0)
+                    .on_resize(&on_resize),
+            ),
+            (ids[2], widget::Canvas::new().min_length(100.0)),
+        ];
+        widget::Canvas::new()
+            .w_h(400.0, 200.0)
+            .x_y(0.0, 0.0)
+            .pad(0.0)
+            .border(0.0)
+            .flow_right(&splits)
+            .set(ids[0], ui);
+        resized.get()
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 3);
+    set_canvas(ui, &ids);
+    set_canvas(ui, &ids);
+    assert_eq!(ui.rect_of(ids[1]).unwrap().w(), 200.0);
+
+    // Drag the shared edge to the right.
+    move_mouse_to_abs_coordinates(0.0, 0.0, ui);
+    press_mouse_button(MouseButton::Left, ui);
+    set_canvas(ui, &ids);
+    move_mouse_to_abs_coordinates(100.0, 0.0, ui);
+    assert_eq!(set_canvas(ui, &ids), Some(0.75));
+    set_canvas(ui, &ids);
+    assert_eq!(ui.rect_of(ids[1]).unwrap().w(), 300.0);
+    assert_eq!(ui.rect_of(ids[2]).unwrap().w(), 100.0);
+
+    // The following split may not be shrunk beneath its minimum length.
+    move_mouse_to_abs_coordinates(180.0, 0.0, ui);
+    assert_eq!(set_canvas(ui, &ids), None);
+    release_mouse_button(MouseButton::Left, ui);
+    set_canvas(ui, &ids);
+    assert_eq!(ui.rect_of(ids[1]).unwrap().w(), 300.0);
+}
+
+#[test]
+fn canvas_splits_should_be_laid_out_within_their_minimum_and_maximum_lengths() {
+    fn set_canvas(ui: &mut Ui, ids: &[widget::Id], w: Scalar) {
+        let ui = &mut ui.set_widgets();
+        let splits = [
+            (
+                ids[1],
+                widget::Canvas::new().min_length(150.0).max_length(400.0),
+            ),
+            (ids[2], widget::Canvas::new()),
+            (ids[3], widget::Canvas::new().length_weight(3.0)),
+        ];
+        widget::Canvas::new()
+            .w_h(w, 100.0)
+            .x_y(0.0, 0.0)
+            .pad(0.0)
+            .border(0.0)
+            .flow_right(&splits)
+            .set(ids[0], ui);
+    }
+    fn split_x_ranges(ui: &Ui, ids: &[widget::Id]) -> Vec<(Scalar, Scalar)> {
+        ids[1..]
+            .iter()
+            .map(|&id| {
+                let rect = ui.rect_of(id).unwrap();
+                let round = |x: Scalar| (x * 100.0).round() / 100.0;
+                (round(rect.left()), round(rect.right()))
+            })
+            .collect()
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 4);
+
+    // Unconstrained, the first split would only be 100 wide, so its deficit is taken from the
+    // other splits in proportion to their lengths.
+    set_canvas(ui, &ids, 500.0);
+    assert_eq!(
+        split_x_ranges(ui, &ids),
+        vec![(-250.0, -100.0), (-100.0, -12.5), (-12.5, 250.0)]
+    );
+
+    // Unconstrained, the first split would be 500 wide, so its surplus is given to the others.
+    set_canvas(ui, &ids, 2500.0);
+    assert_eq!(
+        split_x_ranges(ui, &ids),
+        vec![(-1250.0, -850.0), (-850.0, -325.0), (-325.0, 1250.0)]
+    );
+
+    // Within its bounds, the splits are laid out by weight alone.
+    set_canvas(ui, &ids, 1000.0);
+    assert_eq!(
+        split_x_ranges(ui, &ids),
+        vec![(-500.0, -300.0), (-300.0, -100.0), (-100.0, 500.0)]
+    );
+}
+
+#[test]
+fn canvas_splits_should_share_the_non_absolute_length_by_weight() {
+    fn split_widths(ui: &mut Ui, ids: &[widget::Id], splits: [widget::Canvas; 3]) -> Vec<Scalar> {
+        {
+            let ui = &mut ui.set_widgets();
+            let splits = [
+                (ids[1], splits[0]),
+                (ids[2], splits[1]),
+                (ids[3], splits[2]),
+            ];
+            widget::Canvas::new()
+                .w_h(400.0, 100.0)
+                .x_y(0.0, 0.0)
+                .pad(0.0)
+                .border(0.0)
+                .flow_right(&splits)
+                .set(ids[0], ui);
+        }
+        ids[1..]
+            .iter()
+            .map(|&id| ui.rect_of(id).unwrap().w())
+            .collect()
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 4);
+    let weighted = |weight| widget::Canvas::new().length_weight(weight);
+
+    let splits = [weighted(1.0), weighted(2.0), weighted(1.0)];
+    assert_eq!(split_widths(ui, &ids, splits), vec![100.0, 200.0, 100.0]);
+
+    // A weight of zero collapses the split.
+    let splits = [weighted(1.0), weighted(0.0), weighted(3.0)];
+    assert_eq!(split_widths(ui, &ids, splits), vec![100.0, 0.0, 300.0]);
+    let splits = [weighted(0.0), weighted(0.0), weighted(0.0)];
+    assert_eq!(split_widths(ui, &ids, splits), vec![0.0, 0.0, 0.0]);
+
+    // Absolute lengths are allocated before the remainder is divided by weight.
+    let splits = [
+        widget::Canvas::new().length(100.0),
+        weighted(1.0),
+        weighted(2.0),
+    ];
+    assert_eq!(split_widths(ui, &ids, splits), vec![100.0, 100.0, 200.0]);
+}
+
+#[test]
+fn grid_layout_should_resolve_fixed_and_weighted_cells_and_spans() {
+    use widget::grid_layout::GridLength::{Fixed, Weight};
+
+    let ui = &mut windowless_ui();
+    let (grid, button) = {
+        let mut id_generator = ui.widget_id_generator();
+        (id_generator.next(), id_generator.next())
+    };
+    let cols = [Fixed(100.0), Weight(1.0), Weight(1.0)];
+    let rows = [Weight(1.0), Fixed(50.0)];
+    let cells = {
+        let ui = &mut ui.set_widgets();
+        let cells = widget::GridLayout::new(&cols, &rows)
+            .w_h(300.0, 200.0)
+            .x_y(0.0, 0.0)
+            .set(grid, ui);
+        cells
+            .span(1, 0, 2, 1)
+            .unwrap()
+            .set(widget::Button::new(), button, ui);
+        cells
+    };
+    let rect = |cell: Option<widget::grid_layout::Cell>| cell.map(|cell| cell.rel_rect);
+
+    assert_eq!(cells.iter().count(), 6);
+    assert_eq!(
+        rect(cells.cell(0, 0)),
+        Some(Rect::from_corners([-150.0, -50.0], [-50.0, 100.0]))
+    );
+    assert_eq!(
+        rect(cells.cell(1, 1)),
+        Some(Rect::from_corners([-50.0, -100.0], [50.0, -50.0]))
+    );
+
+    // Spans are limited to the bounds of the grid and cells beyond them are ignored.
+    assert_eq!(
+        rect(cells.span(2, 0, 5, 5)),
+        Some(Rect::from_corners([50.0, -100.0], [150.0, 100.0]))
+    );
+    assert_eq!(cells.cell(3, 0), None);
+    assert_eq!(cells.cell(0, 2), None);
+
+    // Widgets set upon a cell cover its area.
+    assert_eq!(
+        ui.rect_of(button),
+        Some(Rect::from_corners([-50.0, -50.0], [150.0, 100.0]))
+    );
+}
+
+#[test]
+fn id_list_should_reuse_ids_after_shrinking_and_growing() {
+    let ui = &mut windowless_ui();
+    let mut list = widget::id::List::new();
+    list.resize(3, &mut ui.widget_id_generator());
+    let ids = list.to_vec();
+    let node_count = ui.widget_graph().node_count();
+
+    list.resize(1, &mut ui.widget_id_generator());
+    assert_eq!(&list[..], &ids[..1]);
+    list.resize(3, &mut ui.widget_id_generator());
+    assert_eq!(&list[..], &ids[..]);
+    assert_eq!(ui.widget_graph().node_count(), node_count);
+
+    // Walking beyond the end reuses the retained ids before generating new ones.
+    list.resize(0, &mut ui.widget_id_generator());
+    let mut walk = list.walk();
+    assert_eq!(walk.next(&mut list, &mut ui.widget_id_generator()), ids[0]);
+    assert_eq!(&list[..], &ids[..1]);
+    assert_eq!(ui.widget_graph().node_count(), node_count);
+}
+
+#[test]
+fn collapsing_a_canvas_should_only_leave_its_title_bar() {
+    use std::cell::{Cell, RefCell};
+
+    fn set_canvas(ui: &mut Ui, ids: &[widget::Id], collapsed: bool) -> Option<bool> {
+        let ui = &mut ui.set_widgets();
+        let requested = Cell::new(None);
+        let on_collapse = RefCell::new(|collapse| requested.set(Some(collapse)));
+        widget::Canvas::new()
+            .w_h(200.0, 200.0)
+            .x_y(0.0, 0.0)
+            .title_bar("Panel")
+            .collapsible(true)
+            .collapsed(collapsed)
+            .on_collapse(&on_collapse)
+            .set(ids[0], ui);
+        widget::Button::new()
+            .w_h(50.0, 50.0)
+            .mid_top_of(ids[0])
+            .parent(ids[0])
+            .set(ids[1], ui);
+        requested.get()
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 2);
+    set_canvas(ui, &ids, false);
+    set_canvas(ui, &ids, false);
+    let title_bar_top = ui.rect_of(ids[0]).unwrap().top();
+    let kid_area = ui.kid_area_of(ids[0]).unwrap();
+    assert!(kid_area.h() > 0.0);
+
+    // Clicking the caret at the left of the title bar requests that the canvas collapses.
+    move_mouse_to_abs_coordinates(-95.0, title_bar_top - 5.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_canvas(ui, &ids, false), Some(true));
+
+    // While collapsed, the kid area has no height and the area beneath the title bar is no longer
+    // considered to be over the canvas.
+    set_canvas(ui, &ids, true);
+    set_canvas(ui, &ids, true);
+    let kid_area = ui.kid_area_of(ids[0]).unwrap();
+    assert_eq!(kid_area.h(), 0.0);
+    assert!(kid_area.top() < title_bar_top && kid_area.top() > title_bar_top - 50.0);
+
+    // The kids of the canvas are neither drawn nor under the mouse, even though it does not scroll.
+    let button_xy = ui.xy_of(ids[1]).unwrap();
+    move_mouse_to_abs_coordinates(button_xy[0], button_xy[1], ui);
+    set_canvas(ui, &ids, true);
+    assert_eq!(
+        ui.global_input().current.widget_under_mouse,
+        Some(ui.window)
+    );
+    let mut primitives = ui.draw();
+    while let Some(primitive) = primitives.next() {
+        assert!(!ui
+            .widget_graph()
+            .does_recursive_graphic_edge_exist(ids[1], primitive.id));
+    }
+
+    // Clicking the caret again requests that it expands.
+    move_mouse_to_abs_coordinates(-95.0, title_bar_top - 5.0, ui);
+    left_click_mouse(ui);
+    assert_eq!(set_canvas(ui, &ids, true), Some(false));
+}
+
+#[test]
+fn dragging_the_title_bar_of_a_floating_canvas_should_move_it_within_the_window() {
+    fn set_canvases(ui: &mut Ui, ids: &[widget::Id]) {
+        let ui = &mut ui.set_widgets();
+        for &(id, x) in &[(ids[0], -50.0), (ids[1], 50.0)] {
+            widget::Canvas::new()
+                .floating(true)
+                .title_bar("Window")
+                .w_h(200.0, 200.0)
+                .x_y(x, 0.0)
+                .set(id, ui);
+        }
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 2);
+    set_canvases(ui, &ids);
+    set_canvases(ui, &ids);
+    let title_bar_y = ui.rect_of(ids[0]).unwrap().top() - 5.0;
+    move_mouse_to_abs_coordinates(0.0, -30.0, ui);
+    set_canvases(ui, &ids);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[1]));
+
+    // Drag the first window, which lies beneath the second, by its title bar.
+    move_mouse_to_abs_coordinates(-120.0, title_bar_y, ui);
+    set_canvases(ui, &ids);
+    press_mouse_button(MouseButton::Left, ui);
+    set_canvases(ui, &ids);
+    move_mouse_to_abs_coordinates(-100.0, title_bar_y - 30.0, ui);
+    set_canvases(ui, &ids);
+    set_canvases(ui, &ids);
+    assert_eq!(ui.xy_of(ids[0]), Some([-30.0, -30.0]));
+
+    // The dragged window is brought to the front.
+    move_mouse_to_abs_coordinates(0.0, -30.0, ui);
+    set_canvases(ui, &ids);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[0]));
+
+    // The centre of the title bar may not be dragged beyond the window.
+    move_mouse_to_abs_coordinates(1000.0, 1000.0, ui);
+    set_canvases(ui, &ids);
+    release_mouse_button(MouseButton::Left, ui);
+    set_canvases(ui, &ids);
+    let rect = ui.rect_of(ids[0]).unwrap();
+    assert_eq!(rect.x(), 400.0);
+    assert!(rect.top() > 300.0 && rect.top() - 300.0 < 20.0);
+}
+
+#[test]
+fn raising_and_lowering_floating_widgets_should_reorder_their_depth() {
+    fn set_canvases(ui: &mut Ui, ids: &[widget::Id]) {
+        let ui = &mut ui.set_widgets();
+        for &id in ids {
+            widget::Canvas::new()
+                .floating(true)
+                .w_h(100.0, 100.0)
+                .x_y(0.0, 0.0)
+                .set(id, ui);
+        }
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 3);
+    set_canvases(ui, &ids);
+    set_canvases(ui, &ids);
+    ui.raise_to_front(ids[0]);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[1], ids[2], ids[0]]);
+
+    // The order persists across updates.
+    set_canvases(ui, &ids);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[1], ids[2], ids[0]]);
+    move_mouse_to_abs_coordinates(10.0, 10.0, ui);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[0]));
+
+    ui.send_to_back(ids[2]);
+    set_canvases(ui, &ids);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[2], ids[1], ids[0]]);
+
+    // Widgets that are not floating are ignored.
+    ui.raise_to_front(ui.window);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[2], ids[1], ids[0]]);
+}
+
+#[test]
+fn sibling_widgets_should_be_ordered_by_their_depth() {
+    fn set_rectangles(ui: &mut Ui, ids: &[widget::Id], front_depth: Depth) {
+        let ui = &mut ui.set_widgets();
+        widget::Rectangle::fill([100.0, 100.0])
+            .x_y(0.0, 0.0)
+            .depth(front_depth)
+            .set(ids[0], ui);
+        widget::Rectangle::fill([100.0, 100.0])
+            .x_y(0.0, 0.0)
+            .set(ids[1], ui);
+        widget::Rectangle::fill([100.0, 100.0])
+            .x_y(0.0, 0.0)
+            .set(ids[2], ui);
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 3);
+
+    // Siblings of equal depth are ordered by instantiation.
+    set_rectangles(ui, &ids, 0.0);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[0], ids[1], ids[2]]);
+
+    // A lesser depth brings the first sibling to the front.
+    set_rectangles(ui, &ids, -1.0);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[1], ids[2], ids[0]]);
+    move_mouse_to_abs_coordinates(10.0, 10.0, ui);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[0]));
+
+    // A greater depth sends it to the back.
+    set_rectangles(ui, &ids, 1.0);
+    assert_eq!(depth_order_of(ui, &ids), vec![ids[0], ids[1], ids[2]]);
+}
+
+#[test]
+fn nested_clips_should_crop_their_kids_to_the_intersection_of_their_areas() {
+    fn set_widgets(ui: &mut Ui, ids: &[widget::Id]) {
+        let ui = &mut ui.set_widgets();
+        widget::Clip::new()
+            .w_h(220.0, 220.0)
+            .x_y(0.0, 0.0)
+            .pad(10.0)
+            .set(ids[0], ui);
+        widget::Clip::new()
+            .w_h(200.0, 200.0)
+            .x_y(100.0, 100.0)
+            .parent(ids[0])
+            .set(ids[1], ui);
+        widget::Rectangle::fill([200.0, 200.0])
+            .x_y(50.0, 50.0)
+            .parent(ids[1])
+            .set(ids[2], ui);
+    }
+
+    let ui = &mut windowless_ui();
+    let ids = new_ids(ui, 3);
+    set_widgets(ui, &ids);
+    set_widgets(ui, &ids);
+
+    // The rectangle is scissored to the overlap of the outer clip's padded area and the inner clip.
+    let expected = Rect::from_corners([0.0, 0.0], [100.0, 100.0]);
+    let mut scizzor = None;
+    let mut primitives = ui.draw();
+    while let Some(primitive) = primitives.next() {
+        if primitive.id == ids[2] {
+            scizzor = Some(primitive.scizzor);
+        }
+    }
+    assert_eq!(scizzor, Some(expected));
+
+    // The rectangle may only be picked within that overlap.
+    move_mouse_to_abs_coordinates(50.0, 50.0, ui);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[2]));
+    move_mouse_to_abs_coordinates(-40.0, -40.0, ui);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[0]));
+    move_mouse_to_abs_coordinates(105.0, 50.0, ui);
+    assert_eq!(ui.global_input().current.widget_under_mouse, Some(ids[0]));
+}
@@ -68,3 +68,40 @@ fn widget_input_should_provide_any_mouse_events_over_the_widgets_area_if_nothing
 
     assert!(another_widget_input.clicks().left().next().is_none());
 }
+
+#[test]
+fn scrolls_should_be_summed_separately_along_each_axis() {
+    let mut global_input = input::Global::new();
+    let widget = widget::Id::new(4);
+    let widget_area = Rect::from_corners([0.0, 0.0], [40.0, 40.0]);
+    let scroll = |x, y| {
+        event::Ui::Scroll(
+            Some(widget),
+            event::Scroll {
+                x: x,
+                y: y,
+                modifiers: ModifierKey::NO_MODIFIER,
+            },
+        )
+        .into()
+    };
+
+    let widget_input = input::Widget::for_widget(widget, widget_area, &global_input);
+    assert_eq!(widget_input.scroll(), None);
+    assert_eq!(widget_input.scroll_x(), None);
+    assert_eq!(widget_input.scroll_y(), None);
+
+    // A vertical-only gesture followed by a further vertical scroll.
+    push_event(&mut global_input, scroll(0.0, 10.0));
+    push_event(&mut global_input, scroll(0.0, -4.0));
+    let widget_input = input::Widget::for_widget(widget, widget_area, &global_input);
+    assert_eq!(widget_input.scroll_x(), None);
+    assert_eq!(widget_input.scroll_y(), Some(6.0));
+
+    push_event(&mut global_input, scroll(3.0, 0.0));
+    let widget_input = input::Widget::for_widget(widget, widget_area, &global_input);
+    assert_eq!(widget_input.scroll_x(), Some(3.0));
+    assert_eq!(widget_input.scroll_y(), Some(6.0));
+    let total = widget_input.scroll().unwrap();
+    assert_eq!((total.x, total.y), (3.0, 6.0));
+}
@@ -1,7 +1,7 @@
 //! Text layout logic.
 
 use std;
-use {FontSize, Scalar};
+use {Dimensions, FontSize, Scalar};
 
 // Re-export all relevant rusttype types here.
 pub use rusttype::gpu_cache::Cache as GlyphCache;
@@ -50,6 +50,34 @@ pub fn height(num_lines: usize, font_size: FontSize, line_spacing: Scalar) -> Sc
     }
 }
 
+/// Measure the dimensions of the given `text` when laid out with the given `font`, `font_size`
+/// and `line_spacing`, without the need for a `Text` widget.
+///
+/// When some `max_width` is given, lines are wrapped by whitespace as they would be within a
+/// `Text` widget of that width. Otherwise, lines are only broken by newline characters.
+///
+/// The width is that of the widest line while the height covers all lines. An empty string
+/// consists of a single empty line.
+pub fn measure(
+    text: &str,
+    font: &Font,
+    font_size: FontSize,
+    max_width: Option<Scalar>,
+    line_spacing: Scalar,
+) -> Dimensions {
+    let infos = line::infos(text, font, font_size);
+    let infos = match max_width {
+        Some(max_width) => infos.wrap_by_whitespace(max_width),
+        None => infos,
+    };
+    // Measure each line in the same manner as the default width of a `Text` widget.
+    let (num_lines, width) = infos.fold((0, 0.0), |(num_lines, width), info| {
+        let line_width = line::width(&text[info.byte_range()], font, font_size);
+        (num_lines + 1, line_width.max(width))
+    });
+    [width, height(num_lines, font_size, line_spacing)]
+}
+
 /// Produce an iterator yielding each line within the given `text` as a new `&str`, where the
 /// start and end indices into each line are provided by the given iterator.
 pub fn lines<I>(text: &str, ranges: I) -> Lines<I>
@@ -113,10 +141,15 @@ pub mod font {
     pub struct Id(usize);
 
     /// A collection of mappings from `font::Id`s to `rusttype::Font`s.
-    #[derive(Debug)]
+    ///
+    /// The fonts are shared between clones of the `Map`, allowing multiple `Ui`s to use the same
+    /// loaded fonts without duplicating them. Fonts inserted into a clone after cloning are only
+    /// available to that clone, however the clones share the counter from which new `Id`s are
+    /// produced so that an `Id` never refers to two different fonts.
+    #[derive(Clone, Debug)]
     pub struct Map {
-        next_index: usize,
-        map: fnv::FnvHashMap<Id, super::Font>,
+        next_index: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+        map: std::sync::Arc<fnv::FnvHashMap<Id, super::Font>>,
     }
 
     /// An iterator yielding an `Id` for each new `rusttype::Font` inserted into the `Map` via the
@@ -153,8 +186,8 @@ pub mod font {
         /// Construct the new, empty `Map`.
         pub fn new() -> Self {
             Map {
-                next_index: 0,
-                map: fnv::FnvHashMap::default(),
+                next_index: std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+                map: std::sync::Arc::new(fnv::FnvHashMap::default()),
             }
         }
 
@@ -165,10 +198,11 @@ pub mod font {
 
         /// Adds the given `rusttype::Font` to the `Map` and returns a unique `Id` for it.
         pub fn insert(&mut self, font: super::Font) -> Id {
-            let index = self.next_index;
-            self.next_index = index.wrapping_add(1);
+            let index = self
+                .next_index
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
             let id = Id(index);
-            self.map.insert(id, font);
+            std::sync::Arc::make_mut(&mut self.map).insert(id, font);
             id
         }
 
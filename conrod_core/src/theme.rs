@@ -2,7 +2,7 @@
 //! Types a functionality for handling Canvas and Widget theming.
 //!
 
-use color::{Color, BLACK, WHITE};
+use color::{self, Color, BLACK, GREY, WHITE};
 use fnv;
 use position::{Align, Direction, Padding, Position, Relative};
 use std;
@@ -36,6 +36,8 @@ pub struct Theme {
     pub border_width: Scalar,
     /// A default color for widget labels.
     pub label_color: Color,
+    /// The color towards which the colors of disabled widgets are blended.
+    pub disabled_color: Color,
     /// The `Id` of the default font used for text widgets when one is not specified.
     pub font_id: Option<text::font::Id>,
     /// A default "large" font size.
@@ -44,6 +46,14 @@ pub struct Theme {
     pub font_size_medium: u32,
     /// A default "small" font size.
     pub font_size_small: u32,
+    /// A default "large" spacing, e.g. the indentation of a list item.
+    pub spacing_lg: Scalar,
+    /// A default "medium" spacing, e.g. between a widget's edge and its label.
+    pub spacing_md: Scalar,
+    /// A default "small" spacing, e.g. between a point and its value label.
+    pub spacing_sm: Scalar,
+    /// A default "extra small" spacing, e.g. between a bar's edge and its label.
+    pub spacing_xs: Scalar,
     /// `StyleMap` for unique styling
     /// of each widget, index-able by the **Widget::kind**.
     pub widget_styling: StyleMap,
@@ -53,8 +63,15 @@ pub struct Theme {
     /// Once the `Duration` that separates two consecutive `Click`s is greater than this value, a
     /// `DoubleClick` event will no longer be generated.
     pub double_click_threshold: std::time::Duration,
+    /// The `Duration` for which a widget with a tooltip must be hovered before the tooltip is
+    /// displayed.
+    pub tooltip_delay: std::time::Duration,
 }
 
+/// The amount by which the colors of a disabled widget are blended towards the theme's
+/// `disabled_color`.
+pub const DISABLED_COLOR_BLEND: f32 = 0.6;
+
 /// The defaults for a specific widget.
 #[derive(Debug)]
 pub struct WidgetDefault {
@@ -96,16 +113,27 @@ impl Theme {
             border_color: BLACK,
             border_width: 1.0,
             label_color: BLACK,
+            disabled_color: GREY,
             font_id: None,
             font_size_large: 26,
             font_size_medium: 18,
             font_size_small: 12,
+            spacing_lg: 20.0,
+            spacing_md: 10.0,
+            spacing_sm: 5.0,
+            spacing_xs: 4.0,
             widget_styling: fnv::FnvHashMap::default(),
             mouse_drag_threshold: 0.0,
             double_click_threshold: std::time::Duration::from_millis(500),
+            tooltip_delay: std::time::Duration::from_millis(500),
         }
     }
 
+    /// The given color as it appears on a disabled widget, blended towards the `disabled_color`.
+    pub fn disabled(&self, color: Color) -> Color {
+        color::lerp(color, self.disabled_color, DISABLED_COLOR_BLEND)
+    }
+
     /// Retrieve the unique default styling for a widget.
     ///
     /// Attempts to cast the `Box<WidgetStyle>` to the **Widget**'s unique associated style **T**.
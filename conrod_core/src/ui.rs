@@ -1,4 +1,4 @@
-use color::Color;
+use color::{self, Color};
 use cursor;
 use event;
 use fnv;
@@ -7,6 +7,7 @@ use input;
 use position::{self, Align, Dimensions, Direction, Padding, Point, Position, Range, Rect, Scalar};
 use render;
 use std;
+use std::collections::BTreeMap;
 use std::sync::atomic::{self, AtomicUsize};
 use text;
 use theme::Theme;
@@ -35,6 +36,40 @@ pub struct UiBuilder {
     /// If this field is `None` when `build` is called, these collections will be initialised with
     /// no pre-reserved size and will instead grow organically as needed.
     pub maybe_widgets_capacity: Option<usize>,
+    maybe_fonts: Option<text::font::Map>,
+}
+
+/// The state of the overlay shared by all widgets for displaying their tooltips.
+#[derive(Debug)]
+struct Tooltip {
+    /// The text of the tooltip given to each widget via `UiCell::tooltip`.
+    texts: fnv::FnvHashMap<widget::Id, String>,
+    /// The widgets given a tooltip via `UiCell::tooltip` during the latest call to
+    /// `Ui::set_widgets`.
+    owners: fnv::FnvHashSet<widget::Id>,
+    /// The widget with a tooltip that is currently hovered along with when the hover began.
+    maybe_hovered: Option<(widget::Id, instant::Instant)>,
+    /// The widget whose tooltip was displayed by the overlay during the last call to
+    /// `Ui::set_widgets`.
+    maybe_displayed: Option<widget::Id>,
+    /// The background of the overlay.
+    background: widget::Id,
+    /// The text of the overlay.
+    text: widget::Id,
+}
+
+/// The state of the context menu shared by all widgets.
+#[derive(Debug)]
+struct ContextMenu {
+    /// The widget whose context menu is open, along with the position at which it was opened.
+    maybe_open: Option<(widget::Id, Point)>,
+    /// The widgets given a context menu via `UiCell::context_menu` during the latest call to
+    /// `Ui::set_widgets`.
+    owners: fnv::FnvHashSet<widget::Id>,
+    /// The background of the menu.
+    background: widget::Id,
+    /// A button for each item of the menu.
+    items: widget::id::List,
 }
 
 /// `Ui` is the most important type within Conrod and is necessary for rendering and maintaining
@@ -84,8 +119,33 @@ pub struct Ui {
     /// the end of the `Ui::set_widgets` method. This ensures that the events are received by the
     /// target widgets during the next call to `Ui::set_widgets`.
     pending_scroll_events: Vec<event::Ui>,
+    /// Input events produced by widgets during `Ui::set_widgets`, e.g. by an on-screen keyboard.
+    pending_input: Vec<event::Input>,
     /// Mouse cursor
     mouse_cursor: cursor::MouseCursor,
+    /// The mouse cursors requested by widgets via `UiCell::request_mouse_cursor` during the last
+    /// call to `Ui::set_widgets`.
+    mouse_cursor_requests: fnv::FnvHashMap<widget::Id, cursor::MouseCursor>,
+    /// Whether or not some widget requested continuous updates during the last call to
+    /// `Ui::set_widgets`, e.g. for animation.
+    continuous_updates_requested: bool,
+    /// The interactions reported by widgets during the last call to `Ui::set_widgets`.
+    interaction_log: Vec<event::Interaction>,
+    /// The state of the overlay shared by all widgets for displaying their tooltips.
+    tooltip: Tooltip,
+    /// The state of the context menu shared by all widgets.
+    context_menu: ContextMenu,
+    /// The stack of open overlays, e.g. menus and popups, from the bottom to the top.
+    overlays: Vec<widget::Id>,
+    /// The overlays dismissed by pressing the mouse outside of them since the end of the last
+    /// call to `Ui::set_widgets`, from the top down.
+    dismissed_overlays: Vec<widget::Id>,
+    /// State given via `Ui::restore_state` that is yet to be restored to its widgets.
+    pending_saved_states: widget::SavedStates,
+    /// The time elapsed via `Ui::tick` since the last mouse, keyboard, touch or text input.
+    time_since_last_input: std::time::Duration,
+    /// The time returned by `Ui::now` in place of the system clock, if any.
+    maybe_fixed_now: Option<instant::Instant>,
 
     // TODO: Remove the following fields as they should now be handled by `input::Global`.
     /// Window width.
@@ -125,6 +185,7 @@ impl UiBuilder {
             window_dimensions: window_dimensions,
             maybe_theme: None,
             maybe_widgets_capacity: None,
+            maybe_fonts: None,
         }
     }
 
@@ -154,6 +215,23 @@ impl UiBuilder {
         self
     }
 
+    /// The fonts with which the `Ui` is initialised.
+    ///
+    /// Cloning a `font::Map` shares its loaded fonts rather than duplicating them, so a clone of
+    /// one `Ui`'s `fonts` may be given here to share them between multiple `Ui`s, e.g. one for
+    /// each window of an application.
+    pub fn fonts(mut self, value: text::font::Map) -> Self {
+        self.maybe_fonts = Some(value);
+        self
+    }
+
+    /// The fonts with which the `Ui` will be initialised, if any were given via `fonts`.
+    ///
+    /// If this is `None` when `build` is called, an empty `font::Map` will be used.
+    pub fn maybe_fonts(&self) -> Option<&text::font::Map> {
+        self.maybe_fonts.as_ref()
+    }
+
     /// Build **Ui** from the given builder
     pub fn build(self) -> Ui {
         Ui::new(self)
@@ -167,6 +245,7 @@ impl Ui {
             window_dimensions,
             maybe_widgets_capacity,
             maybe_theme,
+            maybe_fonts,
         } = builder;
 
         let (mut widget_graph, depth_order, updated_widgets) = maybe_widgets_capacity.map_or_else(
@@ -190,11 +269,25 @@ impl Ui {
         );
 
         let window = widget_graph.add_placeholder();
+        let tooltip = Tooltip {
+            texts: fnv::FnvHashMap::default(),
+            owners: fnv::FnvHashSet::default(),
+            maybe_hovered: None,
+            maybe_displayed: None,
+            background: widget_graph.add_placeholder(),
+            text: widget_graph.add_placeholder(),
+        };
+        let context_menu = ContextMenu {
+            maybe_open: None,
+            owners: fnv::FnvHashSet::default(),
+            background: widget_graph.add_placeholder(),
+            items: widget::id::List::new(),
+        };
         let prev_updated_widgets = updated_widgets.clone();
         Ui {
             widget_graph: widget_graph,
             theme: maybe_theme.unwrap_or_else(|| Theme::default()),
-            fonts: text::font::Map::new(),
+            fonts: maybe_fonts.unwrap_or_else(text::font::Map::new),
             window: window,
             win_w: window_dimensions[0],
             win_h: window_dimensions[1],
@@ -208,7 +301,18 @@ impl Ui {
             prev_updated_widgets: prev_updated_widgets,
             global_input: input::Global::new(),
             pending_scroll_events: Vec::new(),
+            pending_input: Vec::new(),
             mouse_cursor: cursor::MouseCursor::Arrow,
+            mouse_cursor_requests: fnv::FnvHashMap::default(),
+            continuous_updates_requested: false,
+            interaction_log: Vec::new(),
+            tooltip: tooltip,
+            context_menu: context_menu,
+            overlays: Vec::new(),
+            dismissed_overlays: Vec::new(),
+            pending_saved_states: widget::SavedStates::new(),
+            time_since_last_input: std::time::Duration::from_secs(0),
+            maybe_fixed_now: None,
         }
     }
 
@@ -232,6 +336,18 @@ impl Ui {
         self.widget_graph.widget(id).map(|widget| widget.rect)
     }
 
+    /// The **Rect** of every widget that was instantiated during the last call to
+    /// `Ui::set_widgets`, ordered by `widget::Id`.
+    ///
+    /// As the order is stable, this is useful for catching layout regressions by comparing the
+    /// snapshot against the rects expected of some layout.
+    pub fn layout_snapshot(&self) -> BTreeMap<widget::Id, Rect> {
+        self.updated_widgets
+            .iter()
+            .filter_map(|&id| self.rect_of(id).map(|rect| (id, rect)))
+            .collect()
+    }
+
     /// The absolute width of the widget at the given index.
     ///
     /// Returns `None` if there is no widget for the given index.
@@ -269,6 +385,22 @@ impl Ui {
             .map(|widget| widget.kid_area.rect.padding(widget.kid_area.pad))
     }
 
+    /// All widgets under the given `xy` position, ordered from the topmost to the bottommost.
+    ///
+    /// As with the widget under the mouse, a widget that is a graphic element of some other widget
+    /// is represented by its graphic parent, and each widget appears only once.
+    pub fn pick_widgets(&self, xy: Point) -> Vec<widget::Id> {
+        let depth_order = &self.depth_order.indices;
+        let mut picked = graph::algo::pick_widgets(depth_order, xy);
+        let mut widgets = Vec::new();
+        while let Some(id) = picked.next(&self.widget_graph, depth_order, &self.theme) {
+            if !widgets.contains(&id) {
+                widgets.push(id);
+            }
+        }
+        widgets
+    }
+
     /// An index to the previously updated widget if there is one.
     pub fn maybe_prev_widget(&self) -> Option<widget::Id> {
         self.maybe_prev_widget_id
@@ -303,6 +435,94 @@ impl Ui {
         widget::id::Generator::new(&mut self.widget_graph)
     }
 
+    /// Drop the cached unique state of the widget at the given index.
+    ///
+    /// The next time the widget is `set`, `Widget::init_state` will be called to produce a fresh
+    /// state rather than re-using the previously cached state. This is useful for resetting a
+    /// widget (or a form of widgets) back to its defaults.
+    ///
+    /// Does nothing if there is no widget for the given index.
+    pub fn reset_widget_state(&mut self, id: widget::Id) {
+        if let Some(container) = self.widget_graph.widget_mut(id) {
+            container.maybe_state = None;
+            self.needs_redraw();
+        }
+    }
+
+    /// Remove all widgets from the `Ui`'s `widget_graph`, leaving only the root `window` node.
+    ///
+    /// This is useful when switching between screens that share no widgets, where the widgets of
+    /// the previous screen would otherwise linger within the graph. The `theme`, `fonts` and
+    /// input state are preserved, however any widget capturing the mouse or keyboard is released.
+    ///
+    /// **Note:** all `widget::Id`s previously produced by the `widget_id_generator` (e.g. those
+    /// within a `widget_ids!` generated struct) are invalidated and must be regenerated before
+    /// being used to instantiate widgets again.
+    pub fn clear_widgets(&mut self) {
+        self.widget_graph.clear();
+        let window = self.widget_graph.add_placeholder();
+        let background = self.widget_graph.add_placeholder();
+        let text = self.widget_graph.add_placeholder();
+        let menu = self.widget_graph.add_placeholder();
+        debug_assert_eq!(
+            (window, background, text, menu),
+            (
+                self.window,
+                self.tooltip.background,
+                self.tooltip.text,
+                self.context_menu.background
+            )
+        );
+        self.tooltip.texts.clear();
+        self.tooltip.owners.clear();
+        self.tooltip.maybe_hovered = None;
+        self.tooltip.maybe_displayed = None;
+        self.context_menu.maybe_open = None;
+        self.context_menu.owners.clear();
+        self.context_menu.items = widget::id::List::new();
+        self.overlays.clear();
+        self.dismissed_overlays.clear();
+        self.mouse_cursor_requests.clear();
+        self.depth_order.indices.clear();
+        self.updated_widgets.clear();
+        self.prev_updated_widgets.clear();
+        self.maybe_prev_widget_id = None;
+        self.maybe_current_parent_id = None;
+        for state in &mut [&mut self.global_input.start, &mut self.global_input.current] {
+            state.widget_capturing_keyboard = None;
+            state.widget_capturing_mouse = None;
+            state.widget_under_mouse = None;
+        }
+        self.needs_redraw();
+    }
+
+    /// Save the state of all widgets given a stable key via `Widget::persist_as` that were
+    /// updated during the last call to `Ui::set_widgets`.
+    ///
+    /// The resulting `SavedStates` may be persisted by the application (e.g. across sessions) and
+    /// later given to `Ui::restore_state`.
+    pub fn save_state(&self) -> widget::SavedStates {
+        let mut saved_states = widget::SavedStates::new();
+        for &id in &self.updated_widgets {
+            if let Some(container) = self.widget_graph.widget(id) {
+                if let Some(key) = container.maybe_state_key {
+                    let saved = (container.save_state.0)(container);
+                    saved_states.insert(key.to_string(), saved);
+                }
+            }
+        }
+        saved_states
+    }
+
+    /// Restore the given state previously produced by `Ui::save_state`.
+    ///
+    /// The state and scroll offset of each widget are restored upon the next update of the widget
+    /// with the same key. Entries saved for a different type of widget (see
+    /// `Widget::saved_state_type`) or that the widget considers incompatible are ignored.
+    pub fn restore_state(&mut self, saved_states: widget::SavedStates) {
+        self.pending_saved_states = saved_states;
+    }
+
     /// Scroll the widget at the given index by the given offset amount.
     ///
     /// The produced `Scroll` event will be applied upon the next call to `Ui::set_widgets`.
@@ -323,6 +543,58 @@ impl Ui {
         }
     }
 
+    /// Set the scroll offset of the scrollable widget at the given index.
+    ///
+    /// Unlike `Ui::scroll_widget`, the offset is written directly into the widget's scroll state.
+    /// See `Graph::set_scroll_offset` for details.
+    pub fn set_scroll_offset(&mut self, id: widget::Id, offset: Point) {
+        if self.widget_graph.set_scroll_offset(id, offset) {
+            self.needs_redraw();
+        }
+    }
+
+    /// Scroll the scrollable widget at the `container` index so that the widget at the `child`
+    /// index is brought fully into view, e.g. to jump to a search result.
+    ///
+    /// See `Graph::scroll_to_widget` for details.
+    pub fn scroll_to_widget(&mut self, container: widget::Id, child: widget::Id) {
+        if self.widget_graph.scroll_to_widget(container, child) {
+            self.needs_redraw();
+        }
+    }
+
+    /// Bring the floating widget at the given index in front of all other floating widgets.
+    ///
+    /// See `Graph::raise_to_front` for details.
+    pub fn raise_to_front(&mut self, id: widget::Id) {
+        if self.widget_graph.raise_to_front(id) {
+            self.update_depth_order();
+            self.needs_redraw();
+        }
+    }
+
+    /// Send the floating widget at the given index behind all other floating widgets.
+    ///
+    /// See `Graph::send_to_back` for details.
+    pub fn send_to_back(&mut self, id: widget::Id) {
+        if self.widget_graph.send_to_back(id) {
+            self.update_depth_order();
+            self.needs_redraw();
+        }
+    }
+
+    /// Update the **DepthOrder** so that it reflects the **Graph**'s current state.
+    fn update_depth_order(&mut self) {
+        let Ui {
+            ref widget_graph,
+            ref mut depth_order,
+            window,
+            ref updated_widgets,
+            ..
+        } = *self;
+        depth_order.update(widget_graph, window, updated_widgets);
+    }
+
     /// Determines which widget is currently under the mouse and sets it within the `Ui`'s
     /// `input::Global`'s `input::State`.
     ///
@@ -337,12 +609,32 @@ impl Ui {
     /// released.
     ///
     /// Note: This function expects that `ui.global_input.current.mouse.xy` is up-to-date.
+    ///
+    /// While any overlays are open, only the widgets of the topmost overlay under the mouse
+    /// cursor may be under the mouse.
     fn track_widget_under_mouse_and_update_capturing(&mut self) {
-        self.global_input.current.widget_under_mouse = graph::algo::pick_widgets(
-            &self.depth_order.indices,
-            self.global_input.current.mouse.xy,
-        )
-        .next(&self.widget_graph, &self.depth_order.indices, &self.theme);
+        let xy = self.global_input.current.mouse.xy;
+        let mut picked = graph::algo::pick_widgets(&self.depth_order.indices, xy);
+        self.global_input.current.widget_under_mouse = if self.overlays.is_empty() {
+            picked.next(&self.widget_graph, &self.depth_order.indices, &self.theme)
+        } else {
+            match self.topmost_overlay_at(xy) {
+                None => None,
+                Some(idx) => {
+                    let overlay = self.overlays[idx];
+                    let graph = &self.widget_graph;
+                    let mut widget_under_mouse = None;
+                    while let Some(id) = picked.next(graph, &self.depth_order.indices, &self.theme)
+                    {
+                        if id == overlay || graph.does_recursive_depth_edge_exist(overlay, id) {
+                            widget_under_mouse = Some(id);
+                            break;
+                        }
+                    }
+                    widget_under_mouse
+                }
+            }
+        };
 
         // If MouseButton::Left is up and `widget_under_mouse` has changed, capture new widget
         // under mouse.
@@ -403,6 +695,16 @@ impl Ui {
             }
         }
 
+        // Any input from the user resets the idle time.
+        match event {
+            Input::Press(_)
+            | Input::Release(_)
+            | Input::Motion(_)
+            | Input::Touch(_)
+            | Input::Text(_) => self.time_since_last_input = std::time::Duration::from_secs(0),
+            Input::Resize(..) | Input::Focus(_) | Input::Redraw => (),
+        }
+
         // Here we handle all user input given to conrod.
         //
         // Not only do we store the `Input` event as an `Event::Raw`, we also use them to
@@ -453,6 +755,16 @@ impl Ui {
                         .mouse
                         .buttons
                         .press(mouse_button, xy, widget);
+
+                    // Dismiss each overlay above the topmost overlay under the mouse.
+                    if !self.overlays.is_empty() {
+                        let num_kept = self.topmost_overlay_at(xy).map_or(0, |idx| idx + 1);
+                        while self.overlays.len() > num_kept {
+                            let overlay = self.overlays.pop().unwrap();
+                            self.dismissed_overlays.push(overlay);
+                        }
+                        self.track_widget_under_mouse_and_update_capturing();
+                    }
                 }
 
                 Button::Keyboard(key) => {
@@ -462,8 +774,28 @@ impl Ui {
                         modifiers: self.global_input.current.modifiers,
                     };
                     let widget = self.global_input.current.widget_capturing_keyboard;
-                    let press_event = event::Ui::Press(widget, press).into();
-                    self.global_input.push_event(press_event);
+
+                    // If `Tab` was pressed, move the keyboard focus to the next (or with `Shift`,
+                    // the previous) widget that accepts focus. The press is consumed by the
+                    // navigation rather than delivered to the previously focused widget.
+                    let maybe_next_focus = match key {
+                        Key::Tab => {
+                            let reverse = self
+                                .global_input
+                                .current
+                                .modifiers
+                                .contains(ModifierKey::SHIFT);
+                            self.next_focusable(widget, reverse)
+                        }
+                        _ => None,
+                    };
+                    match maybe_next_focus {
+                        Some(next) => self.keyboard_capture(next),
+                        None => {
+                            let press_event = event::Ui::Press(widget, press).into();
+                            self.global_input.push_event(press_event);
+                        }
+                    }
 
                     // If some modifier key was pressed, add it to the current modifiers.
                     if let Some(modifier) = filter_modifier(key) {
@@ -528,7 +860,7 @@ impl Ui {
                         let click_event = event::Ui::Click(clicked_widget, click).into();
                         self.global_input.push_event(click_event);
 
-                        let now = instant::Instant::now();
+                        let now = self.now();
                         let double_click =
                             self.global_input
                                 .last_click
@@ -845,7 +1177,7 @@ impl Ui {
 
                     // The start of the touch interaction state to be stored.
                     let start = input::state::touch::Start {
-                        time: instant::Instant::now(),
+                        time: self.now(),
                         xy: touch.xy,
                         widget: widget_under_touch,
                     };
@@ -962,17 +1294,43 @@ impl Ui {
         &self.global_input
     }
 
+    /// Advance the `Ui`'s notion of time by the given duration.
+    ///
+    /// This should be called once per frame with the time elapsed since the previous call, and is
+    /// used to track the `time_since_last_input`.
+    pub fn tick(&mut self, elapsed: std::time::Duration) {
+        self.time_since_last_input += elapsed;
+    }
+
+    /// The time elapsed via `Ui::tick` since the last mouse, keyboard, touch or text input was
+    /// handled by `Ui::handle_event`.
+    ///
+    /// This is useful for hiding controls after a period of inactivity, e.g. within a video
+    /// player.
+    pub fn time_since_last_input(&self) -> std::time::Duration {
+        self.time_since_last_input
+    }
+
+    /// The current time as seen by the `Ui`, used to time widget animations, delays and timeouts.
+    ///
+    /// This is the system time unless a fixed time was given via `Ui::set_now`.
+    pub fn now(&self) -> instant::Instant {
+        self.maybe_fixed_now.unwrap_or_else(instant::Instant::now)
+    }
+
+    /// Fix the time returned by `Ui::now`, or return to the system clock by passing `None`.
+    ///
+    /// This allows for stepping time-based behaviour deterministically, e.g. within tests.
+    pub fn set_now(&mut self, maybe_now: Option<instant::Instant>) {
+        self.maybe_fixed_now = maybe_now;
+    }
+
     /// Set keyboard capturing widget
     pub fn keyboard_capture(&mut self, idx: widget::Id) {
         let source = input::Source::Keyboard;
 
-        if self
-            .global_input
-            .current
-            .widget_capturing_keyboard
-            .is_some()
-        {
-            let event = event::Ui::WidgetUncapturesInputSource(idx, source);
+        if let Some(capturing) = self.global_input.current.widget_capturing_keyboard {
+            let event = event::Ui::WidgetUncapturesInputSource(capturing, source);
             self.global_input.push_event(event.into());
             self.global_input.current.widget_capturing_keyboard = None;
         }
@@ -1138,6 +1496,8 @@ impl Ui {
     pub fn set_widgets(&mut self) -> UiCell {
         self.maybe_prev_widget_id = None;
         self.maybe_current_parent_id = None;
+        self.continuous_updates_requested = false;
+        self.interaction_log.clear();
 
         // Move the previous `updated_widgets` to `prev_updated_widgets` and clear
         // `updated_widgets` so that we're ready to store the newly updated widgets.
@@ -1151,6 +1511,8 @@ impl Ui {
             updated_widgets.clear();
         }
 
+        self.update_context_menu();
+
         let mut ui_cell = UiCell { ui: self };
 
         // Instantiate the root `Window` `Widget`.
@@ -1158,24 +1520,21 @@ impl Ui {
         // This widget acts as the parent-most widget and root node for the Ui's `widget_graph`,
         // upon which all other widgets are placed.
         {
-            use {color, Borderable, Colorable, Positionable};
+            use {Borderable, Colorable, Positionable};
             type Window = widget::BorderedRectangle;
             Window::new([ui_cell.win_w, ui_cell.win_h])
                 .no_parent()
                 .x_y(0.0, 0.0)
                 .border(0.0)
                 .border_color(color::BLACK.alpha(0.0))
-                .color(
-                    ui_cell
-                        .maybe_background_color
-                        .unwrap_or(color::BLACK.alpha(0.0)),
-                )
+                .color(ui_cell.background_color())
                 .set(ui_cell.window, &mut ui_cell);
         }
 
         ui_cell.ui.maybe_current_parent_id = Some(ui_cell.window.into());
 
         ui_cell.set_mouse_cursor(cursor::MouseCursor::Arrow);
+        ui_cell.ui.mouse_cursor_requests.clear();
 
         ui_cell
     }
@@ -1199,8 +1558,143 @@ impl Ui {
     ///
     /// This method sets the colour with which this `Rectangle` is drawn (the default being
     /// `conrod::color::TRANSPARENT`.
+    ///
+    /// This is the same as `Ui::set_background_color`.
     pub fn clear_with(&mut self, color: Color) {
-        self.maybe_background_color = Some(color);
+        self.set_background_color(color);
+    }
+
+    /// The text of the tooltip displayed by the shared tooltip overlay during the last call to
+    /// `Ui::set_widgets`, if any.
+    ///
+    /// See `UiCell::tooltip`.
+    pub fn displayed_tooltip(&self) -> Option<&str> {
+        self.tooltip
+            .maybe_displayed
+            .and_then(|id| self.tooltip.texts.get(&id))
+            .map(|text| &text[..])
+    }
+
+    /// The widget whose context menu is currently open, along with the absolute position of the
+    /// mouse at which it was opened.
+    ///
+    /// See `UiCell::context_menu`.
+    pub fn open_context_menu(&self) -> Option<(widget::Id, Point)> {
+        self.context_menu.maybe_open
+    }
+
+    /// The stack of open overlays from the bottom to the top.
+    ///
+    /// See `UiCell::open_overlay`.
+    pub fn overlays(&self) -> &[widget::Id] {
+        &self.overlays
+    }
+
+    /// The overlays that were dismissed by pressing the mouse outside of them since the end of
+    /// the last call to `Ui::set_widgets`, from the top down.
+    pub fn dismissed_overlays(&self) -> &[widget::Id] {
+        &self.dismissed_overlays
+    }
+
+    /// Close the overlay with the given `id` along with every overlay above it.
+    pub fn close_overlay(&mut self, id: widget::Id) {
+        if let Some(idx) = self.overlays.iter().position(|&overlay| overlay == id) {
+            self.overlays.truncate(idx);
+            self.track_widget_under_mouse_and_update_capturing();
+        }
+    }
+
+    /// Open or close the shared context menu in response to this update's input.
+    ///
+    /// Right-clicking a widget (or one of its children) given a context menu during the last
+    /// update opens the menu at the mouse, while clicking anywhere else or pressing `Escape` closes
+    /// it.
+    fn update_context_menu(&mut self) {
+        use input::{Key, MouseButton};
+
+        let background = self.context_menu.background;
+        let mut maybe_open = self.context_menu.maybe_open;
+        if self.dismissed_overlays.contains(&background) {
+            maybe_open = None;
+        }
+        for event in self.global_input.events().ui() {
+            match *event {
+                event::Ui::Click(maybe_id, click) => {
+                    let is_on_menu = |id| {
+                        id == background
+                            || self
+                                .widget_graph
+                                .does_recursive_depth_edge_exist(background, id)
+                    };
+                    if maybe_id.into_iter().any(is_on_menu) {
+                        continue;
+                    }
+                    maybe_open = None;
+                    if let MouseButton::Right = click.button {
+                        // Find the first widget from the one clicked through its parents with a
+                        // context menu.
+                        let mut maybe_id = maybe_id;
+                        while let Some(id) = maybe_id {
+                            if !self.prev_updated_widgets.contains(&id) {
+                                break;
+                            }
+                            if self.context_menu.owners.contains(&id) {
+                                maybe_open = Some((id, click.xy));
+                                break;
+                            }
+                            maybe_id = self.widget_graph.depth_parent(id);
+                        }
+                    }
+                }
+                event::Ui::Press(
+                    _,
+                    event::Press {
+                        button: event::Button::Keyboard(Key::Escape),
+                        ..
+                    },
+                ) => maybe_open = None,
+                _ => (),
+            }
+        }
+
+        self.context_menu.maybe_open = maybe_open;
+        self.context_menu.owners.clear();
+        if maybe_open.is_none() {
+            self.close_overlay(background);
+        }
+    }
+
+    // The index of the topmost overlay whose widget, or one of its descendants, is over `xy`.
+    fn topmost_overlay_at(&self, xy: Point) -> Option<usize> {
+        let graph = &self.widget_graph;
+        let depth_order = &self.depth_order.indices;
+        self.overlays.iter().rposition(|&overlay| {
+            depth_order
+                .iter()
+                .filter(|&&id| id == overlay || graph.does_recursive_depth_edge_exist(overlay, id))
+                .filter_map(|&id| graph.widget(id))
+                .any(|container| container.rect.is_over(xy))
+        })
+    }
+
+    /// Set the root-level background color of the `Ui`.
+    ///
+    /// The window `Rectangle` yielded as the first of the `Primitives` is drawn with this color,
+    /// so the background is consistent whether or not a root `Canvas` is used. Backends may also
+    /// read it via `Ui::background_color` to clear the window before drawing.
+    pub fn set_background_color(&mut self, color: Color) {
+        if self.maybe_background_color != Some(color) {
+            self.maybe_background_color = Some(color);
+            self.needs_redraw();
+        }
+    }
+
+    /// The root-level background color of the `Ui`.
+    ///
+    /// Returns `conrod::color::TRANSPARENT` if no color has been set via
+    /// `Ui::set_background_color`.
+    pub fn background_color(&self) -> Color {
+        self.maybe_background_color.unwrap_or(color::TRANSPARENT)
     }
 
     /// Draw the `Ui` in it's current state.
@@ -1259,11 +1753,36 @@ impl Ui {
         self.redraw_count.load(atomic::Ordering::Relaxed) > 0
     }
 
+    /// Whether or not the application may block while waiting for the next window event rather
+    /// than continuing to update the `Ui` every frame.
+    ///
+    /// Returns `true` if all of the following hold:
+    ///
+    /// - No widget called `UiCell::request_continuous_updates` during the last `set_widgets`.
+    /// - The `Ui` has no remaining frames to redraw (see `Ui::has_changed`).
+    /// - There are no pending events waiting to be delivered to widgets.
+    ///
+    /// This is useful for event loops that wish to avoid spinning the CPU while the GUI is idle.
+    pub fn should_wait_for_events(&self) -> bool {
+        !self.continuous_updates_requested
+            && !self.has_changed()
+            && self.global_input.events().next().is_none()
+    }
+
     /// The **Rect** that bounds the kids of the widget with the given index.
     pub fn kids_bounding_box(&self, id: widget::Id) -> Option<Rect> {
         graph::algo::kids_bounding_box(&self.widget_graph, &self.prev_updated_widgets, id)
     }
 
+    /// The **Rect** that bounds the kids of the widget with the given index as they would be
+    /// positioned if the widget were not scrolled.
+    ///
+    /// This is the total area that may be scrolled through, useful for sizing a scrollbar's
+    /// handle. See `graph::algo::scrollable_bounding_box` for details.
+    pub fn scrollable_bounding_box(&self, id: widget::Id) -> Option<Rect> {
+        graph::algo::scrollable_bounding_box(&self.widget_graph, &self.prev_updated_widgets, id)
+    }
+
     /// The **Rect** that represents the maximum fully visible area for the widget with the given
     /// index, including consideration of cropped scroll area.
     ///
@@ -1272,8 +1791,49 @@ impl Ui {
         graph::algo::cropped_area_of_widget(&self.widget_graph, id)
     }
 
+    /// The next widget in depth order that accepts keyboard focus, following the `current` one.
+    ///
+    /// This is the widget to which the keyboard is captured when `Tab` is pressed (or the
+    /// previous such widget if `reverse` is `true`, as with `Shift+Tab`). See
+    /// `graph::algo::next_focusable` for details.
+    pub fn next_focusable(&self, current: Option<widget::Id>, reverse: bool) -> Option<widget::Id> {
+        graph::algo::next_focusable(
+            &self.widget_graph,
+            &self.depth_order.indices,
+            current,
+            reverse,
+        )
+    }
+
+    /// The interactions reported by widgets during the last call to `Ui::set_widgets`, in the
+    /// order in which they occurred.
+    ///
+    /// This provides a single place in which all widget activity may be observed, e.g. for testing
+    /// or debugging. See `UiCell::log_interaction` for how widgets report interactions.
+    pub fn interaction_log(&self) -> &[event::Interaction] {
+        &self.interaction_log
+    }
+
     /// Get mouse cursor state.
+    ///
+    /// This is the cursor requested via `UiCell::request_mouse_cursor` by the widget capturing the
+    /// mouse (or otherwise the widget under the mouse), or by the nearest of its parents with a
+    /// request of its own. If there is no such request, this is the cursor set via
+    /// `UiCell::set_mouse_cursor`, which defaults to `Arrow`.
+    ///
+    /// Backends should apply this cursor to the window after each call to `Ui::set_widgets` and
+    /// `Ui::handle_event`.
     pub fn mouse_cursor(&self) -> cursor::MouseCursor {
+        let current = &self.global_input.current;
+        let mut maybe_id = current
+            .widget_capturing_mouse
+            .or(current.widget_under_mouse);
+        while let Some(id) = maybe_id {
+            if let Some(&cursor) = self.mouse_cursor_requests.get(&id) {
+                return cursor;
+            }
+            maybe_id = self.widget_graph.depth_parent(id);
+        }
         self.mouse_cursor
     }
 }
@@ -1323,6 +1883,22 @@ impl<'a> UiCell<'a> {
         self.ui.kids_bounding_box(id)
     }
 
+    /// The **Rect** that bounds the kids of the widget with the given index as they would be
+    /// positioned if the widget were not scrolled.
+    ///
+    /// Returns `None` if the widget has no children or if there's is no widget for the given index.
+    pub fn scrollable_bounding_box(&self, id: widget::Id) -> Option<Rect> {
+        self.ui.scrollable_bounding_box(id)
+    }
+
+    /// Drop the cached unique state of the widget at the given index so that its state is
+    /// re-initialised the next time it is `set`.
+    ///
+    /// See `Ui::reset_widget_state` for details.
+    pub fn reset_widget_state(&mut self, id: widget::Id) {
+        self.ui.reset_widget_state(id)
+    }
+
     /// Scroll the widget at the given index by the given offset amount.
     ///
     /// The produced `Scroll` event will be pushed to the `pending_scroll_events` and will be
@@ -1343,38 +1919,342 @@ impl<'a> UiCell<'a> {
         }
     }
 
+    /// Set the scroll offset of the scrollable widget at the given index.
+    ///
+    /// See `Ui::set_scroll_offset` for details.
+    pub fn set_scroll_offset(&mut self, id: widget::Id, offset: Point) {
+        self.ui.set_scroll_offset(id, offset)
+    }
+
+    /// Scroll the scrollable widget at the `container` index so that the widget at the `child`
+    /// index is brought fully into view.
+    ///
+    /// See `Ui::scroll_to_widget` for details.
+    pub fn scroll_to_widget(&mut self, container: widget::Id, child: widget::Id) {
+        self.ui.scroll_to_widget(container, child)
+    }
+
+    /// Bring the floating widget at the given index in front of all other floating widgets.
+    ///
+    /// See `Ui::raise_to_front` for details.
+    pub fn raise_to_front(&mut self, id: widget::Id) {
+        self.ui.raise_to_front(id)
+    }
+
+    /// Send the floating widget at the given index behind all other floating widgets.
+    ///
+    /// See `Ui::send_to_back` for details.
+    pub fn send_to_back(&mut self, id: widget::Id) {
+        self.ui.send_to_back(id)
+    }
+
+    /// Open the widget with the given `id` as an overlay, e.g. a menu or popup, on top of the
+    /// `Ui`'s stack of overlays.
+    ///
+    /// While any overlays are open, mouse input is routed to the topmost overlay under the mouse
+    /// and widgets beneath the overlays do not receive it. Pressing the mouse dismisses every
+    /// overlay above the topmost one under the mouse, so that clicking within a nested overlay
+    /// (e.g. a submenu) keeps its parents open, while clicking outside of all overlays dismisses
+    /// them all. See `Ui::dismissed_overlays`.
+    ///
+    /// Overlays are closed once their widget is no longer instantiated. Has no effect if the
+    /// overlay is already open.
+    pub fn open_overlay(&mut self, id: widget::Id) {
+        if !self.ui.overlays.contains(&id) {
+            self.ui.overlays.push(id);
+        }
+    }
+
+    /// Close the overlay with the given `id` along with every overlay above it.
+    pub fn close_overlay(&mut self, id: widget::Id) {
+        self.ui.close_overlay(id);
+    }
+
+    /// Give the widget with the given `id` a context menu with the given items, returning the
+    /// index of the item chosen during this update, if any.
+    ///
+    /// This should be called after the widget is set during each update for which it has the
+    /// menu. Right-clicking the widget (or any of its children without a context menu of their
+    /// own) opens the `Ui`'s shared context menu at the mouse cursor, which this call then
+    /// instantiates. Only one context menu may be open at a time. Clicking elsewhere or pressing
+    /// `Escape` closes it.
+    ///
+    /// The open menu is an overlay (see `UiCell::open_overlay`), so the widgets beneath it do not
+    /// receive mouse input and it may itself host nested overlays.
+    pub fn context_menu(&mut self, id: widget::Id, items: &[&str]) -> Option<usize> {
+        self.ui.context_menu.owners.insert(id);
+        let [mouse_x, mouse_y] = match self.context_menu.maybe_open {
+            Some((owner, xy)) if owner == id => xy,
+            _ => return None,
+        };
+
+        if self.context_menu.items.len() < items.len() {
+            let Ui {
+                ref mut widget_graph,
+                ref mut context_menu,
+                ..
+            } = *self.ui;
+            let id_gen = &mut widget::id::Generator::new(widget_graph);
+            context_menu.items.resize(items.len(), id_gen);
+        }
+
+        // Place the menu below and to the right of the mouse, within the window.
+        use {Labelable, Positionable, Sizeable};
+        const PAD: Scalar = 6.0;
+        const MIN_W: Scalar = 100.0;
+        let font_id = self.theme.font_id.or_else(|| self.fonts.ids().next());
+        let font_size = self.theme.font_size_medium;
+        let text_w = font_id
+            .and_then(|id| self.fonts.get(id))
+            .map_or(0.0, |font| {
+                items
+                    .iter()
+                    .map(|item| text::line::width(item, font, font_size))
+                    .fold(0.0, utils::partial_max)
+            });
+        let item_h = text::height(1, font_size, 0.0) + PAD * 2.0;
+        let (w, h) = (
+            utils::partial_max(text_w + PAD * 4.0, MIN_W),
+            item_h * items.len() as Scalar,
+        );
+        let (max_x, min_y) = (self.win_w / 2.0 - w / 2.0, -self.win_h / 2.0 + h / 2.0);
+        let x = utils::partial_min(mouse_x + w / 2.0, max_x);
+        let y = utils::partial_max(mouse_y - h / 2.0, min_y);
+
+        // Instantiating the menu must not affect the positioning of the widgets that follow.
+        let maybe_prev_widget_id = self.maybe_prev_widget_id;
+        let maybe_current_parent_id = self.maybe_current_parent_id;
+        let mut maybe_chosen = None;
+        let (window, background) = (self.window, self.context_menu.background);
+        widget::Rectangle::fill_with([w, h], self.theme.shape_color)
+            .x_y(x, y)
+            .parent(window)
+            .floating(true)
+            .set(background, self);
+        self.open_overlay(background);
+        let item_ids: Vec<widget::Id> = self.context_menu.items.iter().cloned().collect();
+        for (i, (&item, &item_id)) in items.iter().zip(item_ids.iter()).enumerate() {
+            let item_y = y + h / 2.0 - item_h * (i as Scalar + 0.5);
+            let mut button = widget::Button::new()
+                .w_h(w, item_h)
+                .x_y(x, item_y)
+                .label(item)
+                .label_font_size(font_size)
+                .parent(background);
+            if let Some(font_id) = font_id {
+                button = button.label_font_id(font_id);
+            }
+            if button.set(item_id, self).was_clicked() {
+                self.ui.context_menu.maybe_open = None;
+                self.close_overlay(background);
+                maybe_chosen = Some(i);
+            }
+        }
+        self.ui.maybe_prev_widget_id = maybe_prev_widget_id;
+        self.ui.maybe_current_parent_id = maybe_current_parent_id;
+        maybe_chosen
+    }
+
+    /// Give the widget with the given `id` a tooltip with the given text.
+    ///
+    /// This should be called during each update for which the widget has the tooltip. Once the
+    /// widget (or any of its children without a tooltip of their own) has been hovered for the
+    /// `Theme`'s `tooltip_delay`, the `Ui`'s shared tooltip overlay displays the text beside the
+    /// mouse cursor.
+    pub fn tooltip(&mut self, id: widget::Id, text: &str) {
+        let tooltip = &mut self.ui.tooltip;
+        tooltip.owners.insert(id);
+        let existing = tooltip.texts.entry(id).or_default();
+        if existing != text {
+            existing.clear();
+            existing.push_str(text);
+        }
+    }
+
+    /// Push the given input event as though it were received from the window.
+    ///
+    /// This allows widgets such as on-screen keyboards to synthesise input. The event will be
+    /// handled via `Ui::handle_event` once the `UiCell` is dropped, so that widgets receive it
+    /// during the next call to `Ui::set_widgets`.
+    pub fn push_input(&mut self, input: event::Input) {
+        self.ui.pending_input.push(input);
+    }
+
     /// Sets the mouse cursor
     pub fn set_mouse_cursor(&mut self, cursor: cursor::MouseCursor) {
         self.ui.mouse_cursor = cursor;
     }
+
+    /// Request the given mouse cursor for while the widget with the given `id` (or any of its
+    /// children without a request of their own) is under the mouse or capturing it.
+    ///
+    /// This should be called by widgets from within their `update` methods during each call to
+    /// `Ui::set_widgets` for as long as the cursor is desired. See `Ui::mouse_cursor`.
+    pub fn request_mouse_cursor(&mut self, id: widget::Id, cursor: cursor::MouseCursor) {
+        self.ui.mouse_cursor_requests.insert(id, cursor);
+    }
+
+    /// Set the widget that is capturing the keyboard.
+    ///
+    /// See `Ui::keyboard_capture`.
+    pub fn keyboard_capture(&mut self, id: widget::Id) {
+        self.ui.keyboard_capture(id);
+    }
+
+    /// Indicates that some widget requires the `Ui` to keep updating even in the absence of new
+    /// input, e.g. because it is animating.
+    ///
+    /// This must be requested during each call to `Ui::set_widgets` for as long as updates are
+    /// required. While requested, `Ui::should_wait_for_events` will return `false`.
+    pub fn request_continuous_updates(&mut self) {
+        self.ui.continuous_updates_requested = true;
+        self.ui.needs_redraw();
+    }
+
+    /// Record an interaction with the widget with the given `id` within the `Ui`'s interaction
+    /// log.
+    ///
+    /// This should be called by widgets from within their `update` methods. The log is cleared at
+    /// the beginning of each call to `Ui::set_widgets`.
+    pub fn log_interaction(
+        &mut self,
+        id: widget::Id,
+        widget_kind: &'static str,
+        kind: event::InteractionKind,
+    ) {
+        self.ui.interaction_log.push(event::Interaction {
+            widget: id,
+            widget_kind: widget_kind,
+            kind: kind,
+        });
+    }
+}
+
+impl<'a> UiCell<'a> {
+    /// Instantiate the shared tooltip overlay if the widget under the mouse (or one of its
+    /// parents) has a tooltip that has been hovered for at least the `tooltip_delay`.
+    fn set_tooltip_overlay(&mut self) {
+        // Find the first widget from the one under the mouse through its parents with a tooltip.
+        let mut maybe_id = self.global_input.current.widget_under_mouse;
+        let mut maybe_hovered = None;
+        while let Some(id) = maybe_id {
+            if !self.updated_widgets.contains(&id) {
+                break;
+            }
+            if self.tooltip.owners.contains(&id) {
+                maybe_hovered = Some(id);
+                break;
+            }
+            maybe_id = self.widget_graph.depth_parent(id);
+        }
+
+        // Track how long the widget has been hovered.
+        let now = self.now();
+        let hovered_since = match (maybe_hovered, self.ui.tooltip.maybe_hovered) {
+            (Some(id), Some((prev_id, since))) if id == prev_id => Some((id, since)),
+            (Some(id), _) => Some((id, now)),
+            (None, _) => None,
+        };
+        self.ui.tooltip.maybe_hovered = hovered_since;
+        self.ui.tooltip.maybe_displayed = None;
+
+        let owner = match hovered_since {
+            Some((id, since)) => {
+                if now.duration_since(since) < self.theme.tooltip_delay {
+                    // Keep updating until the delay has passed.
+                    self.request_continuous_updates();
+                    return;
+                }
+                id
+            }
+            None => return,
+        };
+        self.ui.tooltip.maybe_displayed = Some(owner);
+        let text = self.tooltip.texts[&owner].clone();
+        let text = &text[..];
+
+        // Place the overlay below and to the right of the mouse, within the window.
+        use {Colorable, Positionable};
+        let pad = self.theme.spacing_xs;
+        const CURSOR_OFFSET: Scalar = 16.0;
+        let font_id = self.theme.font_id.or_else(|| self.fonts.ids().next());
+        let font_size = self.theme.font_size_small;
+        let text_w = font_id
+            .and_then(|id| self.fonts.get(id))
+            .map_or(0.0, |font| text::line::width(text, font, font_size));
+        let text_h = text::height(1, font_size, 0.0);
+        let (w, h) = (text_w + pad * 2.0, text_h + pad * 2.0);
+        let [mouse_x, mouse_y] = self.global_input.current.mouse.xy;
+        let (max_x, min_y) = (self.win_w / 2.0 - w / 2.0, -self.win_h / 2.0 + h / 2.0);
+        let x = utils::partial_min(mouse_x + CURSOR_OFFSET + w / 2.0, max_x);
+        let y = utils::partial_max(mouse_y - CURSOR_OFFSET - h / 2.0, min_y);
+
+        let (window, background, text_id) =
+            (self.window, self.tooltip.background, self.tooltip.text);
+        let (color, label_color) = (self.theme.shape_color, self.theme.label_color);
+        widget::Rectangle::fill_with([w, h], color)
+            .x_y(x, y)
+            .parent(window)
+            .floating(true)
+            .set(background, self);
+        widget::Text::new(text)
+            .and_then(font_id, widget::Text::font_id)
+            .font_size(font_size)
+            .color(label_color)
+            .middle_of(background)
+            .parent(background)
+            .graphics_for(background)
+            .set(text_id, self);
+    }
 }
 
 impl<'a> Drop for UiCell<'a> {
     fn drop(&mut self) {
-        // We'll need to re-draw if we have gained or lost widgets.
-        let changed = self.ui.updated_widgets != self.ui.prev_updated_widgets;
-        if changed {
-            self.ui.needs_redraw();
+        // Close the context menu if its widget was not given one during this update.
+        if let Some((id, _)) = self.context_menu.maybe_open {
+            if !self.context_menu.owners.contains(&id) {
+                self.ui.context_menu.maybe_open = None;
+            }
         }
 
-        // Update the **DepthOrder** so that it reflects the **Graph**'s current state.
+        // Display the tooltip for the hovered widget, if any, then forget the tooltips of widgets
+        // that were not given one during this update.
+        self.set_tooltip_overlay();
+        {
+            let Tooltip {
+                ref mut texts,
+                ref mut owners,
+                ..
+            } = self.ui.tooltip;
+            texts.retain(|id, _| owners.contains(id));
+            owners.clear();
+        }
+
+        // Close any overlays that are no longer instantiated.
         {
             let Ui {
-                ref widget_graph,
-                ref mut depth_order,
-                window,
+                ref mut overlays,
                 ref updated_widgets,
                 ..
             } = *self.ui;
+            overlays.retain(|id| updated_widgets.contains(id));
+        }
+        self.ui.dismissed_overlays.clear();
 
-            depth_order.update(widget_graph, window, updated_widgets);
+        // We'll need to re-draw if we have gained or lost widgets.
+        let changed = self.ui.updated_widgets != self.ui.prev_updated_widgets;
+        if changed {
+            self.ui.needs_redraw();
         }
 
+        // Update the **DepthOrder** so that it reflects the **Graph**'s current state.
+        self.ui.update_depth_order();
+
         // Reset the global input state. Note that this is the **only** time this should be called.
         self.ui.global_input.clear_events_and_update_start_state();
 
-        // Update which widget is under the cursor.
-        if changed {
+        // Update which widget is under the cursor. Overlays may have opened or moved.
+        if changed || !self.ui.overlays.is_empty() {
             self.ui.track_widget_under_mouse_and_update_capturing();
         }
 
@@ -1383,6 +2263,12 @@ impl<'a> Drop for UiCell<'a> {
         for scroll_event in self.ui.pending_scroll_events.drain(0..) {
             self.ui.global_input.push_event(scroll_event.into());
         }
+
+        // Handle all input produced by widgets since the start of this method.
+        let pending_input = std::mem::take(&mut self.ui.pending_input);
+        for input in pending_input {
+            self.ui.handle_event(input);
+        }
     }
 }
 
@@ -1463,6 +2349,11 @@ pub fn pre_update_cache(ui: &mut Ui, widget: widget::PreUpdateCache) {
     ui.updated_widgets.insert(widget_id);
 }
 
+/// Take the state pending restoration for the widget with the given key, if there is any.
+pub fn take_saved_state(ui: &mut Ui, key: &str) -> Option<widget::SavedState> {
+    ui.pending_saved_states.remove(key)
+}
+
 /// Cache some `PostUpdateCache` widget data into the widget graph.
 /// Set the widget that is being cached as the new `prev_widget`.
 /// Set the widget's parent as the new `current_parent`.
@@ -3,7 +3,7 @@
 //!
 
 use num::{Float, NumCast, PrimInt, ToPrimitive};
-use position::{Point, Range, Rect};
+use position::{Dimensions, Point, Range, Rect};
 use std;
 use std::borrow::Cow;
 use std::iter::{once, Chain, Once};
@@ -83,6 +83,12 @@ where
         .unwrap()
 }
 
+/// Linearly interpolate from `a` to `b` by `t`, where `t` is clamped to `0.0..=1.0`.
+pub fn lerp<T: Float + NumCast>(a: T, b: T, t: f32) -> T {
+    let t: T = NumCast::from(clamp(t, 0.0, 1.0)).unwrap();
+    a + (b - a) * t
+}
+
 /// Get value percentage between max and min.
 pub fn percentage<T: Float + NumCast>(value: T, min: T, max: T) -> f32 {
     let v: f32 = NumCast::from(value).unwrap();
@@ -91,6 +97,45 @@ pub fn percentage<T: Float + NumCast>(value: T, min: T, max: T) -> f32 {
     (v - mn) / (mx - mn)
 }
 
+/// The curves available to `ease`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Easing {
+    /// Progresses at a constant rate.
+    Linear,
+    /// Accelerates quadratically to the midpoint before decelerating.
+    QuadInOut,
+    /// Accelerates cubically to the midpoint before decelerating.
+    CubicInOut,
+    /// The Hermite interpolation `3t² - 2t³`.
+    Smoothstep,
+}
+
+/// Ease the normalised progress `t` along the given curve.
+///
+/// `t` is clamped to `0.0..=1.0` and the result is also within that range, mapping `0.0` to `0.0`
+/// and `1.0` to `1.0`. Combine with `map_range` to animate between two values.
+pub fn ease(t: f32, kind: Easing) -> f32 {
+    let t = clamp(t, 0.0, 1.0);
+    match kind {
+        Easing::Linear => t,
+        Easing::QuadInOut => {
+            if t < 0.5 {
+                2.0 * t * t
+            } else {
+                1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+            }
+        }
+        Easing::CubicInOut => {
+            if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+            }
+        }
+        Easing::Smoothstep => t * t * (3.0 - 2.0 * t),
+    }
+}
+
 /// Convert turns to radians.
 pub fn turns<F: Float + NumCast>(t: F) -> F {
     use std::f32::consts::PI;
@@ -167,6 +212,19 @@ where
     [a[0] - b[0], a[1] - b[1]]
 }
 
+/// Whether or not the point `xy` lies within the ellipse of the given dimensions centered at
+/// `center`, including its rim.
+///
+/// An ellipse with a zero width or height contains no points.
+pub fn is_over_ellipse(center: Point, xy: Point, dim: Dimensions) -> bool {
+    let (rx, ry) = (dim[0] / 2.0, dim[1] / 2.0);
+    if rx <= 0.0 || ry <= 0.0 {
+        return false;
+    }
+    let (dx, dy) = (xy[0] - center[0], xy[1] - center[1]);
+    (dx / rx).powi(2) + (dy / ry).powi(2) <= 1.0
+}
+
 /// Find the bounding rect for the given series of points.
 pub fn bounding_box_for_points<I>(mut points: I) -> Rect
 where
@@ -320,3 +378,61 @@ fn test_map_range() {
     assert_eq!(map_range(2.5, 5.0, 0.0, 0.0, 10.0), 5.0);
     assert_eq!(map_range(0.0, 5.0, 0.0, 0.0, 10.0), 10.0);
 }
+
+#[test]
+fn test_is_over_ellipse() {
+    let (center, dim) = ([10.0, -5.0], [8.0, 4.0]);
+
+    // The center and the points at the end of each axis are over the ellipse.
+    assert!(is_over_ellipse(center, center, dim));
+    assert!(is_over_ellipse(center, [14.0, -5.0], dim));
+    assert!(is_over_ellipse(center, [6.0, -5.0], dim));
+    assert!(is_over_ellipse(center, [10.0, -3.0], dim));
+    assert!(is_over_ellipse(center, [10.0, -7.0], dim));
+
+    // Points just outside the rim are not.
+    assert!(!is_over_ellipse(center, [14.001, -5.0], dim));
+    assert!(!is_over_ellipse(center, [10.0, -2.999], dim));
+
+    // The corners of the bounding rectangle are not.
+    assert!(!is_over_ellipse(center, [13.9, -3.1], dim));
+
+    // An empty ellipse contains nothing.
+    assert!(!is_over_ellipse(center, center, [0.0, 4.0]));
+}
+
+#[test]
+fn test_ease() {
+    let kinds = [
+        Easing::Linear,
+        Easing::QuadInOut,
+        Easing::CubicInOut,
+        Easing::Smoothstep,
+    ];
+    for &kind in kinds.iter() {
+        // The endpoints are fixed and input beyond them is clamped.
+        assert_eq!(ease(0.0, kind), 0.0);
+        assert_eq!(ease(1.0, kind), 1.0);
+        assert_eq!(ease(-1.0, kind), 0.0);
+        assert_eq!(ease(2.0, kind), 1.0);
+
+        // Each curve is symmetric about the midpoint.
+        assert_eq!(ease(0.5, kind), 0.5);
+
+        // Progress never goes backwards.
+        let samples: Vec<f32> = (0..=100).map(|i| ease(i as f32 / 100.0, kind)).collect();
+        assert!(samples.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
+
+#[test]
+fn test_lerp() {
+    assert_eq!(lerp(2.0, 6.0, 0.0), 2.0);
+    assert_eq!(lerp(2.0, 6.0, 0.25), 3.0);
+    assert_eq!(lerp(2.0, 6.0, 1.0), 6.0);
+    assert_eq!(lerp(6.0f32, 2.0, 0.5), 4.0);
+
+    // `t` is clamped.
+    assert_eq!(lerp(2.0, 6.0, -1.0), 2.0);
+    assert_eq!(lerp(2.0, 6.0, 2.0), 6.0);
+}
@@ -20,6 +20,7 @@ widget_ids! {
     struct Ids {
         border,
         rectangle,
+        rounded_rectangle,
     }
 }
 
@@ -35,6 +36,9 @@ pub struct Style {
     /// The color of the border.
     #[conrod(default = "theme.border_color")]
     pub border_color: Option<Color>,
+    /// The radius of the circle used to round each corner. A radius of `0.0` draws sharp corners.
+    #[conrod(default = "0.0")]
+    pub corner_radius: Option<Scalar>,
 }
 
 /// Unique state for the `BorderedRectangle`.
@@ -52,7 +56,10 @@ impl BorderedRectangle {
         .wh(dim)
     }
 
-    builder_method!(pub with_style { style = Style });
+    builder_methods! {
+        pub with_style { style = Style }
+        pub corner_radius { style.corner_radius = Some(Scalar) }
+    }
 }
 
 impl Widget for BorderedRectangle {
@@ -82,6 +89,29 @@ impl Widget for BorderedRectangle {
         } = args;
 
         let border = style.border(&ui.theme);
+        let radius = style.corner_radius(&ui.theme);
+        if radius > 0.0 {
+            let resolution = widget::rounded_rectangle::DEFAULT_CORNER_RESOLUTION;
+            if border > 0.0 {
+                let border_color = style.border_color(&ui.theme);
+                let triangles = rounded_border_triangles(rect, border, radius, resolution);
+                widget::Triangles::single_color(border_color, triangles)
+                    .with_bounding_rect(rect)
+                    .parent(id)
+                    .graphics_for(id)
+                    .set(state.ids.border, ui);
+            }
+
+            let color = style.color(&ui.theme);
+            widget::RoundedRectangle::fill_with(rect.pad(border).dim(), radius, color)
+                .xy(rect.xy())
+                .corner_resolution(resolution)
+                .parent(id)
+                .graphics_for(id)
+                .set(state.ids.rounded_rectangle, ui);
+            return;
+        }
+
         if let Some(triangles) = border_triangles(rect, border) {
             let border_color = style.border_color(&ui.theme);
             widget::Triangles::single_color(border_color, triangles.iter().cloned())
@@ -1,7 +1,11 @@
 //! The `Button` widget and related items.
 
+use cursor;
+use event;
 use image;
+use input::Key;
 use position::{self, Align, Rect, Scalar};
+use std::time::Duration;
 use text;
 use widget;
 use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Sizeable, UiCell, Widget};
@@ -18,6 +22,21 @@ pub struct Button<'a, S> {
     pub style: Style,
     /// Whether or not user input is enabled.
     enabled: bool,
+    /// If `Some`, the `Button` repeatedly triggers while held.
+    maybe_repeat: Option<Repeat>,
+    /// If `Some`, the mouse cursor requested while the `Button` is hovered.
+    maybe_hover_cursor: Option<cursor::MouseCursor>,
+}
+
+/// The timing used by a `Button` that repeatedly triggers while held.
+///
+/// See the `Button::repeat` method.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Repeat {
+    /// The duration for which the `Button` must be held before it first triggers.
+    pub initial_delay: Duration,
+    /// The duration between each subsequent trigger while the `Button` remains held.
+    pub interval: Duration,
 }
 
 /// Unique styling for the Button.
@@ -32,6 +51,9 @@ pub struct Style {
     /// The color of the border.
     #[conrod(default = "theme.border_color")]
     pub border_color: Option<Color>,
+    /// The radius used to round the corners of the Button's surface.
+    #[conrod(default = "0.0")]
+    pub corner_radius: Option<Scalar>,
     /// The color of the Button's label.
     #[conrod(default = "theme.label_color")]
     pub label_color: Option<Color>,
@@ -50,6 +72,9 @@ pub struct Style {
     /// The position of the title bar's `Label` widget over the *y* axis.
     #[conrod(default = "position::Relative::Align(Align::Middle)")]
     pub label_y: Option<position::Relative>,
+    /// The direction in which the lines of the label run.
+    #[conrod(default = "widget::text::Orientation::Horizontal")]
+    pub label_orientation: Option<widget::text::Orientation>,
 }
 
 widget_ids! {
@@ -70,6 +95,15 @@ widget_ids! {
     }
 }
 
+/// Unique state for the `Button`.
+pub struct State<I> {
+    /// The `widget::Id`s of the `Button`'s graphics.
+    pub ids: I,
+    /// The moment at which the current press of a repeating `Button` began, along with the number
+    /// of times that it has triggered since.
+    maybe_press: Option<(instant::Instant, u16)>,
+}
+
 /// The `Button` simply displays a flat color.
 #[derive(Copy, Clone, Default, PartialEq, Debug)]
 pub struct Flat {
@@ -182,12 +216,16 @@ impl<'a> Button<'a, Image> {
     }
 
     /// The image displayed while the mouse hovers over the `Button`.
+    ///
+    /// If this method is not called, the normal image will be used.
     pub fn hover_image(mut self, id: image::Id) -> Self {
         self.show.hover_image_id = Some(id);
         self
     }
 
     /// The image displayed while the `Button` is pressed.
+    ///
+    /// If this method is not called, the hover image (or otherwise the normal image) will be used.
     pub fn press_image(mut self, id: image::Id) -> Self {
         self.show.press_image_id = Some(id);
         self
@@ -232,15 +270,45 @@ impl<'a, S> Button<'a, S> {
             maybe_label: None,
             style: Style::default(),
             enabled: true,
+            maybe_repeat: None,
+            maybe_hover_cursor: None,
         }
     }
 
+    /// Repeatedly trigger the `Button` while it is held, rather than only upon release.
+    ///
+    /// Once the `Button` has been held for the `initial_delay`, it triggers once and then once
+    /// more for each `interval` that it remains held. Repeating stops as soon as the mouse is
+    /// released or the cursor leaves the `Button`. A press that is released before the
+    /// `initial_delay` triggers the `Button` upon release as usual.
+    pub fn repeat(mut self, initial_delay: Duration, interval: Duration) -> Self {
+        self.maybe_repeat = Some(Repeat {
+            initial_delay: initial_delay,
+            interval: interval,
+        });
+        self
+    }
+
+    /// Request the given mouse cursor while the `Button` is hovered, e.g. `MouseCursor::Hand`.
+    ///
+    /// No cursor is requested while the `Button` is disabled.
+    pub fn hover_cursor(mut self, cursor: cursor::MouseCursor) -> Self {
+        self.maybe_hover_cursor = Some(cursor);
+        self
+    }
+
     /// Specify the font used for displaying the label.
     pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
         self.style.label_font_id = Some(Some(font_id));
         self
     }
 
+    /// Round the corners of the `Button`'s surface with the given radius.
+    pub fn corner_radius(mut self, radius: Scalar) -> Self {
+        self.style.corner_radius = Some(radius);
+        self
+    }
+
     /// Align the label to the left of the `Button`'s surface.
     pub fn left_justify_label(mut self) -> Self {
         self.style.label_justify = Some(text::Justify::Left);
@@ -261,6 +329,12 @@ impl<'a, S> Button<'a, S> {
         self
     }
 
+    /// Specify the direction in which the lines of the label run.
+    pub fn label_orientation(mut self, orientation: widget::text::Orientation) -> Self {
+        self.style.label_orientation = Some(orientation);
+        self
+    }
+
     /// Specify the label's position relatively to `Button` along the *x* axis.
     pub fn label_x(mut self, x: position::Relative) -> Self {
         self.style.label_x = Some(x);
@@ -279,18 +353,25 @@ impl<'a, S> Button<'a, S> {
 }
 
 impl<'a> Widget for Button<'a, Flat> {
-    type State = FlatIds;
+    type State = State<FlatIds>;
     type Style = Style;
     type Event = TimesClicked;
 
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
-        FlatIds::new(id_gen)
+        State {
+            ids: FlatIds::new(id_gen),
+            maybe_press: None,
+        }
     }
 
     fn style(&self) -> Style {
         self.style.clone()
     }
 
+    fn accepts_focus(&self) -> bool {
+        self.enabled
+    }
+
     /// Update the state of the Button.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
@@ -302,10 +383,29 @@ impl<'a> Widget for Button<'a, Flat> {
             ..
         } = args;
         let Button {
-            show, maybe_label, ..
+            show,
+            maybe_label,
+            maybe_repeat,
+            maybe_hover_cursor,
+            enabled,
+            ..
         } = self;
 
-        let (interaction, times_triggered) = interaction_and_times_triggered(id, ui);
+        let (interaction, mut times_triggered) = if enabled {
+            interaction_and_times_triggered(id, ui)
+        } else {
+            (Interaction::Idle, 0)
+        };
+        if let Some(repeat) = maybe_repeat {
+            times_triggered =
+                repeat_times_triggered(interaction, times_triggered, repeat, state, ui);
+        }
+        if times_triggered > 0 {
+            ui.log_interaction(id, "Button", event::InteractionKind::Click);
+        }
+        if let (true, Some(cursor)) = (enabled, maybe_hover_cursor) {
+            ui.request_mouse_cursor(id, cursor);
+        }
         let color = match interaction {
             Interaction::Idle => style.color(&ui.theme),
             Interaction::Hover => show
@@ -315,12 +415,17 @@ impl<'a> Widget for Button<'a, Flat> {
                 .press_color
                 .unwrap_or_else(|| style.color(&ui.theme).clicked()),
         };
+        let color = if enabled {
+            color
+        } else {
+            ui.theme.disabled(color)
+        };
 
-        bordered_rectangle(id, state.rectangle, rect, color, style, ui);
+        bordered_rectangle(id, state.ids.rectangle, rect, color, style, ui);
 
         // Label widget.
         if let Some(l) = maybe_label {
-            label(id, state.label, l, style, ui);
+            label(id, state.ids.label, l, enabled, style, ui);
         }
 
         TimesClicked(times_triggered)
@@ -328,18 +433,25 @@ impl<'a> Widget for Button<'a, Flat> {
 }
 
 impl<'a> Widget for Button<'a, Image> {
-    type State = ImageIds;
+    type State = State<ImageIds>;
     type Style = Style;
     type Event = TimesClicked;
 
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
-        ImageIds::new(id_gen)
+        State {
+            ids: ImageIds::new(id_gen),
+            maybe_press: None,
+        }
     }
 
     fn style(&self) -> Style {
         self.style.clone()
     }
 
+    fn accepts_focus(&self) -> bool {
+        self.enabled
+    }
+
     /// Update the state of the Button.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
@@ -351,10 +463,29 @@ impl<'a> Widget for Button<'a, Image> {
             ..
         } = args;
         let Button {
-            show, maybe_label, ..
+            show,
+            maybe_label,
+            maybe_repeat,
+            maybe_hover_cursor,
+            enabled,
+            ..
         } = self;
 
-        let (interaction, times_triggered) = interaction_and_times_triggered(id, ui);
+        let (interaction, mut times_triggered) = if enabled {
+            interaction_and_times_triggered(id, ui)
+        } else {
+            (Interaction::Idle, 0)
+        };
+        if let Some(repeat) = maybe_repeat {
+            times_triggered =
+                repeat_times_triggered(interaction, times_triggered, repeat, state, ui);
+        }
+        if times_triggered > 0 {
+            ui.log_interaction(id, "Button", event::InteractionKind::Click);
+        }
+        if let (true, Some(cursor)) = (enabled, maybe_hover_cursor) {
+            ui.request_mouse_cursor(id, cursor);
+        }
 
         // Instantiate the image.
         let Image {
@@ -380,6 +511,9 @@ impl<'a> Widget for Button<'a, Image> {
             .graphics_for(id);
         image.src_rect = src_rect;
         image.style.maybe_color = match color {
+            ImageColor::Normal(color) | ImageColor::WithFeedback(color) if !enabled => {
+                Some(Some(ui.theme.disabled(color)))
+            }
             ImageColor::Normal(color) => Some(Some(color)),
             ImageColor::WithFeedback(color) => ui
                 .widget_input(id)
@@ -394,10 +528,10 @@ impl<'a> Widget for Button<'a, Image> {
                 .or(Some(Some(color))),
             ImageColor::None => None,
         };
-        image.set(state.image, ui);
+        image.set(state.ids.image, ui);
 
         if let Some(s) = maybe_label {
-            label(id, state.label, s, style, ui);
+            label(id, state.ids.label, s, enabled, style, ui);
         }
 
         TimesClicked(times_triggered)
@@ -433,10 +567,63 @@ fn interaction_and_times_triggered(button_id: widget::Id, ui: &UiCell) -> (Inter
         }
         Interaction::Press => Interaction::Press,
     };
-    let times_triggered = (input.clicks().left().count() + input.taps().count()) as u16;
+    // A `Button` with keyboard focus is also triggered by pressing `Return` or `Space`.
+    let key_presses = input
+        .presses()
+        .key()
+        .filter(|press| press.key == Key::Return || press.key == Key::Space)
+        .count();
+    let times_triggered =
+        (input.clicks().left().count() + input.taps().count() + key_presses) as u16;
     (interaction, times_triggered)
 }
 
+// Determine the number of times that a repeating `Button` has triggered since the last update.
+fn repeat_times_triggered<I>(
+    interaction: Interaction,
+    times_clicked: u16,
+    repeat: Repeat,
+    state: &mut widget::State<State<I>>,
+    ui: &mut UiCell,
+) -> u16 {
+    match interaction {
+        Interaction::Press => {
+            let now = ui.now();
+            let (start, prev_count) = state.maybe_press.unwrap_or((now, 0));
+            let count = repeat_count(now.duration_since(start), repeat);
+            if state.maybe_press != Some((start, count)) {
+                state.update(|state| state.maybe_press = Some((start, count)));
+            }
+            // Keep updating while held so that we may continue to trigger without new input.
+            ui.request_continuous_updates();
+            count - prev_count
+        }
+        Interaction::Idle | Interaction::Hover => {
+            let prev_count = state.maybe_press.map_or(0, |(_, count)| count);
+            if state.maybe_press.is_some() {
+                state.update(|state| state.maybe_press = None);
+            }
+            // Only trigger upon release if the press never began repeating.
+            if prev_count > 0 {
+                0
+            } else {
+                times_clicked
+            }
+        }
+    }
+}
+
+// The total number of times a repeating `Button` should have triggered after being held for the
+// given duration.
+fn repeat_count(held: Duration, repeat: Repeat) -> u16 {
+    if held < repeat.initial_delay {
+        return 0;
+    }
+    let since_delay = (held - repeat.initial_delay).as_nanos();
+    let interval = std::cmp::max(repeat.interval.as_nanos(), 1);
+    std::cmp::min(1 + since_delay / interval, u16::MAX as u128) as u16
+}
+
 fn bordered_rectangle(
     button_id: widget::Id,
     rectangle_id: widget::Id,
@@ -449,24 +636,40 @@ fn bordered_rectangle(
     let dim = rect.dim();
     let border = style.border(&ui.theme);
     let border_color = style.border_color(&ui.theme);
+    let corner_radius = style.corner_radius(&ui.theme);
     widget::BorderedRectangle::new(dim)
         .middle_of(button_id)
         .graphics_for(button_id)
         .color(color)
         .border(border)
         .border_color(border_color)
+        .corner_radius(corner_radius)
         .set(rectangle_id, ui);
 }
 
-fn label(button_id: widget::Id, label_id: widget::Id, label: &str, style: &Style, ui: &mut UiCell) {
+fn label(
+    button_id: widget::Id,
+    label_id: widget::Id,
+    label: &str,
+    enabled: bool,
+    style: &Style,
+    ui: &mut UiCell,
+) {
     let color = style.label_color(&ui.theme);
+    let color = if enabled {
+        color
+    } else {
+        ui.theme.disabled(color)
+    };
     let font_size = style.label_font_size(&ui.theme);
     let x = style.label_x(&ui.theme);
     let y = style.label_y(&ui.theme);
     let justify = style.label_justify(&ui.theme);
+    let orientation = style.label_orientation(&ui.theme);
     let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
     widget::Text::new(label)
         .and_then(font_id, widget::Text::font_id)
+        .orientation(orientation)
         .x_position_relative_to(button_id, x)
         .y_position_relative_to(button_id, y)
         .justify(justify)
@@ -495,3 +698,23 @@ impl<'a, S> Labelable<'a> for Button<'a, S> {
         label_font_size { style.label_font_size = Some(FontSize) }
     }
 }
+
+#[test]
+fn test_repeat_count() {
+    let repeat = Repeat {
+        initial_delay: Duration::from_millis(500),
+        interval: Duration::from_millis(100),
+    };
+    let count = |ms| repeat_count(Duration::from_millis(ms), repeat);
+
+    // Nothing is triggered before the initial delay.
+    assert_eq!(count(0), 0);
+    assert_eq!(count(499), 0);
+
+    // Triggers once upon the initial delay, then once per interval.
+    assert_eq!(count(500), 1);
+    assert_eq!(count(599), 1);
+    assert_eq!(count(600), 2);
+    assert_eq!(count(1000), 6);
+    assert_eq!(count(1050), 6);
+}
@@ -1,9 +1,16 @@
 //! The `Canvas` widget and related items.
 
+use color;
+use cursor;
+use graph;
 use position::Direction::{Backwards, Forwards};
-use position::{self, Dimensions, Padding, Place, Position, Range, Rect, Scalar};
+use position::{self, Axis, Dimensions, Padding, Place, Point, Position, Range, Rect, Scalar};
+use std::cell::RefCell;
+use std::fmt;
 use text;
+use utils;
 use widget;
+use widget::scroll::Visibility;
 use {
     Borderable, Color, Colorable, FontSize, Labelable, Positionable, Sizeable, Theme, Ui, UiCell,
     Widget,
@@ -20,7 +27,16 @@ use {
 ///
 /// A **Canvas** can also be divided into a sequence of smaller **Canvas**ses using the `.flow_*`
 /// methods. This creates a kind of **Canvas** tree, where each "split" can be sized using the
-/// `.length` or `.length_weight` methods.
+/// `.length` or `.length_weight` methods. A split may be made `resizable`, in which case the edge
+/// that it shares with the following split may be dragged to reapportion their lengths.
+///
+/// A **Canvas** may also be made `collapsible`, in which case a caret is drawn within its title bar
+/// that may be clicked to collapse or expand it. While `collapsed`, only the title bar is drawn and
+/// the kid area has zero height.
+///
+/// A **Canvas** with a title bar may be moved by dragging its title bar, though it remains at
+/// least partially within the window. A `floating` **Canvas** is also brought to the front when
+/// clicked.
 ///
 /// See the `canvas.rs` example for a demonstration of the **Canvas** type.
 #[derive(Copy, Clone, Debug, WidgetCommon_)]
@@ -34,10 +50,38 @@ pub struct Canvas<'a> {
     pub maybe_title_bar_label: Option<&'a str>,
     /// A list of child **Canvas**ses as splits of this **Canvas** flowing in the given direction.
     pub maybe_splits: Option<FlowOfSplits<'a>>,
+    /// The grid drawn upon the kid area behind the children of the **Canvas** if there is one.
+    pub maybe_grid_background: Option<GridBackground>,
+    /// If this **Canvas** is a split, whether or not the edge shared with the following split may
+    /// be dragged to resize both.
+    pub resizable: bool,
+    /// Whether or not a caret for collapsing the **Canvas** is drawn within its title bar.
+    pub collapsible: bool,
+    /// Whether or not the **Canvas** is collapsed to its title bar.
+    pub collapsed: bool,
+    /// Called with whether or not the **Canvas** should now be collapsed when its caret is clicked.
+    pub maybe_on_collapse: Option<Callback<'a, bool>>,
+    /// If this **Canvas** is a resizable split, called with its new ratio when it is resized.
+    pub maybe_on_resize: Option<Callback<'a, Scalar>>,
+}
+
+/// A closure borrowed by a **Canvas** and called with some value that it reports.
+///
+/// The closure is borrowed via a `RefCell` so that the **Canvas** may remain `Copy`.
+pub struct Callback<'a, T: 'a>(&'a (dyn CallMut<T> + 'a));
+
+/// Allows for calling a `FnMut` closure behind a shared `RefCell` reference.
+trait CallMut<T> {
+    fn call_mut(&self, value: T);
 }
 
 /// **Canvas** state to be cached.
 pub struct State {
+    /// The portion of the combined length with the following split occupied by each split whose
+    /// shared edge has been dragged.
+    split_ratios: Vec<(widget::Id, Scalar)>,
+    /// Whether or not the **Canvas** was collapsed during the last update.
+    collapsed: bool,
     ids: Ids,
 }
 
@@ -45,6 +89,12 @@ widget_ids! {
     struct Ids {
         rectangle,
         title_bar,
+        grid,
+        x_scrollbar,
+        y_scrollbar,
+        resize_handles[],
+        collapse_toggle,
+        collapse_caret,
     }
 }
 
@@ -60,9 +110,21 @@ pub struct Style {
     /// The color of the Canvas' border.
     #[conrod(default = "theme.border_color")]
     pub border_color: Option<Color>,
+    /// The radius used to round the corners of the Canvas' rectangle.
+    #[conrod(default = "0.0")]
+    pub corner_radius: Option<Scalar>,
     /// If this Canvas is a split of some parent Canvas, this is the length of the split.
     #[conrod(default = "Length::Weight(1.0)")]
     pub length: Option<Length>,
+    /// If this Canvas is a split, the length beneath which it may not be laid out or dragged.
+    #[conrod(default = "0.0")]
+    pub min_length: Option<Scalar>,
+    /// If this Canvas is a split, the length beyond which it may not be laid out or dragged.
+    #[conrod(default = "None")]
+    pub max_length: Option<Option<Scalar>>,
+    /// If this Canvas is a resizable split, the thickness of the handle on its shared edge.
+    #[conrod(default = "6.0")]
+    pub resize_handle_thickness: Option<Scalar>,
 
     /// Padding for the left edge of the Canvas' kid area.
     #[conrod(default = "theme.padding.x.start")]
@@ -95,8 +157,43 @@ pub struct Style {
     /// The label's typographic alignment over the *x* axis.
     #[conrod(default = "text::Justify::Center")]
     pub title_bar_justify: Option<text::Justify>,
+
+    /// The visibility of the `Scrollbar` along the *x* axis if the Canvas scrolls horizontally.
+    #[conrod(default = "Visibility::Never")]
+    pub x_scrollbar_visibility: Option<Visibility>,
+    /// The visibility of the `Scrollbar` along the *y* axis if the Canvas scrolls vertically.
+    #[conrod(default = "Visibility::Never")]
+    pub y_scrollbar_visibility: Option<Visibility>,
 }
 
+/// A grid drawn upon the kid area of a **Canvas**, behind its children.
+///
+/// The grid is aligned with the coordinate space of the children, so that it moves along with
+/// them as the **Canvas** is scrolled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GridBackground {
+    /// Whether the grid is drawn as lines or as dots at their intersections.
+    pub kind: GridKind,
+    /// The distance between neighbouring lines of the grid.
+    pub spacing: Scalar,
+    /// The color of the lines or dots.
+    pub color: Color,
+}
+
+/// The way in which a **GridBackground** is drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GridKind {
+    /// Vertical and horizontal lines.
+    Lines,
+    /// A dot at each intersection of the vertical and horizontal lines.
+    Dots,
+}
+
+/// The thickness of the lines of a `GridKind::Lines` grid.
+const GRID_LINE_THICKNESS: Scalar = 1.0;
+/// The width and height of the dots of a `GridKind::Dots` grid.
+const GRID_DOT_SIZE: Scalar = 2.0;
+
 /// A series of **Canvas** splits along with their unique identifiers.
 pub type ListOfSplits<'a> = &'a [(widget::Id, Canvas<'a>)];
 
@@ -106,7 +203,8 @@ pub type FlowOfSplits<'a> = (Direction, ListOfSplits<'a>);
 /// The length of a `Split` given as a weight.
 ///
 /// The length is determined by determining what percentage each `Split`'s weight contributes to
-/// the total weight of all `Split`s in a flow list.
+/// the total weight of all `Split`s in a flow list, after the `Absolute` lengths have been
+/// allocated. A `Split` with a weight of `0.0` (or less) collapses to zero length.
 pub type Weight = Scalar;
 
 /// Used to describe the desired length for a `Split`.
@@ -127,6 +225,36 @@ pub enum Direction {
     Y(position::Direction),
 }
 
+impl<'a, T> Callback<'a, T> {
+    /// Call the borrowed closure with the given value.
+    fn call(&self, value: T) {
+        self.0.call_mut(value)
+    }
+}
+
+impl<T, F> CallMut<T> for RefCell<F>
+where
+    F: FnMut(T),
+{
+    fn call_mut(&self, value: T) {
+        (*self.borrow_mut())(value)
+    }
+}
+
+impl<'a, T> Copy for Callback<'a, T> {}
+
+impl<'a, T> Clone for Callback<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> fmt::Debug for Callback<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Callback")
+    }
+}
+
 impl<'a> Canvas<'a> {
     /// Construct a new Canvas builder.
     pub fn new() -> Self {
@@ -135,6 +263,12 @@ impl<'a> Canvas<'a> {
             style: Style::default(),
             maybe_title_bar_label: None,
             maybe_splits: None,
+            maybe_grid_background: None,
+            resizable: false,
+            collapsible: false,
+            collapsed: false,
+            maybe_on_collapse: None,
+            maybe_on_resize: None,
         }
     }
 
@@ -144,7 +278,44 @@ impl<'a> Canvas<'a> {
         pub pad_right { style.pad_right = Some(Scalar) }
         pub pad_bottom { style.pad_bottom = Some(Scalar) }
         pub pad_top { style.pad_top = Some(Scalar) }
+        pub corner_radius { style.corner_radius = Some(Scalar) }
         pub with_style { style = Style }
+        pub resizable { resizable = bool }
+        pub collapsible { collapsible = bool }
+        pub min_length { style.min_length = Some(Scalar) }
+        pub resize_handle_thickness { style.resize_handle_thickness = Some(Scalar) }
+    }
+
+    /// Specify whether or not the **Canvas** is collapsed to its title bar.
+    ///
+    /// The kids of a collapsed **Canvas** are cropped to its zero-height kid area, so that any set
+    /// with the **Canvas** as their parent are neither drawn nor interacted with.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        if collapsed {
+            self.common.crop_kids = true;
+        }
+        self
+    }
+
+    /// Call the given closure with whether or not the **Canvas** should now be collapsed whenever
+    /// its collapse caret is clicked.
+    pub fn on_collapse<F>(mut self, on_collapse: &'a RefCell<F>) -> Self
+    where
+        F: FnMut(bool) + 'a,
+    {
+        self.maybe_on_collapse = Some(Callback(on_collapse));
+        self
+    }
+
+    /// Call the given closure whenever this resizable split is resized, with the portion of its
+    /// combined length with the following split that it now occupies.
+    pub fn on_resize<F>(mut self, on_resize: &'a RefCell<F>) -> Self
+    where
+        F: FnMut(Scalar) + 'a,
+    {
+        self.maybe_on_resize = Some(Callback(on_resize));
+        self
     }
 
     /// Set the length of the Split as an absolute scalar.
@@ -161,6 +332,15 @@ impl<'a> Canvas<'a> {
         self
     }
 
+    /// Set the maximum length of the Split.
+    ///
+    /// If the Split would otherwise exceed this length, the surplus is distributed among its
+    /// weighted siblings.
+    pub fn max_length(mut self, max_length: Scalar) -> Self {
+        self.style.max_length = Some(Some(max_length));
+        self
+    }
+
     /// Set the child Canvas Splits of the current Canvas flowing in a given direction.
     fn flow(mut self, direction: Direction, splits: ListOfSplits<'a>) -> Self {
         self.maybe_splits = Some((direction, splits));
@@ -205,6 +385,38 @@ impl<'a> Canvas<'a> {
             .pad_top(pad.y.end)
     }
 
+    /// Draw a grid of lines with the given spacing behind the children of the `Canvas`.
+    pub fn grid_background(mut self, spacing: Scalar, color: Color) -> Self {
+        self.maybe_grid_background = Some(GridBackground {
+            kind: GridKind::Lines,
+            spacing: spacing,
+            color: color,
+        });
+        self
+    }
+
+    /// Draw a grid of dots with the given spacing behind the children of the `Canvas`.
+    pub fn dot_grid_background(mut self, spacing: Scalar, color: Color) -> Self {
+        self.maybe_grid_background = Some(GridBackground {
+            kind: GridKind::Dots,
+            spacing: spacing,
+            color: color,
+        });
+        self
+    }
+
+    /// Set the visibility of the `Scrollbar` along the given axis.
+    ///
+    /// The `Scrollbar` is only ever shown along axes that have been made scrollable (e.g. via
+    /// `scroll_kids_vertically`). By default, no `Scrollbar` is shown along either axis.
+    pub fn scroll_bar_visibility(mut self, axis: Axis, visibility: Visibility) -> Self {
+        match axis {
+            Axis::X => self.style.x_scrollbar_visibility = Some(visibility),
+            Axis::Y => self.style.y_scrollbar_visibility = Some(visibility),
+        }
+        self
+    }
+
     /// Set the color of the `Canvas`' `TitleBar` if it is visible.
     pub fn title_bar_color(mut self, color: Color) -> Self {
         self.style.title_bar_color = Some(Some(color));
@@ -219,6 +431,8 @@ impl<'a> Widget for Canvas<'a> {
 
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
         State {
+            split_ratios: Vec::new(),
+            collapsed: false,
             ids: Ids::new(id_gen),
         }
     }
@@ -241,7 +455,7 @@ impl<'a> Widget for Canvas<'a> {
     fn drag_area(&self, dim: Dimensions, style: &Style, theme: &Theme) -> Option<Rect> {
         self.maybe_title_bar_label.map(|_| {
             let font_size = style.title_bar_font_size(theme);
-            let (h, rel_y) = title_bar_h_rel_y(dim[1], font_size);
+            let (h, rel_y) = title_bar_h_rel_y(dim[1], font_size, theme.spacing_xs);
             let rel_xy = [0.0, rel_y];
             let dim = [dim[0], h];
             Rect::from_xy_dim(rel_xy, dim)
@@ -249,13 +463,29 @@ impl<'a> Widget for Canvas<'a> {
     }
 
     /// The area of the widget below the title bar, upon which child widgets will be placed.
+    ///
+    /// While collapsed, this is a zero-height area along the bottom of the title bar.
     fn kid_area(&self, args: widget::KidAreaArgs<Self>) -> widget::KidArea {
         let widget::KidAreaArgs {
             rect, style, theme, ..
         } = args;
-        if self.maybe_title_bar_label.is_some() {
+        if self.collapsed {
+            let font_size = style.title_bar_font_size(theme);
+            let title_bar_h = match self.maybe_title_bar_label {
+                Some(_) => title_bar(rect, font_size, theme.spacing_xs).h(),
+                None => 0.0,
+            };
+            let top = rect.top() - title_bar_h.min(rect.h());
+            widget::KidArea {
+                rect: Rect {
+                    x: rect.x,
+                    y: Range::new(top, top),
+                },
+                pad: Padding::none(),
+            }
+        } else if self.maybe_title_bar_label.is_some() {
             let font_size = style.title_bar_font_size(theme);
-            let title_bar = title_bar(rect, font_size);
+            let title_bar = title_bar(rect, font_size, theme.spacing_xs);
             widget::KidArea {
                 rect: rect.pad_top(title_bar.h()),
                 pad: style.padding(theme),
@@ -268,6 +498,11 @@ impl<'a> Widget for Canvas<'a> {
         }
     }
 
+    /// While collapsed, only the title bar is considered to be over the Canvas.
+    fn is_over(&self) -> widget::IsOverFn {
+        is_over_widget
+    }
+
     /// Update the state of the Canvas.
     fn update(self, args: widget::UpdateArgs<Self>) {
         let widget::UpdateArgs {
@@ -281,19 +516,41 @@ impl<'a> Widget for Canvas<'a> {
             style,
             maybe_title_bar_label,
             maybe_splits,
+            maybe_grid_background,
+            collapsible,
+            collapsed,
+            maybe_on_collapse,
             ..
         } = self;
 
-        // BorderedRectangle widget as the rectangle backdrop.
-        let dim = rect.dim();
+        if state.collapsed != collapsed {
+            state.update(|state| state.collapsed = collapsed);
+        }
+
+        // BorderedRectangle widget as the rectangle backdrop, covering only the title bar while
+        // collapsed.
+        let title_bar_h = match maybe_title_bar_label {
+            Some(_) => {
+                let font_size = style.title_bar_font_size(&ui.theme);
+                title_bar(rect, font_size, ui.theme.spacing_xs).h()
+            }
+            None => 0.0,
+        };
+        let dim = if collapsed {
+            [rect.w(), title_bar_h.min(rect.h())]
+        } else {
+            rect.dim()
+        };
         let color = style.color(ui.theme());
         let border = style.border(ui.theme());
         let border_color = style.border_color(ui.theme());
+        let corner_radius = style.corner_radius(ui.theme());
         widget::BorderedRectangle::new(dim)
             .color(color)
             .border(border)
             .border_color(border_color)
-            .middle_of(id)
+            .corner_radius(corner_radius)
+            .mid_top_of(id)
             .graphics_for(id)
             .place_on_kid_area(false)
             .set(state.ids.rectangle, &mut ui);
@@ -322,6 +579,111 @@ impl<'a> Widget for Canvas<'a> {
                 .set(state.ids.title_bar, &mut ui);
         }
 
+        // The caret at the left of the title bar that toggles whether the Canvas is collapsed.
+        //
+        // The toggle is a child of the title bar so that it is not cropped to the kid area should
+        // the Canvas be scrollable.
+        if collapsible && maybe_title_bar_label.is_some() {
+            let toggle_rect = Rect {
+                x: Range::new(rect.left(), rect.left() + title_bar_h),
+                y: Range::new(rect.top() - title_bar_h, rect.top()),
+            };
+            widget::Rectangle::fill_with(toggle_rect.dim(), color::TRANSPARENT)
+                .xy(toggle_rect.xy())
+                .parent(state.ids.title_bar)
+                .set(state.ids.collapse_toggle, &mut ui);
+            let num_clicks = ui
+                .widget_input(state.ids.collapse_toggle)
+                .clicks()
+                .left()
+                .count();
+            if let (1, Some(on_collapse)) = (num_clicks % 2, maybe_on_collapse) {
+                on_collapse.call(!collapsed);
+            }
+
+            // The caret points right while collapsed and down while expanded.
+            let (x, y) = (toggle_rect.x(), toggle_rect.y());
+            let side_offset = toggle_rect.w() / 10.0;
+            let point_offset = toggle_rect.h() / 6.0;
+            let points = if collapsed {
+                [
+                    [x + point_offset, y],
+                    [x - point_offset, y + side_offset],
+                    [x - point_offset, y - side_offset],
+                ]
+            } else {
+                [
+                    [x, y - point_offset],
+                    [x + side_offset, y + point_offset],
+                    [x - side_offset, y + point_offset],
+                ]
+            };
+            widget::Polygon::fill(points.iter().cloned())
+                .xy(toggle_rect.xy())
+                .wh(toggle_rect.dim())
+                .parent(state.ids.collapse_toggle)
+                .graphics_for(state.ids.collapse_toggle)
+                .color(style.title_bar_text_color(&ui.theme))
+                .set(state.ids.collapse_caret, &mut ui);
+        }
+
+        // Nothing beneath the title bar is instantiated while collapsed.
+        if collapsed {
+            return;
+        }
+
+        // The grid behind the children, if we were given one.
+        if let Some(grid) = maybe_grid_background {
+            let kid_area = ui.kid_area_of(id).expect("No KidArea found");
+            let scroll_offset = {
+                let container = ui.widget_graph().widget(id).expect("No Container found");
+                [
+                    container
+                        .maybe_x_scroll_state
+                        .map_or(0.0, |scroll| scroll.offset),
+                    container
+                        .maybe_y_scroll_state
+                        .map_or(0.0, |scroll| scroll.offset),
+                ]
+            };
+            let origin = [rect.x() + scroll_offset[0], rect.y() + scroll_offset[1]];
+            let (xs, ys) = grid_lines(kid_area, origin, grid.spacing);
+            let mut triangles = Vec::new();
+            let mut push_rect = |rect: Rect| {
+                let (a, b) = widget::rectangle::triangles(rect);
+                triangles.push(a);
+                triangles.push(b);
+            };
+            match grid.kind {
+                GridKind::Lines => {
+                    for &x in &xs {
+                        push_rect(Rect {
+                            x: Range::from_pos_and_len(x, GRID_LINE_THICKNESS),
+                            y: kid_area.y,
+                        });
+                    }
+                    for &y in &ys {
+                        push_rect(Rect {
+                            x: kid_area.x,
+                            y: Range::from_pos_and_len(y, GRID_LINE_THICKNESS),
+                        });
+                    }
+                }
+                GridKind::Dots => {
+                    for &x in &xs {
+                        for &y in &ys {
+                            push_rect(Rect::from_xy_dim([x, y], [GRID_DOT_SIZE, GRID_DOT_SIZE]));
+                        }
+                    }
+                }
+            }
+            widget::Triangles::single_color(grid.color, triangles)
+                .with_bounding_rect(kid_area)
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.grid, &mut ui);
+        }
+
         // If we were given some child canvas splits, we should instantiate them.
         if let Some((direction, splits)) = maybe_splits {
             let (total_abs, total_weight) =
@@ -330,7 +692,7 @@ impl<'a> Widget for Canvas<'a> {
                     .fold((0.0, 0.0), |(abs, weight), &(_, split)| {
                         match split.style.length(ui.theme()) {
                             Length::Absolute(a) => (abs + a, weight),
-                            Length::Weight(w) => (abs, weight + w),
+                            Length::Weight(w) => (abs, weight + w.max(0.0)),
                         }
                     });
 
@@ -343,15 +705,48 @@ impl<'a> Widget for Canvas<'a> {
 
             let total_length = kid_area_range.len();
             let non_abs_length = (total_length - total_abs).max(0.0);
-            let weight_normaliser = 1.0 / total_weight;
+            // If no split has a positive weight, the weighted splits collapse to zero length.
+            let weight_normaliser = if total_weight > 0.0 {
+                1.0 / total_weight
+            } else {
+                0.0
+            };
 
             let length = |split: &Self, ui: &UiCell| -> Scalar {
                 match split.style.length(ui.theme()) {
                     Length::Absolute(length) => length,
-                    Length::Weight(weight) => weight * weight_normaliser * non_abs_length,
+                    Length::Weight(weight) => weight.max(0.0) * weight_normaliser * non_abs_length,
                 }
             };
 
+            // Constrain the lengths of the splits to their minimum and maximum lengths, then
+            // reapportion the lengths of any pairs of splits whose shared edge has been dragged.
+            let mut lengths: Vec<Scalar> = splits
+                .iter()
+                .map(|&(_, split)| length(&split, &ui))
+                .collect();
+            let bounds: Vec<Range> = splits
+                .iter()
+                .map(|&(_, split)| split.style.length_bounds(&ui.theme))
+                .collect();
+            let is_weighted: Vec<bool> = splits
+                .iter()
+                .map(|&(_, split)| match split.style.length(&ui.theme) {
+                    Length::Absolute(_) => false,
+                    Length::Weight(_) => true,
+                })
+                .collect();
+            constrain_lengths(&mut lengths, &bounds, &is_weighted, total_length);
+            let num_handles = splits.len().saturating_sub(1);
+            for (i, &(split_id, _)) in splits.iter().take(num_handles).enumerate() {
+                let ratio = state.split_ratios.iter().find(|&&(id, _)| id == split_id);
+                if let Some(&(_, ratio)) = ratio {
+                    let combined = lengths[i] + lengths[i + 1];
+                    lengths[i] = combined * ratio;
+                    lengths[i + 1] = combined - lengths[i];
+                }
+            }
+
             let set_split = |split_id: widget::Id, split: Canvas<'a>, ui: &mut UiCell| {
                 split.parent(id).set(split_id, ui);
             };
@@ -361,7 +756,7 @@ impl<'a> Widget for Canvas<'a> {
                 Direction::X(direction) => match direction {
                     Forwards => {
                         for (i, &(split_id, split)) in splits.iter().enumerate() {
-                            let w = length(&split, &ui);
+                            let w = lengths[i];
                             let split = match i {
                                 0 => split.h(kid_area.h()).mid_left_of(id),
                                 _ => split.right(0.0),
@@ -372,7 +767,7 @@ impl<'a> Widget for Canvas<'a> {
                     }
                     Backwards => {
                         for (i, &(split_id, split)) in splits.iter().enumerate() {
-                            let w = length(&split, &ui);
+                            let w = lengths[i];
                             let split = match i {
                                 0 => split.h(kid_area.h()).mid_right_of(id),
                                 _ => split.left(0.0),
@@ -386,7 +781,7 @@ impl<'a> Widget for Canvas<'a> {
                 Direction::Y(direction) => match direction {
                     Forwards => {
                         for (i, &(split_id, split)) in splits.iter().enumerate() {
-                            let h = length(&split, &ui);
+                            let h = lengths[i];
                             let split = match i {
                                 0 => split.w(kid_area.w()).mid_bottom_of(id),
                                 _ => split.up(0.0),
@@ -397,7 +792,7 @@ impl<'a> Widget for Canvas<'a> {
                     }
                     Backwards => {
                         for (i, &(split_id, split)) in splits.iter().enumerate() {
-                            let h = length(&split, &ui);
+                            let h = lengths[i];
                             let split = match i {
                                 0 => split.w(kid_area.w()).mid_top_of(id),
                                 _ => split.down(0.0),
@@ -408,27 +803,238 @@ impl<'a> Widget for Canvas<'a> {
                     }
                 },
             }
+
+            // A handle upon the edge that each resizable split shares with the following split.
+            if state.ids.resize_handles.len() < num_handles {
+                let id_gen = &mut ui.widget_id_generator();
+                state.update(|state| state.ids.resize_handles.resize(num_handles, id_gen));
+            }
+            let mut split_ratios = state.split_ratios.clone();
+            split_ratios.retain(|&(ratio_id, _)| {
+                splits[..num_handles]
+                    .iter()
+                    .any(|&(split_id, _)| split_id == ratio_id)
+            });
+            for (i, &(split_id, split)) in splits.iter().take(num_handles).enumerate() {
+                if !split.resizable {
+                    continue;
+                }
+
+                // Dragging the handle towards the following split grows the preceding one.
+                let split_rect = ui.rect_of(split_id).expect("No Rect found for split");
+                let thickness = split.style.resize_handle_thickness(&ui.theme);
+                let (handle_rect, axis, sign) = match direction {
+                    Direction::X(direction) => {
+                        let (x, sign) = match direction {
+                            Forwards => (split_rect.right(), 1.0),
+                            Backwards => (split_rect.left(), -1.0),
+                        };
+                        let x = Range::from_pos_and_len(x, thickness);
+                        (
+                            Rect {
+                                x: x,
+                                y: split_rect.y,
+                            },
+                            Axis::X,
+                            sign,
+                        )
+                    }
+                    Direction::Y(direction) => {
+                        let (y, sign) = match direction {
+                            Forwards => (split_rect.top(), 1.0),
+                            Backwards => (split_rect.bottom(), -1.0),
+                        };
+                        let y = Range::from_pos_and_len(y, thickness);
+                        (
+                            Rect {
+                                x: split_rect.x,
+                                y: y,
+                            },
+                            Axis::Y,
+                            sign,
+                        )
+                    }
+                };
+
+                let handle_id = state.ids.resize_handles[i];
+                let combined = lengths[i] + lengths[i + 1];
+                let mut new_length = lengths[i];
+                for drag in ui.widget_input(handle_id).drags().left() {
+                    let delta = match axis {
+                        Axis::X => drag.delta_xy[0],
+                        Axis::Y => drag.delta_xy[1],
+                    };
+                    new_length += delta * sign;
+                }
+                let (a, b) = (bounds[i], bounds[i + 1]);
+                let min = a.start.max(combined - b.end);
+                let max = a.end.min(combined - b.start);
+                let new_length = utils::clamp(new_length, min, max.max(min)).min(combined);
+                if new_length != lengths[i] && combined > 0.0 {
+                    let ratio = new_length / combined;
+                    split_ratios.retain(|&(ratio_id, _)| ratio_id != split_id);
+                    split_ratios.push((split_id, ratio));
+                    if let Some(on_resize) = split.maybe_on_resize {
+                        on_resize.call(ratio);
+                    }
+                }
+
+                let color = split.style.border_color(&ui.theme);
+                widget::Rectangle::fill_with(handle_rect.dim(), color)
+                    .xy(handle_rect.xy())
+                    .parent(id)
+                    .set(handle_id, &mut ui);
+                let cursor = match axis {
+                    Axis::X => cursor::MouseCursor::ResizeHorizontal,
+                    Axis::Y => cursor::MouseCursor::ResizeVertical,
+                };
+                ui.request_mouse_cursor(handle_id, cursor);
+            }
+            if state.split_ratios != split_ratios {
+                state.update(|state| state.split_ratios = split_ratios);
+            }
+        }
+
+        // The scrollbars for each scrollable axis that should be visible.
+        //
+        // These are instantiated as siblings of the Canvas so that they are neither scrolled along
+        // with the Canvas' children nor considered part of the scrollable range.
+        let (show_x_scrollbar, show_y_scrollbar) = {
+            let container = ui.widget_graph().widget(id).expect("No Container found");
+            let is_visible = |visibility, offset_bounds: Option<Range>| match offset_bounds {
+                None => false,
+                Some(bounds) => match visibility {
+                    Visibility::Always => true,
+                    Visibility::Auto => bounds.magnitude() != 0.0,
+                    Visibility::Never => false,
+                },
+            };
+            let x_bounds = container.maybe_x_scroll_state.map(|s| s.offset_bounds);
+            let y_bounds = container.maybe_y_scroll_state.map(|s| s.offset_bounds);
+            (
+                is_visible(style.x_scrollbar_visibility(&ui.theme), x_bounds),
+                is_visible(style.y_scrollbar_visibility(&ui.theme), y_bounds),
+            )
+        };
+        let maybe_parent_id = ui.widget_graph().depth_parent(id);
+        if show_x_scrollbar {
+            widget::Scrollbar::x_axis(id)
+                .and_then(maybe_parent_id, |s, parent| s.parent(parent))
+                .set(state.ids.x_scrollbar, &mut ui);
+        }
+        if show_y_scrollbar {
+            widget::Scrollbar::y_axis(id)
+                .and_then(maybe_parent_id, |s, parent| s.parent(parent))
+                .set(state.ids.y_scrollbar, &mut ui);
+        }
+    }
+}
+
+/// The function to use for picking whether a given point is over the Canvas.
+///
+/// While collapsed, only the title bar is considered to be over the Canvas.
+pub fn is_over_widget(widget: &graph::Container, point: Point, _: &Theme) -> widget::IsOver {
+    widget
+        .unique_widget_state::<Canvas>()
+        .and_then(|canvas| match canvas.state.collapsed {
+            true => Some(canvas.state.ids.rectangle.into()),
+            false => None,
+        })
+        .unwrap_or_else(|| widget.hit_rect().is_over(point).into())
+}
+
+/// Clamp each of the split `lengths` to its `bounds`, distributing the resulting surplus or deficit
+/// among the weighted splits that remain within their bounds in proportion to their lengths.
+///
+/// Absolute splits are never resized to make room for their siblings. If the constraints cannot
+/// all be satisfied within the `total` length, the splits will overflow or underflow it.
+fn constrain_lengths(
+    lengths: &mut [Scalar],
+    bounds: &[Range],
+    is_weighted: &[bool],
+    total: Scalar,
+) {
+    let mut is_fixed: Vec<bool> = is_weighted.iter().map(|&weighted| !weighted).collect();
+    loop {
+        // Fix any splits that violate their bounds at their nearest bound.
+        let mut violated = false;
+        for i in 0..lengths.len() {
+            let clamped = utils::clamp(lengths[i], bounds[i].start, bounds[i].end);
+            if clamped != lengths[i] {
+                lengths[i] = clamped;
+                is_fixed[i] = true;
+                violated = true;
+            }
+        }
+        if !violated {
+            return;
+        }
+
+        // Distribute the remaining length among the splits that are still free.
+        let free = (0..lengths.len()).filter(|&i| !is_fixed[i]);
+        let fixed_length: Scalar = (0..lengths.len())
+            .filter(|&i| is_fixed[i])
+            .map(|i| lengths[i])
+            .sum();
+        let free_length: Scalar = free.clone().map(|i| lengths[i]).sum();
+        let num_free = free.clone().count();
+        if num_free == 0 {
+            return;
+        }
+        let remaining = (total - fixed_length).max(0.0);
+        for i in free {
+            lengths[i] = if free_length > 0.0 {
+                remaining * lengths[i] / free_length
+            } else {
+                remaining / num_free as Scalar
+            };
         }
     }
 }
 
 /// The height and relative y coordinate of a Canvas' title bar given some canvas height and font
-/// size for the title bar.
-fn title_bar_h_rel_y(canvas_h: Scalar, font_size: FontSize) -> (Scalar, Scalar) {
-    let h = widget::title_bar::calc_height(font_size);
+/// size and label padding for the title bar.
+fn title_bar_h_rel_y(
+    canvas_h: Scalar,
+    font_size: FontSize,
+    label_padding: Scalar,
+) -> (Scalar, Scalar) {
+    let h = widget::title_bar::calc_height_with_label_padding(font_size, label_padding);
     let rel_y = canvas_h / 2.0 - h / 2.0;
     (h, rel_y)
 }
 
 /// The Rect for the Canvas' title bar.
-fn title_bar(canvas: Rect, font_size: FontSize) -> Rect {
+fn title_bar(canvas: Rect, font_size: FontSize, label_padding: Scalar) -> Rect {
     let (c_w, c_h) = canvas.w_h();
-    let (h, rel_y) = title_bar_h_rel_y(c_h, font_size);
+    let (h, rel_y) = title_bar_h_rel_y(c_h, font_size, label_padding);
     let xy = [0.0, rel_y];
     let dim = [c_w, h];
     Rect::from_xy_dim(xy, dim)
 }
 
+/// The positions of the vertical and horizontal lines of a grid with the given `spacing` that lie
+/// within the given `area`, returned as the *x* and *y* positions respectively.
+///
+/// The grid is aligned so that a line along each axis passes through the given `origin`.
+pub fn grid_lines(area: Rect, origin: Point, spacing: Scalar) -> (Vec<Scalar>, Vec<Scalar>) {
+    fn lines(range: Range, origin: Scalar, spacing: Scalar) -> Vec<Scalar> {
+        if !spacing.is_finite() || spacing <= 0.0 {
+            return Vec::new();
+        }
+        let range = range.undirected();
+        let first = ((range.start - origin) / spacing).ceil() as i64;
+        let last = ((range.end - origin) / spacing).floor() as i64;
+        (first..last + 1)
+            .map(|i| origin + i as Scalar * spacing)
+            .collect()
+    }
+    (
+        lines(area.x, origin[0], spacing),
+        lines(area.y, origin[1], spacing),
+    )
+}
+
 impl Style {
     /// Get the Padding for the Canvas' kid area.
     pub fn padding(&self, theme: &Theme) -> position::Padding {
@@ -437,6 +1043,13 @@ impl Style {
             y: Range::new(self.pad_bottom(theme), self.pad_top(theme)),
         }
     }
+
+    /// The range within which this Canvas' length must lie when laid out as a split.
+    pub fn length_bounds(&self, theme: &Theme) -> Range {
+        let min = self.min_length(theme);
+        let max = self.max_length(theme).unwrap_or(::std::f64::INFINITY);
+        Range::new(min, max.max(min))
+    }
 }
 
 impl<'a> ::color::Colorable for Canvas<'a> {
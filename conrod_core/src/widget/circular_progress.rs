@@ -0,0 +1,173 @@
+//! A read-only widget for displaying progress as a section of a circle.
+
+use position::{Point, Rect, Scalar};
+use std::f64::consts::PI;
+use utils::clamp;
+use widget;
+use widget::oval::{Circumference, DEFAULT_RESOLUTION};
+use widget::triangles::Triangle;
+use {Color, Colorable, Positionable, Widget};
+
+/// Displays a `fraction` of progress as a section of a circle, either as a ring or as a pie.
+///
+/// The section begins at the `start_angle` and proceeds anti-clockwise, in the same manner as the
+/// `Oval`'s `Section`.
+#[derive(WidgetCommon_)]
+pub struct CircularProgress {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    /// The fraction of the circle that is drawn, clamped to `0.0..=1.0`.
+    pub fraction: Scalar,
+    /// Whether the progress is drawn as a ring or as a pie.
+    pub kind: Kind,
+    /// The angle in radians at which the section begins.
+    ///
+    /// A value of `0.0` begins at the rightmost point of the circle. By default, the section
+    /// begins at the top.
+    pub start_angle: Scalar,
+    style: Style,
+}
+
+/// The way in which a `CircularProgress` is drawn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// An arc of the given `thickness` following the edge of the circle.
+    Ring,
+    /// A filled section of the circle joined to its center.
+    Pie,
+}
+
+/// Graphical styling unique to the CircularProgress widget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the progress section.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The thickness of the arc when drawn as a `Ring`.
+    #[conrod(default = "8.0")]
+    pub thickness: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        ring,
+        pie,
+    }
+}
+
+/// Represents the state of the CircularProgress widget.
+pub struct State {
+    ids: Ids,
+}
+
+impl CircularProgress {
+    /// Construct a new CircularProgress displaying the given `fraction` as a ring.
+    pub fn new(fraction: Scalar) -> Self {
+        CircularProgress {
+            common: widget::CommonBuilder::default(),
+            fraction: fraction,
+            kind: Kind::Ring,
+            start_angle: PI / 2.0,
+            style: Style::default(),
+        }
+    }
+
+    builder_methods! {
+        pub fraction { fraction = Scalar }
+        pub kind { kind = Kind }
+        pub start_angle { start_angle = Scalar }
+        pub thickness { style.thickness = Some(Scalar) }
+    }
+}
+
+impl Widget for CircularProgress {
+    type State = State;
+    type Style = Style;
+    type Event = ();
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the CircularProgress.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+
+        // Nothing is drawn until there is some progress.
+        let fraction = clamp(self.fraction, 0.0, 1.0);
+        if fraction <= 0.0 {
+            return;
+        }
+
+        let radians = fraction * 2.0 * PI;
+        let resolution =
+            std::cmp::max((fraction * DEFAULT_RESOLUTION as Scalar).ceil() as usize, 1);
+        let color = style.color(ui.theme());
+        match self.kind {
+            Kind::Ring => {
+                let thickness = clamp(
+                    style.thickness(ui.theme()),
+                    0.0,
+                    rect.w().min(rect.h()) / 2.0,
+                );
+                let triangles =
+                    ring_triangles(rect, thickness, resolution, radians, self.start_angle);
+                widget::Triangles::single_color(color, triangles)
+                    .with_bounding_rect(rect)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.ring, ui);
+            }
+            Kind::Pie => {
+                widget::Oval::fill(rect.dim())
+                    .resolution(resolution)
+                    .section(radians)
+                    .offset_radians(self.start_angle)
+                    .middle_of(id)
+                    .graphics_for(id)
+                    .color(color)
+                    .set(state.ids.pie, ui);
+            }
+        }
+    }
+}
+
+/// The triangles describing an arc of the given `thickness` along the inside edge of `rect`.
+fn ring_triangles(
+    rect: Rect,
+    thickness: Scalar,
+    resolution: usize,
+    radians: Scalar,
+    offset_radians: Scalar,
+) -> Vec<Triangle<Point>> {
+    let arc = |rect| {
+        Circumference::new_section(rect, resolution, radians)
+            .offset_radians(offset_radians)
+            .collect::<Vec<_>>()
+    };
+    let outer = arc(rect);
+    let inner = arc(rect.pad(thickness));
+    let mut triangles = Vec::with_capacity(resolution * 2);
+    for (o, i) in outer.windows(2).zip(inner.windows(2)) {
+        triangles.push(Triangle([o[0], o[1], i[1]]));
+        triangles.push(Triangle([o[0], i[1], i[0]]));
+    }
+    triangles
+}
+
+impl Colorable for CircularProgress {
+    builder_method!(color { style.color = Some(Color) });
+}
@@ -0,0 +1,111 @@
+//! A widget that crops its children to its area and does nothing else.
+
+use position::{self, Padding, Range, Scalar};
+use widget;
+use {Theme, Widget};
+
+/// Crops all child widgets to its area, which may be narrowed via padding.
+///
+/// Unlike a `Canvas`, a `Clip` draws nothing of its own and does not scroll. This makes it useful
+/// for masking content such as an `Image` or custom `Triangles`. Children that are cropped out of
+/// view may not be picked by the mouse or touch.
+///
+/// Nested `Clip`s crop their children to the intersection of their areas.
+#[derive(Copy, Clone, Debug, WidgetCommon_)]
+pub struct Clip {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    style: Style,
+}
+
+/// Unique styling for the `Clip`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// Padding for the left edge of the area to which children are cropped.
+    #[conrod(default = "0.0")]
+    pub pad_left: Option<Scalar>,
+    /// Padding for the right edge of the area to which children are cropped.
+    #[conrod(default = "0.0")]
+    pub pad_right: Option<Scalar>,
+    /// Padding for the bottom edge of the area to which children are cropped.
+    #[conrod(default = "0.0")]
+    pub pad_bottom: Option<Scalar>,
+    /// Padding for the top edge of the area to which children are cropped.
+    #[conrod(default = "0.0")]
+    pub pad_top: Option<Scalar>,
+}
+
+/// The state of the `Clip`, which has nothing to cache.
+#[derive(Copy, Clone, Debug)]
+pub struct State;
+
+impl Clip {
+    /// Begin building a `Clip`.
+    pub fn new() -> Self {
+        Clip {
+            common: widget::CommonBuilder {
+                crop_kids: true,
+                ..widget::CommonBuilder::default()
+            },
+            style: Style::default(),
+        }
+    }
+
+    builder_methods! {
+        pub pad_left { style.pad_left = Some(Scalar) }
+        pub pad_right { style.pad_right = Some(Scalar) }
+        pub pad_bottom { style.pad_bottom = Some(Scalar) }
+        pub pad_top { style.pad_top = Some(Scalar) }
+    }
+
+    /// Set the padding of the area to which children are cropped on all sides.
+    pub fn pad(self, pad: Scalar) -> Self {
+        self.pad_left(pad)
+            .pad_right(pad)
+            .pad_bottom(pad)
+            .pad_top(pad)
+    }
+
+    /// Set the padding of the area to which children are cropped.
+    pub fn padding(self, pad: Padding) -> Self {
+        self.pad_left(pad.x.start)
+            .pad_right(pad.x.end)
+            .pad_bottom(pad.y.start)
+            .pad_top(pad.y.end)
+    }
+}
+
+impl Widget for Clip {
+    type State = State;
+    type Style = Style;
+    type Event = ();
+
+    fn init_state(&self, _: widget::id::Generator) -> Self::State {
+        State
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// The area to which children are cropped, i.e. the `Clip`'s rect narrowed by its padding.
+    fn kid_area(&self, args: widget::KidAreaArgs<Self>) -> widget::KidArea {
+        widget::KidArea {
+            rect: args.rect.padding(args.style.padding(args.theme)),
+            pad: Padding::none(),
+        }
+    }
+
+    /// Update the state of the Clip.
+    fn update(self, _: widget::UpdateArgs<Self>) -> Self::Event {}
+}
+
+impl Style {
+    /// Get the Padding for the area to which children are cropped.
+    pub fn padding(&self, theme: &Theme) -> position::Padding {
+        position::Padding {
+            x: Range::new(self.pad_left(theme), self.pad_right(theme)),
+            y: Range::new(self.pad_bottom(theme), self.pad_top(theme)),
+        }
+    }
+}
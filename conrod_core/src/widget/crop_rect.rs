@@ -0,0 +1,380 @@
+//! A draggable, resizable selection rectangle for cropping images.
+
+use event;
+use input;
+use position::{Dimensions, Point, Range, Rect, Scalar};
+use utils::{map_range, vec2_add, vec2_sub};
+use widget;
+use {Color, Colorable, Positionable, Widget};
+
+/// A draggable, resizable selection rectangle, designed to be overlaid upon an `Image`.
+///
+/// The selection is both given and produced in the pixel coordinates of the image, where
+/// `[0.0, 0.0]` is the bottom left corner of the image. The `CropRect` should be given the same
+/// position and dimensions as the `Image` upon which it is overlaid.
+///
+/// The selection may be moved by dragging its interior or resized by dragging the handles on its
+/// corners and edges. The selection is always constrained to the bounds of the image.
+///
+/// Its reaction is triggered whenever the selection changes.
+#[derive(Clone, WidgetCommon_)]
+pub struct CropRect {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    image_dim: Dimensions,
+    selection: Rect,
+    maybe_aspect_ratio: Option<Scalar>,
+    style: Style,
+    /// Whether or not user input is enabled.
+    pub enabled: bool,
+}
+
+/// Unique styling for the `CropRect`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the selection's outline and handles.
+    #[conrod(default = "theme.border_color")]
+    pub color: Option<Color>,
+    /// The thickness of the selection's outline.
+    #[conrod(default = "1.0")]
+    pub thickness: Option<Scalar>,
+    /// The width and height of the handles on each of the selection's corners and edges.
+    #[conrod(default = "8.0")]
+    pub handle_size: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        outline,
+        handles[],
+    }
+}
+
+/// The state of the `CropRect`.
+pub struct State {
+    ids: Ids,
+    maybe_grab: Option<Grab>,
+}
+
+/// The part of the selection that is currently grabbed by the mouse.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Grab {
+    /// The interior of the selection, which moves the selection as a whole.
+    Move,
+    /// One or two of the selection's edges, which resizes the selection.
+    Resize(Edges),
+}
+
+/// The edges of the selection that are being resized.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Edges {
+    left: bool,
+    right: bool,
+    bottom: bool,
+    top: bool,
+}
+
+/// The number of handles on the selection, one for each corner and edge.
+const NUM_HANDLES: usize = 8;
+
+impl CropRect {
+    /// Begin building a `CropRect` for an image with the given pixel dimensions.
+    ///
+    /// The `selection` is given in the pixel coordinates of the image.
+    pub fn new(image_dim: Dimensions, selection: Rect) -> Self {
+        CropRect {
+            common: widget::CommonBuilder::default(),
+            image_dim: image_dim,
+            selection: selection,
+            maybe_aspect_ratio: None,
+            style: Style::default(),
+            enabled: true,
+        }
+    }
+
+    /// Lock the aspect ratio (width / height) of the selection while it is being resized.
+    pub fn lock_aspect(mut self, ratio: Scalar) -> Self {
+        self.maybe_aspect_ratio = Some(ratio);
+        self
+    }
+
+    builder_methods! {
+        pub thickness { style.thickness = Some(Scalar) }
+        pub handle_size { style.handle_size = Some(Scalar) }
+        pub enabled { enabled = bool }
+    }
+}
+
+impl Widget for CropRect {
+    type State = State;
+    type Style = Style;
+    type Event = Option<Rect>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+            maybe_grab: None,
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the CropRect.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let CropRect {
+            image_dim,
+            selection,
+            maybe_aspect_ratio,
+            enabled,
+            ..
+        } = self;
+
+        let image_rect = Rect::from_corners([0.0, 0.0], image_dim);
+        let handle_size = style.handle_size(&ui.theme);
+
+        // Map the selection from image pixel coordinates to absolute widget coordinates.
+        let to_widget = |selection: Rect| Rect {
+            x: map_range_to(selection.x, image_rect.x, rect.x),
+            y: map_range_to(selection.y, image_rect.y, rect.y),
+        };
+
+        let mut new_selection = Rect {
+            x: clamp_range(selection.x.undirected(), image_rect.x),
+            y: clamp_range(selection.y.undirected(), image_rect.y),
+        };
+        let mut maybe_grab = state.maybe_grab;
+        if enabled {
+            for widget_event in ui.widget_input(id).events() {
+                match widget_event {
+                    event::Widget::Press(press) => {
+                        if let event::Button::Mouse(input::MouseButton::Left, xy) = press.button {
+                            let abs_xy = vec2_add(xy, rect.xy());
+                            maybe_grab = grab_at(to_widget(new_selection), abs_xy, handle_size);
+                        }
+                    }
+
+                    event::Widget::Drag(drag) if drag.button == input::MouseButton::Left => {
+                        // Scale the drag from widget coordinates to image pixel coordinates.
+                        let delta = vec2_sub(drag.to, drag.from);
+                        let delta = [
+                            delta[0] * image_rect.w() / rect.w(),
+                            delta[1] * image_rect.h() / rect.h(),
+                        ];
+                        new_selection = match maybe_grab {
+                            Some(Grab::Move) => move_within(new_selection, delta, image_rect),
+                            Some(Grab::Resize(edges)) => resize_within(
+                                new_selection,
+                                edges,
+                                delta,
+                                image_rect,
+                                maybe_aspect_ratio,
+                            ),
+                            None => new_selection,
+                        };
+                    }
+
+                    event::Widget::Release(release) => {
+                        if let event::Button::Mouse(input::MouseButton::Left, _) = release.button {
+                            maybe_grab = None;
+                        }
+                    }
+
+                    _ => (),
+                }
+            }
+        }
+
+        if state.maybe_grab != maybe_grab {
+            state.update(|state| state.maybe_grab = maybe_grab);
+        }
+
+        // The outline of the selection.
+        let color = style.color(&ui.theme);
+        let thickness = style.thickness(&ui.theme);
+        let selection_rect = to_widget(new_selection);
+        let line_style = widget::line::Style::solid()
+            .color(color)
+            .thickness(thickness);
+        widget::Rectangle::outline_styled(selection_rect.dim(), line_style)
+            .xy_relative_to(id, vec2_sub(selection_rect.xy(), rect.xy()))
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.outline, ui);
+
+        // A handle for each corner and edge of the selection.
+        if state.ids.handles.len() < NUM_HANDLES {
+            state.update(|state| {
+                state
+                    .ids
+                    .handles
+                    .resize(NUM_HANDLES, &mut ui.widget_id_generator())
+            });
+        }
+        let (l, r, b, t) = selection_rect.l_r_b_t();
+        let (x, y) = (selection_rect.x(), selection_rect.y());
+        let handle_xys = [
+            [l, b],
+            [x, b],
+            [r, b],
+            [r, y],
+            [r, t],
+            [x, t],
+            [l, t],
+            [l, y],
+        ];
+        for (&handle_id, &xy) in state.ids.handles.iter().zip(handle_xys.iter()) {
+            widget::Rectangle::fill([handle_size, handle_size])
+                .xy_relative_to(id, vec2_sub(xy, rect.xy()))
+                .color(color)
+                .graphics_for(id)
+                .parent(id)
+                .set(handle_id, ui);
+        }
+
+        if new_selection != selection {
+            Some(new_selection)
+        } else {
+            None
+        }
+    }
+}
+
+impl Colorable for CropRect {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+// Map the given `range` from the `from` space to the `to` space.
+fn map_range_to(range: Range, from: Range, to: Range) -> Range {
+    let map = |value| map_range(value, from.start, from.end, to.start, to.end);
+    Range::new(map(range.start), map(range.end))
+}
+
+// Clamp the given undirected `range` so that it lies within the given `bounds`.
+fn clamp_range(range: Range, bounds: Range) -> Range {
+    let start = bounds.clamp_value(range.start);
+    let end = Range::new(start, bounds.end).clamp_value(range.end);
+    Range::new(start, end)
+}
+
+// Determine which part of the `selection` (in absolute widget coordinates), if any, is at `xy`.
+fn grab_at(selection: Rect, xy: Point, handle_size: Scalar) -> Option<Grab> {
+    let half_handle = handle_size / 2.0;
+    if !selection.pad(-half_handle).is_over(xy) {
+        return None;
+    }
+
+    // Whether `value` is over the handle at `edge` and closer to it than the `opposite` edge.
+    let is_over_edge = |value: Scalar, edge: Scalar, opposite: Scalar| {
+        (value - edge).abs() <= half_handle && (value - edge).abs() <= (value - opposite).abs()
+    };
+    let (l, r, b, t) = selection.l_r_b_t();
+    let edges = Edges {
+        left: is_over_edge(xy[0], l, r),
+        right: is_over_edge(xy[0], r, l) && !is_over_edge(xy[0], l, r),
+        bottom: is_over_edge(xy[1], b, t),
+        top: is_over_edge(xy[1], t, b) && !is_over_edge(xy[1], b, t),
+    };
+
+    if edges.left || edges.right || edges.bottom || edges.top {
+        Some(Grab::Resize(edges))
+    } else {
+        Some(Grab::Move)
+    }
+}
+
+// Shift the `selection` by `delta`, keeping it entirely within the `bounds`.
+fn move_within(selection: Rect, delta: Point, bounds: Rect) -> Rect {
+    fn shift_within(range: Range, amount: Scalar, bounds: Range) -> Range {
+        let range = range.shift(amount);
+        if range.start < bounds.start {
+            range.shift(bounds.start - range.start)
+        } else if range.end > bounds.end {
+            range.shift(bounds.end - range.end)
+        } else {
+            range
+        }
+    }
+    Rect {
+        x: shift_within(selection.x, delta[0], bounds.x),
+        y: shift_within(selection.y, delta[1], bounds.y),
+    }
+}
+
+// Shift the grabbed `edges` of the `selection` by `delta`, keeping the selection within the
+// `bounds` and maintaining the aspect ratio if one is given.
+fn resize_within(
+    selection: Rect,
+    edges: Edges,
+    delta: Point,
+    bounds: Rect,
+    maybe_aspect_ratio: Option<Scalar>,
+) -> Rect {
+    let Rect { mut x, mut y } = selection;
+    if edges.left {
+        x.start = Range::new(bounds.x.start, x.end).clamp_value(x.start + delta[0]);
+    }
+    if edges.right {
+        x.end = Range::new(x.start, bounds.x.end).clamp_value(x.end + delta[0]);
+    }
+    if edges.bottom {
+        y.start = Range::new(bounds.y.start, y.end).clamp_value(y.start + delta[1]);
+    }
+    if edges.top {
+        y.end = Range::new(y.start, bounds.y.end).clamp_value(y.end + delta[1]);
+    }
+
+    let aspect_ratio = match maybe_aspect_ratio {
+        Some(ratio) if ratio > 0.0 => ratio,
+        _ => return Rect { x: x, y: y },
+    };
+
+    // The length of `range` with the given ends fixed, keeping it within `bounds`.
+    fn max_len(range: Range, bounds: Range, from_start: bool, from_end: bool) -> Scalar {
+        if from_start {
+            range.end - bounds.start
+        } else if from_end {
+            bounds.end - range.start
+        } else {
+            let middle = range.middle();
+            2.0 * (middle - bounds.start).min(bounds.end - middle)
+        }
+    }
+
+    // Resize the `range` to `len`, keeping the end opposite to the grabbed end fixed. If neither
+    // end is grabbed, the range is resized about its middle.
+    fn with_len(range: Range, len: Scalar, from_start: bool, from_end: bool) -> Range {
+        if from_start {
+            Range::new(range.end - len, range.end)
+        } else if from_end {
+            Range::new(range.start, range.start + len)
+        } else {
+            Range::from_pos_and_len(range.middle(), len)
+        }
+    }
+
+    // Derive the length of the non-driving axis from the driving axis, shrinking both if the
+    // derived length would exceed the bounds.
+    let (w, h) = if edges.left || edges.right {
+        let max_h = max_len(y, bounds.y, edges.bottom, edges.top);
+        let h = (x.len() / aspect_ratio).min(max_h);
+        (h * aspect_ratio, h)
+    } else {
+        let max_w = max_len(x, bounds.x, edges.left, edges.right);
+        let w = (y.len() * aspect_ratio).min(max_w);
+        (w, w / aspect_ratio)
+    };
+    Rect {
+        x: with_len(x, w, edges.left, edges.right),
+        y: with_len(y, h, edges.bottom, edges.top),
+    }
+}
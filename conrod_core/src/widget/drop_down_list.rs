@@ -1,6 +1,8 @@
 //! The `DropDownList` and related items.
 
+use input;
 use position::{self, Align, Scalar};
+use std;
 use text;
 use utils;
 use widget::{self, Widget};
@@ -14,6 +16,24 @@ pub type Len = usize;
 /// Displays a given `Vec<String>` as a selectable drop down menu.
 ///
 /// It's reaction is triggered upon selection of a list item.
+///
+/// The `DropDownList` may also be operated via the keyboard while it or any of its items are
+/// capturing the keyboard:
+///
+/// - `Space`, `Enter` or `Down` open the closed list.
+/// - `Up` and `Down` move the highlighted item of the open list.
+/// - `Enter` selects the highlighted item and closes the list.
+/// - `Escape` closes the list without changing the selection.
+/// - Typing jumps to the first item whose label starts with the typed text, ignoring case. The
+///   typed text accumulates until no key is typed for the `type_ahead_timeout` or the list
+///   closes, while typing the same single character repeatedly cycles through the items starting
+///   with it. Text that no longer matches any item starts over from the last typed character.
+///   The item is highlighted if the list is open, or selected if the list is closed. This may be
+///   disabled via `type_ahead`.
+///
+/// In `multi_select` mode, any number of items may be selected. Clicking an item (or pressing
+/// `Enter` while it is highlighted) toggles whether or not it is selected and leaves the list
+/// open. See `DropDownList::bind_multi`.
 #[derive(WidgetCommon_)]
 pub struct DropDownList<'a, T: 'a> {
     #[conrod(common_builder)]
@@ -23,6 +43,13 @@ pub struct DropDownList<'a, T: 'a> {
     maybe_label: Option<&'a str>,
     style: Style,
     enabled: bool,
+    /// Whether or not multiple items may be selected at once.
+    pub multi_select: bool,
+    /// Whether or not typing jumps to the first item starting with the typed text.
+    pub type_ahead: bool,
+    /// The selected state of each item in `multi_select` mode, updated whenever an item is
+    /// toggled.
+    maybe_multi_binding: Option<&'a mut Vec<bool>>,
 }
 
 /// Styling for the DropDownList, necessary for constructing its renderable Element.
@@ -64,6 +91,9 @@ pub struct Style {
     /// The ID of the font used to display the labels.
     #[conrod(default = "theme.font_id")]
     pub label_font_id: Option<Option<text::font::Id>>,
+    /// The duration without typing after which the type-ahead search text is cleared.
+    #[conrod(default = "std::time::Duration::from_secs(1)")]
+    pub type_ahead_timeout: Option<std::time::Duration>,
 }
 
 widget_ids! {
@@ -76,6 +106,10 @@ widget_ids! {
 /// Represents the state of the DropDownList.
 pub struct State {
     menu_state: MenuState,
+    highlighted: Option<Idx>,
+    multi_selected: Vec<bool>,
+    /// The text typed so far for the type-ahead search and the moment at which it was last typed.
+    type_ahead: Option<(String, instant::Instant)>,
     ids: Ids,
 }
 
@@ -104,12 +138,29 @@ impl<'a, T> DropDownList<'a, T> {
             selected: selected,
             maybe_label: None,
             enabled: true,
+            multi_select: false,
+            type_ahead: true,
+            maybe_multi_binding: None,
             style: Style::default(),
         }
     }
 
+    /// Construct a new DropDownList in `multi_select` mode, where `selected` holds whether or not
+    /// each item is selected.
+    ///
+    /// Whenever an item is toggled, its new state is written to `selected` (which is extended to
+    /// the number of items if necessary) and the index of the item is returned.
+    pub fn bind_multi(items: &'a [T], selected: &'a mut Vec<bool>) -> Self {
+        let mut drop_down_list = Self::new(items, None).multi_select(true);
+        drop_down_list.maybe_multi_binding = Some(selected);
+        drop_down_list
+    }
+
     builder_methods! {
         pub enabled { enabled = bool }
+        pub multi_select { multi_select = bool }
+        pub type_ahead { type_ahead = bool }
+        pub type_ahead_timeout { style.type_ahead_timeout = Some(std::time::Duration) }
     }
 
     /// Set the maximum height of the DropDownList (before the scrollbar appears) as a number of
@@ -200,6 +251,9 @@ where
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
         State {
             menu_state: MenuState::Closed,
+            highlighted: None,
+            multi_selected: Vec::new(),
+            type_ahead: None,
             ids: Ids::new(id_gen),
         }
     }
@@ -209,7 +263,7 @@ where
     }
 
     /// Update the state of the DropDownList.
-    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+    fn update(mut self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
             id,
             state,
@@ -226,18 +280,148 @@ where
             .selected
             .and_then(|idx| if idx < num_items { Some(idx) } else { None });
 
+        // In `multi_select` mode, whether or not each item is selected. If no selection is bound,
+        // the selection is retained within the widget's state.
+        let multi_select = self.multi_select;
+        let mut maybe_multi_binding = self.maybe_multi_binding.take();
+        let mut multi_selected = match maybe_multi_binding {
+            Some(ref binding) => binding.to_vec(),
+            None => state.multi_selected.clone(),
+        };
+        multi_selected.resize(num_items, false);
+        let is_selected = |i: Idx| {
+            if multi_select {
+                multi_selected[i]
+            } else {
+                Some(i) == selected
+            }
+        };
+
         // Track whether or not a list item was clicked.
         let mut clicked_item = None;
 
+        // The list has keyboard focus while it or any of its children are capturing the keyboard,
+        // or while it is open. Keep the capture on the list itself so that key presses are routed
+        // to it.
+        let has_focus = match ui.global_input().current.widget_capturing_keyboard {
+            Some(capturing) => {
+                capturing == id
+                    || ui
+                        .widget_graph()
+                        .does_recursive_depth_edge_exist(id, capturing)
+            }
+            None => false,
+        };
+        let has_focus = has_focus || self.enabled && state.menu_state == MenuState::Open;
+        if has_focus && ui.global_input().current.widget_capturing_keyboard != Some(id) {
+            ui.keyboard_capture(id);
+        }
+
+        // Open, close and navigate the list via the keyboard.
+        let mut menu_state = state.menu_state;
+        let mut highlighted = state.highlighted;
+        let timeout = style.type_ahead_timeout(&ui.theme);
+        let now = ui.now();
+        let mut type_ahead = state
+            .type_ahead
+            .clone()
+            .filter(|&(_, last_typed)| now.duration_since(last_typed) < timeout);
+        if self.enabled && num_items > 0 {
+            for press in ui.widget_input(id).presses().key() {
+                match (menu_state, press.key) {
+                    (MenuState::Closed, input::Key::Space)
+                    | (MenuState::Closed, input::Key::Return)
+                    | (MenuState::Closed, input::Key::Down) => {
+                        menu_state = MenuState::Open;
+                        highlighted = Some(selected.unwrap_or(0));
+                    }
+                    (MenuState::Open, input::Key::Down) => {
+                        let next = highlighted.or(selected).map_or(0, |i| i + 1);
+                        highlighted = Some(std::cmp::min(next, num_items - 1));
+                    }
+                    (MenuState::Open, input::Key::Up) => {
+                        let prev = highlighted
+                            .or(selected)
+                            .map_or(num_items - 1, |i| i.saturating_sub(1));
+                        highlighted = Some(prev);
+                    }
+                    (MenuState::Open, input::Key::Return) => {
+                        clicked_item = highlighted.or(clicked_item);
+                        if !multi_select {
+                            menu_state = MenuState::Closed;
+                        }
+                    }
+                    (MenuState::Open, input::Key::Escape) => {
+                        menu_state = MenuState::Closed;
+                    }
+                    _ => (),
+                }
+            }
+
+            // Jump to the first item starting with the typed text.
+            let mut search = type_ahead
+                .as_ref()
+                .map_or_else(String::new, |(search, _)| search.clone());
+            let texts = ui.widget_input(id).texts().filter(|_| self.type_ahead);
+            for text in texts {
+                for ch in text.string.chars().filter(|ch| !ch.is_whitespace()) {
+                    search.push(ch);
+                    let current = match menu_state {
+                        MenuState::Open => highlighted.or(selected),
+                        MenuState::Closed => clicked_item.or(selected),
+                    };
+                    // If the text typed so far no longer matches, start over from this character.
+                    let mut maybe_item = next_item_starting_with(self.items, current, &search);
+                    if maybe_item.is_none() && search.chars().count() > 1 {
+                        search = ch.to_string();
+                        maybe_item = next_item_starting_with(self.items, current, &search);
+                    }
+                    match maybe_item {
+                        Some(i) => match menu_state {
+                            MenuState::Open => highlighted = Some(i),
+                            // Toggling items that aren't visible would be surprising.
+                            MenuState::Closed if multi_select => (),
+                            MenuState::Closed => clicked_item = Some(i),
+                        },
+                        None => search.clear(),
+                    }
+                    type_ahead = if search.is_empty() {
+                        None
+                    } else {
+                        Some((search.clone(), now))
+                    };
+                }
+            }
+        }
+        if !self.enabled {
+            menu_state = MenuState::Closed;
+        }
+        if menu_state == MenuState::Closed {
+            highlighted = None;
+        }
+
         // Act on the current menu state and determine what the next one will be.
         // new_menu_state is what we will be getting passed next frame
-        let new_menu_state = match state.menu_state {
+        let new_menu_state = match menu_state {
             // If closed, we only want the button at the selected index to be drawn.
             MenuState::Closed => {
                 // Get the button index and the label for the closed menu's button.
-                let label = selected
-                    .map(|i| self.items[i].as_ref())
-                    .unwrap_or_else(|| self.maybe_label.unwrap_or(""));
+                let label = if multi_select {
+                    let labels: Vec<&str> = (0..num_items)
+                        .filter(|&i| multi_selected[i])
+                        .map(|i| self.items[i].as_ref())
+                        .collect();
+                    labels.join(", ")
+                } else {
+                    selected
+                        .map(|i| self.items[i].as_ref().to_string())
+                        .unwrap_or_default()
+                };
+                let label = if label.is_empty() {
+                    self.maybe_label.unwrap_or("")
+                } else {
+                    &label
+                };
 
                 let was_clicked = {
                     // use the pre-existing Button widget
@@ -245,6 +429,7 @@ where
                         .xy(rect.xy())
                         .wh(rect.dim())
                         .label(label)
+                        .enabled(self.enabled)
                         .parent(id);
                     button.style = style.button_style(false);
                     button.set(state.ids.closed_menu, ui).was_clicked()
@@ -305,7 +490,10 @@ where
                     .floating(true)
                     .set(state.ids.list, ui);
 
-                while let Some(event) = events.next(ui, |i| Some(i) == selected) {
+                // While open, the list is an overlay that receives all mouse input over it.
+                ui.open_overlay(state.ids.list);
+
+                while let Some(event) = events.next(ui, is_selected) {
                     use widget::list_select::Event;
                     match event {
                         // Instantiate a `Button` for each item.
@@ -313,7 +501,12 @@ where
                             let i = item.i;
                             let label = self.items[i].as_ref();
                             let mut button = widget::Button::new().label(label);
-                            button.style = style.button_style(Some(i) == selected);
+                            let is_selected = match highlighted {
+                                Some(highlighted) => highlighted == i,
+                                None => is_selected(i),
+                            };
+                            let is_selected = is_selected || multi_select && multi_selected[i];
+                            button.style = style.button_style(is_selected);
                             item.set(button, ui);
                         }
 
@@ -329,27 +522,11 @@ where
                     scrollbar.set(ui);
                 }
 
-                // Close the menu if the mouse is pressed and the currently pressed widget is
-                // not any of the drop down list's children.
-                let should_close = clicked_item.is_some()
-                    || clicked_item.is_none()
-                        && ui
-                            .global_input()
-                            .current
-                            .mouse
-                            .buttons
-                            .pressed()
-                            .next()
-                            .is_some()
-                        && match ui.global_input().current.widget_capturing_mouse {
-                            None => true,
-                            Some(capturing) => !ui
-                                .widget_graph()
-                                .does_recursive_depth_edge_exist(id, capturing),
-                        };
-
-                // If a mouse button was pressed somewhere else, close the menu.
-                //
+                // Close the menu once an item is chosen, or if the mouse was pressed outside of the
+                // list, dismissing its overlay.
+                let should_close = clicked_item.is_some() && !multi_select
+                    || ui.dismissed_overlays().contains(&state.ids.list);
+
                 // Otherwise, leave the menu open.
                 if should_close {
                     MenuState::Closed
@@ -359,14 +536,69 @@ where
             }
         };
 
-        if state.menu_state != new_menu_state {
-            state.update(|state| state.menu_state = new_menu_state);
+        if new_menu_state == MenuState::Closed {
+            highlighted = None;
+            ui.close_overlay(state.ids.list);
+        }
+
+        // Toggle the clicked item's membership of the selection.
+        if multi_select {
+            if let Some(i) = clicked_item {
+                multi_selected[i] = !multi_selected[i];
+                if let Some(binding) = maybe_multi_binding.as_mut() {
+                    binding.resize(num_items, false);
+                    binding[i] = multi_selected[i];
+                }
+            }
+        }
+
+        // The search text starts over each time the list closes.
+        if state.menu_state == MenuState::Open && new_menu_state == MenuState::Closed {
+            type_ahead = None;
+        }
+
+        if state.menu_state != new_menu_state
+            || state.highlighted != highlighted
+            || state.type_ahead != type_ahead
+            || maybe_multi_binding.is_none() && state.multi_selected != multi_selected
+        {
+            state.update(|state| {
+                state.menu_state = new_menu_state;
+                state.highlighted = highlighted;
+                state.type_ahead = type_ahead;
+                if maybe_multi_binding.is_none() {
+                    state.multi_selected = multi_selected;
+                }
+            });
         }
 
         clicked_item
     }
 }
 
+/// The index of the first item from `current` whose label starts with the given `search` text,
+/// ignoring case and wrapping around to the start of the list.
+///
+/// If the `search` consists of a single repeated character, the search begins after `current`
+/// so that repeatedly typing the same character cycles through the items starting with it.
+fn next_item_starting_with<T>(items: &[T], current: Option<Idx>, search: &str) -> Option<Idx>
+where
+    T: AsRef<str>,
+{
+    let mut chars = search.chars();
+    let first = chars.next();
+    let (start, search) = if chars.all(|ch| Some(ch) == first) {
+        let prefix = &search[..first.map_or(0, char::len_utf8)];
+        (current.map_or(0, |i| i + 1), prefix)
+    } else {
+        (current.unwrap_or(0), search)
+    };
+    let search = search.to_lowercase();
+    (0..items.len())
+        .map(|offset| (start + offset) % items.len())
+        .find(|&i| items[i].as_ref().to_lowercase().starts_with(&search))
+}
+
 impl Style {
     /// Style for a `Button` given this `Style`'s current state.
     pub fn button_style(&self, is_selected: bool) -> widget::button::Style {
@@ -376,12 +608,14 @@ impl Style {
                 .map(|c| if is_selected { c.highlighted() } else { c }),
             border: self.border,
             border_color: self.border_color,
+            corner_radius: None,
             label_color: self.label_color,
             label_font_size: self.label_font_size,
             label_justify: self.label_justify,
             label_x: self.label_x,
             label_y: self.label_y,
             label_font_id: self.label_font_id,
+            label_orientation: None,
         }
     }
 }
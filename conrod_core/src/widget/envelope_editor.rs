@@ -632,11 +632,11 @@ where
             };
             let value_font_size = style.value_font_size(ui.theme());
             let closest_point_id = state.ids.points[closest_idx];
-            const VALUE_TEXT_PAD: f64 = 5.0; // Slight padding between the point and the text.
+            let value_text_pad = ui.theme.spacing_sm; // Slight padding between the point and the text.
             widget::Text::new(&xy_string)
                 .and_then(font_id, widget::Text::font_id)
-                .x_direction_from(closest_point_id, x_direction, VALUE_TEXT_PAD)
-                .y_direction_from(closest_point_id, y_direction, VALUE_TEXT_PAD)
+                .x_direction_from(closest_point_id, x_direction, value_text_pad)
+                .y_direction_from(closest_point_id, y_direction, value_text_pad)
                 .color(line_color)
                 .graphics_for(id)
                 .parent(id)
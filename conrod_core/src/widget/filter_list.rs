@@ -0,0 +1,239 @@
+//! A list of items that may be filtered via a text box.
+
+use position::{Axis, Scalar};
+use text;
+use widget::scroll::Visibility;
+use widget::{self, Widget};
+use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Sizeable};
+
+/// A `TextBox` above a vertically scrollable list of `Button`s, one for each item whose label
+/// contains the text box's contents.
+///
+/// The filter ignores case and shows all items while the text box is empty. If no items match,
+/// the `no_matches_label` is displayed in place of the list.
+///
+/// Clicking an item, or pressing `Return` within the text box to choose the first shown item,
+/// produces the item's index within `items` along with its label.
+#[derive(WidgetCommon_)]
+pub struct FilterList<'a, T: 'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    items: &'a [T],
+    selected: Option<Idx>,
+    no_matches_label: &'a str,
+    style: Style,
+}
+
+/// Unique styling for the FilterList.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// Color of the text box and the item buttons.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// Width of the border around the text box, the list and each item.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// Color of the border around the text box, the list and each item.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// Color of the filter text and the item labels.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// Font size of the filter text and the item labels.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used for the filter text and the item labels.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+    /// The height of the text box and of each item.
+    #[conrod(default = "30.0")]
+    pub item_height: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        text_box,
+        canvas,
+        no_matches,
+        items[],
+    }
+}
+
+/// Represents the state of the FilterList.
+pub struct State {
+    /// The current contents of the text box.
+    filter: String,
+    ids: Ids,
+}
+
+/// The index of an item within the FilterList's `items`.
+pub type Idx = usize;
+
+impl<'a, T> FilterList<'a, T> {
+    /// Construct a new FilterList over the given `items`, with the item at `selected` highlighted.
+    pub fn new(items: &'a [T], selected: Option<Idx>) -> Self {
+        FilterList {
+            common: widget::CommonBuilder::default(),
+            items: items,
+            selected: selected,
+            no_matches_label: "No matches",
+            style: Style::default(),
+        }
+    }
+
+    /// Specify the font used for displaying the filter text and the item labels.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods! {
+        pub no_matches_label { no_matches_label = &'a str }
+        pub item_height { style.item_height = Some(Scalar) }
+    }
+}
+
+impl<'a, T> Widget for FilterList<'a, T>
+where
+    T: AsRef<str>,
+{
+    type State = State;
+    type Style = Style;
+    /// The index and label of the chosen item, if any.
+    type Event = Option<(Idx, &'a str)>;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            filter: String::new(),
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the FilterList.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+
+        let color = style.color(&ui.theme);
+        let border = style.border(&ui.theme);
+        let border_color = style.border_color(&ui.theme);
+        let label_color = style.label_color(&ui.theme);
+        let font_size = style.label_font_size(&ui.theme);
+        let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+        let item_h = style.item_height(&ui.theme);
+
+        // The indices of the items whose labels contain the filter text.
+        let matches = |filter: &str| -> Vec<Idx> {
+            let filter = filter.to_lowercase();
+            (0..self.items.len())
+                .filter(|&i| self.items[i].as_ref().to_lowercase().contains(&filter))
+                .collect()
+        };
+
+        let mut chosen = None;
+
+        // The **TextBox** for editing the filter at the top of the widget.
+        let mut filter = state.filter.clone();
+        for event in widget::TextBox::new(&state.filter)
+            .and_then(font_id, widget::TextBox::font_id)
+            .w_h(rect.w(), item_h)
+            .mid_top_of(id)
+            .parent(id)
+            .color(color)
+            .border(border)
+            .border_color(border_color)
+            .text_color(label_color)
+            .font_size(font_size)
+            .set(state.ids.text_box, ui)
+        {
+            match event {
+                widget::text_box::Event::Update(string) => filter = string,
+                widget::text_box::Event::Enter => chosen = matches(&filter).first().cloned(),
+            }
+        }
+        if state.filter != filter {
+            state.update(|state| state.filter = filter);
+        }
+        let shown = matches(&state.filter);
+
+        // The scrollable **Canvas** containing the shown items.
+        widget::Canvas::new()
+            .w_h(rect.w(), (rect.h() - item_h).max(0.0))
+            .mid_bottom_of(id)
+            .parent(id)
+            .pad(0.0)
+            .color(color)
+            .border(border)
+            .border_color(border_color)
+            .scroll_kids_vertically()
+            .scroll_bar_visibility(Axis::Y, Visibility::Auto)
+            .set(state.ids.canvas, ui);
+
+        if shown.is_empty() {
+            widget::Text::new(self.no_matches_label)
+                .and_then(font_id, widget::Text::font_id)
+                .middle_of(state.ids.canvas)
+                .graphics_for(id)
+                .color(label_color)
+                .font_size(font_size)
+                .set(state.ids.no_matches, ui);
+        }
+
+        // Instantiate a **Button** for each shown item.
+        if state.ids.items.len() < shown.len() {
+            state.update(|state| {
+                state
+                    .ids
+                    .items
+                    .resize(shown.len(), &mut ui.widget_id_generator())
+            });
+        }
+        for (n, &i) in shown.iter().enumerate() {
+            let button_color = if self.selected == Some(i) {
+                color.highlighted()
+            } else {
+                color
+            };
+            let was_clicked = widget::Button::new()
+                .and_then(font_id, widget::Button::label_font_id)
+                .kid_area_w_of(state.ids.canvas)
+                .h(item_h)
+                .mid_top_with_margin_on(state.ids.canvas, n as Scalar * item_h)
+                .parent(state.ids.canvas)
+                .color(button_color)
+                .border(border)
+                .border_color(border_color)
+                .label(self.items[i].as_ref())
+                .label_color(label_color)
+                .label_font_size(font_size)
+                .set(state.ids.items[n], ui)
+                .was_clicked();
+            if was_clicked {
+                chosen = Some(i);
+            }
+        }
+
+        chosen.map(|i| (i, self.items[i].as_ref()))
+    }
+}
+
+impl<'a, T> Colorable for FilterList<'a, T> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl<'a, T> Borderable for FilterList<'a, T> {
+    builder_methods! {
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
@@ -0,0 +1,241 @@
+//! A helper widget for laying out child widgets within a grid of non-uniform rows and columns.
+
+use position::{Range, Rect};
+use widget;
+use {Positionable, Scalar, Sizeable, UiCell, Widget};
+
+/// Lays out child widgets within a grid whose columns and rows may each have a different length.
+///
+/// Fixed lengths are allocated first, after which the remaining length is divided among the
+/// weighted columns or rows in proportion to their weights, in the same manner as **Canvas**
+/// splits.
+///
+/// The `Cells` yielded upon `set` may be used to position a widget over a single cell or over a
+/// span of several cells.
+#[derive(Copy, Clone, Debug, WidgetCommon_)]
+pub struct GridLayout<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    style: Style,
+    cols: &'a [GridLength],
+    rows: &'a [GridLength],
+}
+
+/// The length of a single column or row of a `GridLayout`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GridLength {
+    /// The length as an absolute scalar.
+    Fixed(Scalar),
+    /// The length as a weight of the non-fixed length of the `GridLayout`.
+    Weight(Scalar),
+}
+
+/// Unique styling for the `GridLayout`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The width of the padding on either side of each cell.
+    #[conrod(default = "0.0")]
+    pub cell_pad_w: Option<Scalar>,
+    /// The height of the padding above and below each cell.
+    #[conrod(default = "0.0")]
+    pub cell_pad_h: Option<Scalar>,
+}
+
+/// The state of the `GridLayout`, which has nothing to cache as its cells are laid out anew upon
+/// each update.
+#[derive(Copy, Clone, Debug)]
+pub struct State;
+
+/// The event type yielded by the `GridLayout`, describing the area occupied by each cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cells {
+    grid_id: widget::Id,
+    /// The range occupied by each column, from left to right, relative to the grid's centre.
+    cols: Vec<Range>,
+    /// The range occupied by each row, from top to bottom, relative to the grid's centre.
+    rows: Vec<Range>,
+    cell_pad_w: Scalar,
+    cell_pad_h: Scalar,
+}
+
+/// The area spanned by one or more cells of a `GridLayout`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cell {
+    /// The column of the top left cell.
+    pub col: usize,
+    /// The row of the top left cell.
+    pub row: usize,
+    /// The number of columns spanned.
+    pub colspan: usize,
+    /// The number of rows spanned.
+    pub rowspan: usize,
+    /// The area of the cell relative to the centre of the `GridLayout`, excluding cell padding.
+    pub rel_rect: Rect,
+    /// The id of the `GridLayout`, used for positioning.
+    grid_id: widget::Id,
+}
+
+impl<'a> GridLayout<'a> {
+    /// Begin building a `GridLayout` with the given column and row lengths.
+    pub fn new(cols: &'a [GridLength], rows: &'a [GridLength]) -> Self {
+        GridLayout {
+            common: widget::CommonBuilder::default(),
+            style: Style::default(),
+            cols: cols,
+            rows: rows,
+        }
+    }
+
+    /// A builder method for adding padding to each cell.
+    pub fn cell_padding(mut self, w: Scalar, h: Scalar) -> Self {
+        self.style.cell_pad_w = Some(w);
+        self.style.cell_pad_h = Some(h);
+        self
+    }
+}
+
+impl<'a> Widget for GridLayout<'a> {
+    type State = State;
+    type Style = Style;
+    type Event = Cells;
+
+    fn init_state(&self, _: widget::id::Generator) -> Self::State {
+        State
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the GridLayout.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let (half_w, half_h) = (rect.w() / 2.0, rect.h() / 2.0);
+        Cells {
+            grid_id: id,
+            cols: resolve_lengths(self.cols, rect.w())
+                .into_iter()
+                .map(|range| range.shift(-half_w))
+                .collect(),
+            rows: resolve_lengths(self.rows, rect.h())
+                .into_iter()
+                .map(|range| Range::new(half_h - range.end, half_h - range.start))
+                .collect(),
+            cell_pad_w: style.cell_pad_w(&ui.theme),
+            cell_pad_h: style.cell_pad_h(&ui.theme),
+        }
+    }
+}
+
+impl Cells {
+    /// The number of columns in the grid.
+    pub fn num_cols(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// The number of rows in the grid.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The single cell at the given column and row.
+    ///
+    /// Returns `None` if the cell lies beyond the bounds of the grid.
+    pub fn cell(&self, col: usize, row: usize) -> Option<Cell> {
+        self.span(col, row, 1, 1)
+    }
+
+    /// The area spanning `colspan` columns and `rowspan` rows from the given column and row.
+    ///
+    /// Returns `None` if the top left cell lies beyond the bounds of the grid or if either span is
+    /// zero. A span extending beyond the bounds of the grid is limited to its last column or row.
+    pub fn span(&self, col: usize, row: usize, colspan: usize, rowspan: usize) -> Option<Cell> {
+        if col >= self.cols.len() || row >= self.rows.len() || colspan == 0 || rowspan == 0 {
+            return None;
+        }
+        let colspan = colspan.min(self.cols.len() - col);
+        let rowspan = rowspan.min(self.rows.len() - row);
+        let x = Range::new(self.cols[col].start, self.cols[col + colspan - 1].end);
+        let y = Range::new(self.rows[row + rowspan - 1].start, self.rows[row].end);
+        let rel_rect = Rect {
+            x: x.pad(self.cell_pad_w),
+            y: y.pad(self.cell_pad_h),
+        };
+        Some(Cell {
+            col: col,
+            row: row,
+            colspan: colspan,
+            rowspan: rowspan,
+            rel_rect: rel_rect,
+            grid_id: self.grid_id,
+        })
+    }
+
+    /// An iterator yielding each single cell of the grid, row by row from the top left.
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = Cell> + 'a {
+        let num_cols = self.cols.len();
+        (0..self.rows.len() * num_cols).filter_map(move |i| self.cell(i % num_cols, i / num_cols))
+    }
+}
+
+impl Cell {
+    /// Sets the given widget over the area of the cell.
+    ///
+    /// Sets the:
+    /// - position of the widget.
+    /// - dimensions of the widget.
+    /// - parent of the widget.
+    /// - and finally sets the widget within the `Ui`.
+    pub fn set<W>(self, widget: W, widget_id: widget::Id, ui: &mut UiCell) -> W::Event
+    where
+        W: Widget,
+    {
+        let Cell {
+            grid_id, rel_rect, ..
+        } = self;
+        widget
+            .wh(rel_rect.dim())
+            .x_y_relative_to(grid_id, rel_rect.x(), rel_rect.y())
+            .parent(grid_id)
+            .set(widget_id, ui)
+    }
+}
+
+/// Resolve the given `lengths` into consecutive ranges beginning at `0.0` and covering the `total`
+/// length.
+///
+/// Fixed lengths are allocated first, after which the remaining length is divided among the
+/// weighted lengths in proportion to their weights. Negative lengths and weights are treated as
+/// zero.
+pub fn resolve_lengths(lengths: &[GridLength], total: Scalar) -> Vec<Range> {
+    let (total_fixed, total_weight) =
+        lengths
+            .iter()
+            .fold((0.0, 0.0), |(fixed, weight), length| match *length {
+                GridLength::Fixed(f) => (fixed + f.max(0.0), weight),
+                GridLength::Weight(w) => (fixed, weight + w.max(0.0)),
+            });
+    let non_fixed = (total - total_fixed).max(0.0);
+    let mut start = 0.0;
+    lengths
+        .iter()
+        .map(|length| {
+            let len = match *length {
+                GridLength::Fixed(f) => f.max(0.0),
+                GridLength::Weight(w) if total_weight > 0.0 => {
+                    w.max(0.0) / total_weight * non_fixed
+                }
+                GridLength::Weight(_) => 0.0,
+            };
+            let range = Range::new(start, start + len);
+            start += len;
+            range
+        })
+        .collect()
+}
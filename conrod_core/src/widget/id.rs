@@ -25,8 +25,14 @@ pub struct Generator<'a> {
 }
 
 /// A list of lazily generated `widget::Id`s.
+///
+/// The `widget::Id`s removed by shrinking the list are retained and reused when it grows again, so
+/// that a list resized to a varying length upon each update does not keep generating new ones.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct List(Vec<Id>);
+pub struct List {
+    ids: Vec<Id>,
+    len: usize,
+}
 /// An iterator-like type for producing indices from a `List`.
 #[allow(missing_copy_implementations)]
 pub struct ListWalk {
@@ -52,12 +58,26 @@ impl<'a> Generator<'a> {
     pub fn next(&mut self) -> Id {
         self.widget_graph.add_placeholder()
     }
+
+    /// Produce the `widget::Id` for the given name, generating it upon the first request.
+    ///
+    /// Every request for the same name within the same `Ui` produces the same `widget::Id`, so
+    /// named ids need not be stored between updates. As `widget::Id`s are allocated
+    /// consecutively, the id itself still depends on the order in which it was first requested,
+    /// however the name may be used as a stable key for the widget across runs via
+    /// `Graph::named_ids`.
+    pub fn named(&mut self, name: &str) -> Id {
+        self.widget_graph.named_placeholder(name)
+    }
 }
 
 impl List {
     /// Construct a cache for multiple indices.
     pub fn new() -> Self {
-        List(Vec::new())
+        List {
+            ids: Vec::new(),
+            len: 0,
+        }
     }
 
     /// Produce a walker for producing the `List`'s indices.
@@ -65,35 +85,37 @@ impl List {
         ListWalk { i: 0 }
     }
 
-    /// Resizes the `List`'s inner `Vec` to the given target length, using the given `UiCell` to
-    /// generate new unique `widget::Id`s if necessary.
+    /// Resizes the `List` to the given target length, using the given `Generator` to generate new
+    /// unique `widget::Id`s if necessary.
+    ///
+    /// The `widget::Id`s beyond the target length are retained for reuse should the `List` grow
+    /// again.
     pub fn resize(&mut self, target_len: usize, id_generator: &mut Generator) {
-        if self.len() < target_len {
-            self.0.reserve(target_len);
-            while self.len() < target_len {
-                self.0.push(id_generator.next());
+        if self.ids.len() < target_len {
+            self.ids.reserve(target_len - self.ids.len());
+            while self.ids.len() < target_len {
+                self.ids.push(id_generator.next());
             }
         }
-        while self.len() > target_len {
-            self.0.pop();
-        }
+        self.len = target_len;
     }
 }
 
 impl std::ops::Deref for List {
     type Target = [Id];
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.ids[..self.len]
     }
 }
 
 impl ListWalk {
     /// Yield the next index, generating one if it does not yet exist.
-    pub fn next(&mut self, &mut List(ref mut ids): &mut List, id_gen: &mut Generator) -> Id {
-        while self.i >= ids.len() {
-            ids.push(id_gen.next());
+    pub fn next(&mut self, list: &mut List, id_gen: &mut Generator) -> Id {
+        while self.i >= list.ids.len() {
+            list.ids.push(id_gen.next());
         }
-        let ix = ids[self.i];
+        list.len = std::cmp::max(list.len, self.i + 1);
+        let ix = list.ids[self.i];
         self.i += 1;
         ix
     }
@@ -147,6 +169,12 @@ impl ListWalk {
 ///     widget::Toggle::new(true).set(id, ui);
 /// }
 /// ```
+///
+/// An `Ids::named` constructor is also generated. It produces each single `widget::Id` via
+/// `Generator::named` with a stable name such as `"Ids::button"`, e.g. for keying snapshots via
+/// `Graph::named_ids`. As every container constructed via `Ids::named` shares the same ids, it
+/// should only be used for a single instance per `Ui`, e.g. the ids of the application's own
+/// widgets rather than those within a custom widget's `State`.
 #[macro_export]
 macro_rules! widget_ids {
 
@@ -181,6 +209,18 @@ macro_rules! widget_ids {
                 }
             }
 
+            /// Construct a new `widget::Id` container, producing each single `widget::Id` via
+            /// `Generator::named` with a stable name of the form `"Ids::field"`.
+            ///
+            /// Every container constructed this way within the same `Ui` shares the same
+            /// `widget::Id`s.
+            #[allow(dead_code, unused_mut, unused_variables)]
+            pub fn named(mut generator: $crate::widget::id::Generator) -> Self {
+                widget_ids! {
+                    named_constructor $Ids, generator { {} $($id)* }
+                }
+            }
+
         }
     };
 
@@ -349,6 +389,54 @@ macro_rules! widget_ids {
         }
     };
 
+
+    /////////////////////////////
+    ///// named_constructor /////
+    /////////////////////////////
+    //
+    // The same as the `constructor` branches, but converts `foo` within `Ids` to
+    // `foo: generator.named("Ids::foo")`. `foo[]` lists are still constructed empty.
+
+    (named_constructor $Ids:ident, $generator:ident {
+        { $($id_field:ident: $new:expr,)* } $id:ident[], $($rest:tt)*
+    }) => {
+        widget_ids! {
+            named_constructor $Ids, $generator {
+                {
+                    $($id_field: $new,)*
+                    $id: $crate::widget::id::List::new(),
+                }
+                $($rest)*
+            }
+        }
+    };
+    (named_constructor $Ids:ident, $generator:ident {
+        { $($id_field:ident: $new:expr,)* } $id:ident, $($rest:tt)*
+    }) => {
+        widget_ids! {
+            named_constructor $Ids, $generator {
+                {
+                    $($id_field: $new,)*
+                    $id: $generator.named(concat!(stringify!($Ids), "::", stringify!($id))),
+                }
+                $($rest)*
+            }
+        }
+    };
+    (named_constructor $Ids:ident, $generator:ident {
+        { $($id_field:ident: $new:expr,)* } $id:ident[]
+    }) => {
+        widget_ids! { named_constructor $Ids, $generator { { $($id_field: $new,)* } $id[], } }
+    };
+    (named_constructor $Ids:ident, $generator:ident {
+        { $($id_field:ident: $new:expr,)* } $id:ident
+    }) => {
+        widget_ids! { named_constructor $Ids, $generator { { $($id_field: $new,)* } $id, } }
+    };
+    (named_constructor $Ids:ident, $generator:ident { { $($id:ident: $new:expr,)* } }) => {
+        widget_ids! { constructor $Ids, $generator { { $($id: $new,)* } } }
+    };
+
 }
 
 #[test]
@@ -407,3 +495,23 @@ fn test_invocation_variations() {
     let g = G::new(ui.widget_id_generator());
     let h = H::new(ui.widget_id_generator());
 }
+
+#[test]
+fn named_ids_should_be_stable_across_constructions() {
+    use ui::UiBuilder;
+
+    widget_ids! { struct Ids { foo, bar[], baz } }
+
+    let mut ui = UiBuilder::new([800.0, 600.0]).build();
+    let a = Ids::named(ui.widget_id_generator());
+    let b = Ids::named(ui.widget_id_generator());
+    assert_eq!((a.foo, a.baz), (b.foo, b.baz));
+    assert_ne!(a.foo, a.baz);
+    assert_eq!(ui.widget_graph().named_id("Ids::foo"), Some(a.foo));
+    assert_eq!(ui.widget_graph().named_id("Ids::baz"), Some(a.baz));
+    assert!(a.bar.iter().next().is_none());
+
+    // Ids constructed via `new` are unique as usual.
+    let c = Ids::new(ui.widget_id_generator());
+    assert_ne!(c.foo, a.foo);
+}
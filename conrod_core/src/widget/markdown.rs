@@ -0,0 +1,279 @@
+//! A scrollable column of text laid out from a small subset of Markdown.
+
+use position::{Place, Scalar};
+use text;
+use widget;
+use {Color, Colorable, FontSize, Positionable, Sizeable, Widget};
+
+/// Displays the given Markdown `source` as a vertically scrollable column of `Text` widgets.
+///
+/// Only a small subset of Markdown is supported:
+///
+/// - `#` headings, where level one headings use the `heading_font_size` and deeper headings
+///   use the `subheading_font_size`.
+/// - `-`, `*` and `+` bullet lists, indented by two spaces per level of nesting.
+/// - Paragraphs of consecutive lines, separated by blank lines.
+///
+/// Within each block, the markers surrounding `**bold**`, `__bold__`, `*italic*` and `` `code` ``
+/// spans are removed and the spans are displayed as plain text. Any other syntax is displayed as
+/// plain text as it appears in the source.
+///
+/// The spacing between blocks and the indentation of bullets are taken from the `Theme`.
+#[derive(Copy, Clone, WidgetCommon_)]
+pub struct Markdown<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    source: &'a str,
+    style: Style,
+}
+
+/// Unique styling for the `Markdown`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the text.
+    #[conrod(default = "theme.label_color")]
+    pub color: Option<Color>,
+    /// The font size of paragraphs and bullets.
+    #[conrod(default = "theme.font_size_small")]
+    pub font_size: Option<FontSize>,
+    /// The font size of level one headings.
+    #[conrod(default = "theme.font_size_large")]
+    pub heading_font_size: Option<FontSize>,
+    /// The font size of headings of level two and deeper.
+    #[conrod(default = "theme.font_size_medium")]
+    pub subheading_font_size: Option<FontSize>,
+    /// The ID of the font used to display the text.
+    #[conrod(default = "theme.font_id")]
+    pub font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        blocks[],
+        bullets[],
+    }
+}
+
+/// The state of the `Markdown`.
+pub struct State {
+    ids: Ids,
+}
+
+/// A single block of the parsed Markdown source.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Block {
+    /// A heading of the given level, starting from `1`.
+    Heading(usize, String),
+    /// An item within a bullet list, nested at the given depth starting from `0`.
+    Bullet(usize, String),
+    /// A paragraph of text.
+    Paragraph(String),
+}
+
+/// The marker displayed to the left of each bullet.
+const BULLET: &str = "\u{2022}";
+
+impl<'a> Markdown<'a> {
+    /// Begin building a `Markdown` widget for the given `source`.
+    pub fn new(source: &'a str) -> Self {
+        Markdown {
+            common: widget::CommonBuilder::default(),
+            source: source,
+            style: Style::default(),
+        }
+        .scroll_kids_vertically()
+    }
+
+    /// Specify the font used for displaying the text.
+    pub fn font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods! {
+        pub font_size { style.font_size = Some(FontSize) }
+        pub heading_font_size { style.heading_font_size = Some(FontSize) }
+        pub subheading_font_size { style.subheading_font_size = Some(FontSize) }
+    }
+}
+
+impl<'a> Widget for Markdown<'a> {
+    type State = State;
+    type Style = Style;
+    type Event = ();
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Markdown.
+    fn update(self, args: widget::UpdateArgs<Self>) {
+        let widget::UpdateArgs {
+            id,
+            state,
+            style,
+            rect,
+            ui,
+            ..
+        } = args;
+
+        let blocks = parse(self.source);
+        // Every block may need a bullet marker.
+        if state.ids.blocks.len() < blocks.len() {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| {
+                state.ids.blocks.resize(blocks.len(), id_gen);
+                state.ids.bullets.resize(blocks.len(), id_gen);
+            });
+        }
+
+        let color = style.color(&ui.theme);
+        let font_id = style.font_id(&ui.theme).or(ui.fonts.ids().next());
+        let block_spacing = ui.theme.spacing_md;
+        let bullet_indent = ui.theme.spacing_lg;
+        let kid_w = rect.w();
+
+        let mut bullet_ids = state.ids.bullets.iter();
+        let mut maybe_prev = None;
+        for (block, &block_id) in blocks.iter().zip(state.ids.blocks.iter()) {
+            let (string, font_size, indent) = match *block {
+                Block::Heading(1, ref string) => (string, style.heading_font_size(&ui.theme), 0.0),
+                Block::Heading(_, ref string) => {
+                    (string, style.subheading_font_size(&ui.theme), 0.0)
+                }
+                Block::Bullet(depth, ref string) => {
+                    let indent = bullet_indent * (depth + 1) as Scalar;
+                    (string, style.font_size(&ui.theme), indent)
+                }
+                Block::Paragraph(ref string) => (string, style.font_size(&ui.theme), 0.0),
+            };
+
+            let text = widget::Text::new(string)
+                .and_then(font_id, widget::Text::font_id)
+                .color(color)
+                .font_size(font_size)
+                .w((kid_w - indent).max(0.0))
+                .x_place_on(id, Place::Start(Some(indent)))
+                .parent(id);
+            let text = match maybe_prev {
+                None => text.y_place_on(id, Place::End(None)),
+                Some(prev) => text.down_from(prev, block_spacing),
+            };
+            text.set(block_id, ui);
+            maybe_prev = Some(block_id);
+
+            // The bullet marker sits within the indentation, aligned with the first line.
+            if let Block::Bullet(..) = *block {
+                let bullet_id = *bullet_ids.next().unwrap();
+                widget::Text::new(BULLET)
+                    .and_then(font_id, widget::Text::font_id)
+                    .color(color)
+                    .font_size(font_size)
+                    .x_place_on(id, Place::Start(Some(indent - bullet_indent / 2.0)))
+                    .align_top_of(block_id)
+                    .graphics_for(block_id)
+                    .parent(id)
+                    .set(bullet_id, ui);
+            }
+        }
+    }
+}
+
+impl<'a> Colorable for Markdown<'a> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+/// Parse the supported subset of Markdown from the given `source` into a list of blocks.
+///
+/// See the `Markdown` docs for the supported syntax.
+pub fn parse(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Option<String> = None;
+    for line in source.lines() {
+        let trimmed = line.trim();
+        let block = if trimmed.is_empty() {
+            None
+        } else if let Some(heading) = parse_heading(trimmed) {
+            Some(heading)
+        } else if let Some(bullet) = parse_bullet(line) {
+            Some(bullet)
+        } else {
+            // Consecutive lines of text are joined into a single paragraph.
+            let text = plain_text(trimmed);
+            paragraph = Some(match paragraph.take() {
+                Some(mut paragraph) => {
+                    paragraph.push(' ');
+                    paragraph.push_str(&text);
+                    paragraph
+                }
+                None => text,
+            });
+            continue;
+        };
+        if let Some(paragraph) = paragraph.take() {
+            blocks.push(Block::Paragraph(paragraph));
+        }
+        blocks.extend(block);
+    }
+    blocks.extend(paragraph.map(Block::Paragraph));
+    blocks
+}
+
+// A line consisting of one to six `#`s followed by a space and the heading text.
+fn parse_heading(line: &str) -> Option<Block> {
+    let level = line.chars().take_while(|&ch| ch == '#').count();
+    if level == 0 || level > 6 || !line[level..].starts_with(' ') {
+        return None;
+    }
+    Some(Block::Heading(level, plain_text(line[level..].trim())))
+}
+
+// A line beginning with `-`, `*` or `+` followed by a space, nested by two spaces per level.
+fn parse_bullet(line: &str) -> Option<Block> {
+    let trimmed = line.trim_start();
+    let leading_spaces = line.len() - trimmed.len();
+    let mut chars = trimmed.chars();
+    match (chars.next(), chars.next()) {
+        (Some('-'), Some(' ')) | (Some('*'), Some(' ')) | (Some('+'), Some(' ')) => Some(
+            Block::Bullet(leading_spaces / 2, plain_text(trimmed[2..].trim())),
+        ),
+        _ => None,
+    }
+}
+
+// Remove the markers surrounding the bold, italic and code spans within the given text.
+//
+// Markers without a matching closing marker are left as they are.
+fn plain_text(inline: &str) -> String {
+    const MARKERS: &[&str] = &["`", "**", "__", "*"];
+    let mut string = String::with_capacity(inline.len());
+    let mut rest = inline;
+    while let Some(ch) = rest.chars().next() {
+        let maybe_marker = MARKERS.iter().find(|&&marker| rest.starts_with(marker));
+        if let Some(&marker) = maybe_marker {
+            let after = &rest[marker.len()..];
+            match after.find(marker) {
+                Some(end) if end > 0 => {
+                    // The contents of code spans are displayed verbatim.
+                    if marker == "`" {
+                        string.push_str(&after[..end]);
+                    } else {
+                        string.push_str(&plain_text(&after[..end]));
+                    }
+                    rest = &after[end + marker.len()..];
+                    continue;
+                }
+                _ => (),
+            }
+        }
+        string.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    string
+}
@@ -5,13 +5,14 @@
 
 use graph::{Container, UniqueWidgetState};
 use position::{
-    Align, Depth, Dimension, Dimensions, Padding, Point, Position, Positionable, Rect, Relative,
-    Sizeable,
+    Align, Axis, Depth, Dimension, Dimensions, Padding, Point, Position, Positionable, Rect,
+    Relative, Scalar, Sizeable,
 };
 use std;
 use text::font;
 use theme::{self, Theme};
 use ui::{self, Ui, UiCell};
+use utils;
 
 pub use self::id::Id;
 
@@ -28,26 +29,40 @@ pub use self::primitive::text::{self, Text};
 pub use self::bordered_rectangle::BorderedRectangle;
 pub use self::button::Button;
 pub use self::canvas::Canvas;
+pub use self::circular_progress::CircularProgress;
+pub use self::clip::Clip;
 pub use self::collapsible_area::CollapsibleArea;
+pub use self::crop_rect::CropRect;
 pub use self::drop_down_list::DropDownList;
 pub use self::envelope_editor::EnvelopeEditor;
 pub use self::file_navigator::FileNavigator;
+pub use self::filter_list::FilterList;
 pub use self::graph::Graph;
 pub use self::grid::Grid;
+pub use self::grid_layout::GridLayout;
 pub use self::list::List;
 pub use self::list_select::ListSelect;
+pub use self::markdown::Markdown;
 pub use self::matrix::Matrix;
 pub use self::number_dialer::NumberDialer;
 pub use self::plot_path::PlotPath;
+pub use self::progress_bar::ProgressBar;
+pub use self::property_grid::PropertyGrid;
 pub use self::range_slider::RangeSlider;
 pub use self::rounded_rectangle::RoundedRectangle;
 pub use self::scrollbar::Scrollbar;
+pub use self::scrubber::Scrubber;
 pub use self::slider::Slider;
+pub use self::snap_guides::SnapGuides;
+pub use self::splitter::Splitter;
+pub use self::table::Table;
 pub use self::tabs::Tabs;
 pub use self::text_box::TextBox;
 pub use self::text_edit::TextEdit;
 pub use self::title_bar::TitleBar;
 pub use self::toggle::Toggle;
+pub use self::viewport::Viewport;
+pub use self::virtual_keyboard::VirtualKeyboard;
 pub use self::xy_pad::XYPad;
 
 // Macro providing modules.
@@ -67,26 +82,40 @@ pub mod primitive;
 pub mod bordered_rectangle;
 pub mod button;
 pub mod canvas;
+pub mod circular_progress;
+pub mod clip;
 pub mod collapsible_area;
+pub mod crop_rect;
 pub mod drop_down_list;
 pub mod envelope_editor;
 pub mod file_navigator;
+pub mod filter_list;
 pub mod graph;
 pub mod grid;
+pub mod grid_layout;
 pub mod list;
 pub mod list_select;
+pub mod markdown;
 pub mod matrix;
 pub mod number_dialer;
 pub mod plot_path;
+pub mod progress_bar;
+pub mod property_grid;
 pub mod range_slider;
 pub mod rounded_rectangle;
 pub mod scrollbar;
+pub mod scrubber;
 pub mod slider;
+pub mod snap_guides;
+pub mod splitter;
+pub mod table;
 pub mod tabs;
 pub mod text_box;
 pub mod text_edit;
 pub mod title_bar;
 pub mod toggle;
+pub mod viewport;
+pub mod virtual_keyboard;
 pub mod xy_pad;
 
 /// Arguments for the [**Widget::update**](./trait.Widget#method.update) method in a struct to
@@ -218,6 +247,9 @@ pub struct CommonBuilder {
     pub maybe_x_scroll: Option<scroll::Scroll>,
     /// Arguments to the scrolling of the widget's *y* axis.
     pub maybe_y_scroll: Option<scroll::Scroll>,
+    /// Whether or not scrolling of the widget's `KidArea` continues to decelerate over the
+    /// following frames once the scrolling input has ceased.
+    pub scroll_momentum: bool,
     /// Whether or not the **Widget** should be placed on the kid_area.
     ///
     /// If `true`, the **Widget** will be placed on the `kid_area` of the parent **Widget** if the
@@ -239,6 +271,17 @@ pub struct CommonBuilder {
     /// default.
     /// - Any **Graphic** child of *b* will be considered as a **Graphic** child of *a*.
     pub maybe_graphics_for: Option<Id>,
+    /// The opacity of the **Widget** and all of its descendants, where `1.0` is fully opaque and
+    /// `0.0` is fully transparent.
+    pub opacity: f32,
+    /// Whether or not the **Widget** accepts keyboard focus, overriding `Widget::accepts_focus`.
+    pub maybe_accepts_focus: Option<bool>,
+    /// The stable key under which the **Widget**'s state is saved via `Ui::save_state`.
+    pub maybe_state_key: Option<&'static str>,
+    /// The distance by which the **Widget**'s hit-test area extends beyond its `Rect`.
+    pub hit_padding: Scalar,
+    /// Whether or not the **Widget** lets the mouse and touch pass through to widgets beneath.
+    pub ignore_mouse: bool,
 }
 
 /// Styling and positioning data that is common between all widget types.
@@ -254,6 +297,16 @@ pub struct CommonStyle {
     pub maybe_y_position: Option<Position>,
     /// The rendering Depth of the Widget.
     pub maybe_depth: Option<Depth>,
+    /// The ratio of width over height maintained by the Widget.
+    pub maybe_aspect_ratio: Option<Scalar>,
+    /// The minimum width of a Widget.
+    pub maybe_min_width: Option<Scalar>,
+    /// The maximum width of a Widget.
+    pub maybe_max_width: Option<Scalar>,
+    /// The minimum height of a Widget.
+    pub maybe_min_height: Option<Scalar>,
+    /// The maximum height of a Widget.
+    pub maybe_max_height: Option<Scalar>,
 }
 
 /// A wrapper around a **Widget**'s unique **Widget::State**.
@@ -303,6 +356,8 @@ pub struct CommonState {
 pub struct PreUpdateCache {
     /// The **Widget**'s unique type identifier.
     pub type_id: std::any::TypeId,
+    /// The name of the **Widget**'s type.
+    pub type_name: &'static str,
     /// The **Widget**'s unique Id.
     pub id: Id,
     /// The **Widget**'s parent's unique index (if it has a parent).
@@ -334,6 +389,20 @@ pub struct PreUpdateCache {
     pub maybe_graphics_for: Option<Id>,
     /// A function describing whether or not a given point is over the widget.
     pub is_over: IsOverFn,
+    /// The opacity of the **Widget**, cascaded to its descendants.
+    pub opacity: f32,
+    /// Whether or not the **Widget** may receive keyboard focus via `Tab` navigation.
+    pub accepts_focus: bool,
+    /// Whether or not the **Widget**'s depth-wise descendants may receive keyboard focus.
+    pub kids_accept_focus: bool,
+    /// The stable key under which the **Widget**'s state is saved via `Ui::save_state`.
+    pub maybe_state_key: Option<&'static str>,
+    /// A function for saving the **Widget**'s unique state.
+    pub save_state: SaveStateFn,
+    /// The distance by which the **Widget**'s hit-test area extends beyond its `Rect`.
+    pub hit_padding: Scalar,
+    /// Whether or not the **Widget** lets the mouse and touch pass through to widgets beneath.
+    pub ignore_mouse: bool,
 }
 
 // **Widget** data to be cached after the **Widget::update** call in the **widget::set_widget**
@@ -382,9 +451,35 @@ impl From<Id> for IsOver {
 /// A function type used to determine whether or not a given point is over a widget.
 pub type IsOverFn = fn(&Container, Point, &Theme) -> IsOver;
 
+/// A function type used to save the state of the widget within the given `Container`.
+///
+/// See `Widget::save_state`.
+pub type SaveStateFn = fn(&Container) -> SavedState;
+
+/// The state of a single widget as saved via `Ui::save_state`.
+///
+/// With the `serde` feature enabled, `SavedState` (and in turn `SavedStates`) may be serialized,
+/// e.g. to persist a layout file between runs of an application.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedState {
+    /// The type of the widget whose state was saved, as given by `Widget::saved_state_type`.
+    ///
+    /// Saved state is only restored to widgets with the same `saved_state_type`.
+    pub widget_type: String,
+    /// The widget's unique state as produced by `Widget::save_state`, if the widget supports it.
+    pub state: Option<String>,
+    /// The offset of the widget's scrolled kid area along the *x* and *y* axes.
+    pub scroll_offset: [Scalar; 2],
+}
+
+/// The saved state of all persistent widgets, keyed by the stable key given to each via
+/// `Widget::persist_as`.
+pub type SavedStates = std::collections::BTreeMap<String, SavedState>;
+
 /// The default `IsOverFn` used if the `Widget::is_over` method is not overridden.
 pub fn is_over_rect(container: &Container, point: Point, _: &Theme) -> IsOver {
-    container.rect.is_over(point).into()
+    container.hit_rect().is_over(point).into()
 }
 
 /// The necessary bounds for a **Widget**'s associated **Style** type.
@@ -511,10 +606,12 @@ pub trait Common {
 /// - scroll_kids
 /// - scroll_kids_vertically
 /// - scroll_kids_horizontally
+/// - scroll_momentum
 /// - place_widget_on_kid_area
 /// - parent
 /// - no_parent
 /// - set
+/// - try_set
 pub trait Widget: Common + Sized {
     /// State to be stored within the `Ui`s widget cache.
     ///
@@ -676,6 +773,9 @@ pub trait Widget: Common + Sized {
 
     /// If the widget is draggable, implement this method and return the position and dimensions of
     /// the draggable space. The position should be relative to the center of the widget.
+    ///
+    /// While dragged, the widget is kept positioned such that the centre of this area remains
+    /// within the window.
     fn drag_area(&self, _dim: Dimensions, _style: &Self::Style, _theme: &Theme) -> Option<Rect> {
         None
     }
@@ -708,6 +808,44 @@ pub trait Widget: Common + Sized {
         is_over_rect
     }
 
+    /// Whether or not the widget may receive keyboard focus when the user navigates between
+    /// widgets with the `Tab` key.
+    ///
+    /// By default, widgets do not accept focus. Interactive widgets that respond to the keyboard
+    /// should override this to return `true`. This may be overridden for a single instance via the
+    /// `Widget::focusable` builder method.
+    fn accepts_focus(&self) -> bool {
+        false
+    }
+
+    /// Save the given unique **State** so that it may be persisted across sessions via
+    /// `Ui::save_state`.
+    ///
+    /// This is only called for widgets given a stable key via `Widget::persist_as`. By default,
+    /// no unique state is saved.
+    fn save_state(_state: &Self::State) -> Option<String> {
+        None
+    }
+
+    /// A stable name for the type of the widget, e.g. `"Tabs"`, used to ensure that state saved
+    /// via `Widget::save_state` is only restored to the same type of widget.
+    ///
+    /// Unlike `std::any::type_name`, this must not change between compiler versions so that saved
+    /// state may be kept across sessions. Widgets that override `save_state` should override this
+    /// too. By default this is empty, in which case only the scroll offset is shared with other
+    /// widgets that do not override it.
+    fn saved_state_type() -> &'static str {
+        ""
+    }
+
+    /// Restore the unique **State** from that previously produced by `Widget::save_state`.
+    ///
+    /// Returns `false` if the saved state is incompatible with the widget, in which case it is
+    /// ignored.
+    fn restore_state(_state: &mut Self::State, _saved: &str) -> bool {
+        false
+    }
+
     // None of the following methods should require overriding. Perhaps they should be split off
     // into a separate trait which is impl'ed for W: Widget to make this clearer?
     // Most of them would benefit by some sort of field inheritance as they are mainly just used to
@@ -762,6 +900,64 @@ pub trait Widget: Common + Sized {
         self
     }
 
+    /// Set the opacity of the widget, where `1.0` is fully opaque (the default) and `0.0` is fully
+    /// transparent.
+    ///
+    /// The opacity cascades to all of the widget's descendants. That is, the alpha of every
+    /// primitive produced by the widget or its children is multiplied by the product of the
+    /// opacities of all of its depth-wise parents. This is useful for fading whole panels in or
+    /// out.
+    ///
+    /// The opacity is clamped to the range `0.0..=1.0`. Nothing is drawn for a widget or its
+    /// descendants while its opacity is `0.0`.
+    fn opacity(mut self, opacity: f32) -> Self {
+        self.common_mut().opacity = opacity;
+        self
+    }
+
+    /// Persist the widget's state under the given stable key.
+    ///
+    /// The widget's unique state (see `Widget::save_state`) and scroll offset are included in
+    /// the `SavedStates` produced by `Ui::save_state` and are restored upon the widget's next
+    /// update following a call to `Ui::restore_state`.
+    fn persist_as(mut self, key: &'static str) -> Self {
+        self.common_mut().maybe_state_key = Some(key);
+        self
+    }
+
+    /// Extend the area within which the widget is hit by the mouse or touch by the given distance
+    /// beyond each of its edges, without affecting its visual `Rect`.
+    ///
+    /// This is useful for giving thin widgets (e.g. a `Scrollbar`) a larger touch target. The
+    /// padding applies to the default rectangular hit-test (see `Widget::is_over`).
+    fn hit_padding(mut self, padding: Scalar) -> Self {
+        self.common_mut().hit_padding = padding;
+        self
+    }
+
+    /// Specify whether or not the widget should let the mouse and touch pass through to the widgets
+    /// beneath it, e.g. for the transparent areas of a HUD overlay.
+    ///
+    /// Unlike a disabled widget, the widget is still drawn as usual. It simply may never be picked
+    /// as the widget under the mouse or touch, and neither may any of its graphic children.
+    fn ignore_mouse(mut self, ignore_mouse: bool) -> Self {
+        self.common_mut().ignore_mouse = ignore_mouse;
+        self
+    }
+
+    /// Specify whether or not the widget may receive keyboard focus via `Tab` navigation,
+    /// overriding the widget's default (see `Widget::accepts_focus`).
+    ///
+    /// Pressing `Tab` moves the keyboard capture to the next widget in depth order that accepts
+    /// focus, while `Shift+Tab` moves it to the previous one.
+    ///
+    /// Passing `false` also opts out all of the widget's depth-wise descendants, e.g. the
+    /// `TextEdit` within a `TextBox` or the items of a `DropDownList`.
+    fn focusable(mut self, accepts_focus: bool) -> Self {
+        self.common_mut().maybe_accepts_focus = Some(accepts_focus);
+        self
+    }
+
     /// Set whether or not the widget is floating (the default is `false`).
     /// A typical example of a floating widget would be a pop-up or alert window.
     ///
@@ -815,6 +1011,20 @@ pub trait Widget: Common + Sized {
         self.crop_kids()
     }
 
+    /// Set whether or not the scrolling of the widget's `KidArea` has momentum (the default is
+    /// false).
+    ///
+    /// With momentum, releasing a drag of the `KidArea` (e.g. via a `Scrollbar`) causes the scroll
+    /// offset to continue decelerating over the following frames until it comes to rest or
+    /// reaches the end of its scrollable range. Other scrolling, e.g. via the mouse wheel, stops
+    /// immediately.
+    ///
+    /// This has no effect unless the widget's `KidArea` is scrollable.
+    fn scroll_momentum(mut self, momentum: bool) -> Self {
+        self.common_mut().scroll_momentum = momentum;
+        self
+    }
+
     /// A builder method that "lifts" the **Widget** through the given `build` function.
     ///
     /// This method is solely for providing slight ergonomic improvement by helping to maintain
@@ -885,7 +1095,22 @@ pub trait Widget: Common + Sized {
     /// needs to be re-drawn.
     /// - The new State and Style will be cached within the `Ui`.
     fn set<'a, 'b>(self, id: Id, ui_cell: &'a mut UiCell<'b>) -> Self::Event {
-        set_widget(self, id, ui_cell)
+        set_widget(self, id, ui_cell, false).expect("`Widget::update` was not isolated")
+    }
+
+    /// Note: There should be no need to override this method.
+    ///
+    /// The same as `Widget::set`, but isolates any panic that occurs within the widget's
+    /// `Widget::update` method.
+    ///
+    /// If `update` panics, the `widget::Id` and type of the widget are logged to `stderr` and
+    /// `None` is returned. This allows the rest of the GUI to continue being set and drawn, which
+    /// can be useful when debugging third-party widgets.
+    ///
+    /// Note that the widget's state is cached as `update` left it when it panicked. Any changes
+    /// that `update` had already made to the state are kept, so the state may be inconsistent.
+    fn try_set<'a, 'b>(self, id: Id, ui_cell: &'a mut UiCell<'b>) -> Option<Self::Event> {
+        set_widget(self, id, ui_cell, true)
     }
 }
 
@@ -899,7 +1124,15 @@ pub trait Widget: Common + Sized {
 /// users have a clear, concise, purely functional `Widget` API. As a result, we try to keep this
 /// as verbosely annotated as possible. If anything is unclear, feel free to post an issue or PR
 /// with concerns/improvements to the github repo.
-fn set_widget<'a, 'b, W>(widget: W, id: Id, ui: &'a mut UiCell<'b>) -> W::Event
+///
+/// If `catch_panics` is `true`, any panic that occurs within `Widget::update` will be caught and
+/// `None` will be returned. Otherwise, this function always returns `Some`.
+fn set_widget<'a, 'b, W>(
+    widget: W,
+    id: Id,
+    ui: &'a mut UiCell<'b>,
+    catch_panics: bool,
+) -> Option<W::Event>
 where
     W: Widget,
 {
@@ -971,6 +1204,20 @@ where
     let new_style = widget.style();
     let depth = widget.get_depth();
     let dim = widget.get_wh(&ui).unwrap_or([0.0, 0.0]);
+    if maybe_prev_common.is_none() {
+        let style = &widget.common().style;
+        let is_fully_sized = style.maybe_x_dimension.is_some() && style.maybe_y_dimension.is_some();
+        if style.maybe_aspect_ratio.is_some() && is_fully_sized {
+            use std::io::Write;
+            writeln!(
+                std::io::stderr(),
+                "The widget at {:?} was given both a width and a height, so its aspect ratio is \
+                 ignored.",
+                id
+            )
+            .unwrap();
+        }
+    }
     let x_pos = widget.get_x_position(ui);
     let y_pos = widget.get_y_position(ui);
     let place_on_kid_area = widget.common().place_on_kid_area;
@@ -1001,9 +1248,15 @@ where
                                 }
 
                                 if let Some(dragged_from) = current_dragged_from {
+                                    // Keep the centre of the drag area within the window so that
+                                    // the widget may always be dragged back.
+                                    let window = Rect::from_xy_dim([0.0, 0.0], ui.window_dim());
+                                    let drag_xy = drag_area.xy();
+                                    let x = dragged_from[0] + drag.to[0] - drag.origin[0];
+                                    let y = dragged_from[1] + drag.to[1] - drag.origin[1];
                                     current_xy = [
-                                        dragged_from[0] + drag.to[0] - drag.origin[0],
-                                        dragged_from[1] + drag.to[1] - drag.origin[1],
+                                        window.x.clamp_value(x + drag_xy[0]) - drag_xy[0],
+                                        window.y.clamp_value(y + drag_xy[1]) - drag_xy[1],
                                     ];
                                 }
                             }
@@ -1129,6 +1382,52 @@ where
         }
     }
 
+    // Restore the scroll offset of any state saved for the widget that is pending restoration.
+    //
+    // This is applied prior to caching the scroll state so that the widget's children are
+    // positioned with the restored offset during this same update.
+    let maybe_saved = widget
+        .common()
+        .maybe_state_key
+        .and_then(|key| ui::take_saved_state(ui::ref_mut_from_ui_cell(ui), key))
+        .filter(|saved| saved.widget_type == W::saved_state_type());
+    if let Some(ref saved) = maybe_saved {
+        let is_first_set = maybe_prev_common.is_none();
+        if let Some(ref mut state) = maybe_x_scroll_state {
+            state.restore_offset(saved.scroll_offset[0], is_first_set);
+        }
+        if let Some(ref mut state) = maybe_y_scroll_state {
+            state.restore_offset(saved.scroll_offset[1], is_first_set);
+        }
+    }
+
+    // Step the momentum of each scrollable axis, either measuring the velocity of a drag that
+    // occurred since the last update or continuing to decelerate once the drag is released.
+    if widget.common().scroll_momentum {
+        let now = ui.now();
+        let is_held = ui.global_input().current.mouse.buttons.left().is_down();
+        let has_scrolled = ui.widget_input(id).scrolls().next().is_some();
+        let maybe_prev_x = maybe_prev_common
+            .as_ref()
+            .and_then(|p| p.maybe_x_scroll_state);
+        let maybe_prev_y = maybe_prev_common
+            .as_ref()
+            .and_then(|p| p.maybe_y_scroll_state);
+        if let Some(ref mut state) = maybe_x_scroll_state {
+            let prev_offset = maybe_prev_x.map_or(state.offset, |prev| prev.offset);
+            state.step_momentum(prev_offset, has_scrolled, is_held, now);
+        }
+        if let Some(ref mut state) = maybe_y_scroll_state {
+            let prev_offset = maybe_prev_y.map_or(state.offset, |prev| prev.offset);
+            state.step_momentum(prev_offset, has_scrolled, is_held, now);
+        }
+        let x_velocity = maybe_x_scroll_state.map_or(0.0, |s| s.velocity);
+        let y_velocity = maybe_y_scroll_state.map_or(0.0, |s| s.velocity);
+        if x_velocity != 0.0 || y_velocity != 0.0 {
+            ui.request_continuous_updates();
+        }
+    }
+
     // Determine whether or not this is the first time set has been called.
     // We'll use this to determine whether or not we need to draw for the first time.
     let is_first_set = maybe_prev_common.is_none();
@@ -1155,6 +1454,7 @@ where
             ui,
             PreUpdateCache {
                 type_id: type_id,
+                type_name: std::any::type_name::<W>(),
                 id: id,
                 maybe_parent_id: maybe_parent_id,
                 maybe_x_positioned_relatively_id: maybe_x_positioned_relatively_id,
@@ -1169,6 +1469,16 @@ where
                 maybe_x_scroll_state: maybe_x_scroll_state,
                 maybe_graphics_for: widget.common().maybe_graphics_for,
                 is_over: widget.is_over(),
+                opacity: utils::clamp(widget.common().opacity, 0.0, 1.0),
+                accepts_focus: widget
+                    .common()
+                    .maybe_accepts_focus
+                    .unwrap_or_else(|| widget.accepts_focus()),
+                kids_accept_focus: widget.common().maybe_accepts_focus != Some(false),
+                maybe_state_key: widget.common().maybe_state_key,
+                save_state: save_state::<W>,
+                hit_padding: widget.common().hit_padding,
+                ignore_mouse: widget.common().ignore_mouse,
             },
         );
     }
@@ -1191,15 +1501,22 @@ where
         // `init_state` method to construct some initial state.
         let mut unique_state =
             maybe_prev_unique_state.unwrap_or_else(|| widget.init_state(ui.widget_id_generator()));
+
+        // Restore any unique state saved for the widget.
+        let has_restored = match maybe_saved.as_ref().and_then(|saved| saved.state.as_ref()) {
+            Some(state) => W::restore_state(&mut unique_state, state),
+            None => false,
+        };
+
         let (has_updated, event) = {
             // A wrapper around the widget's unique state in order to keep track of whether or not it
             // has been updated during the `Widget::update` method.
             let mut state = State {
                 state: &mut unique_state,
-                has_updated: false,
+                has_updated: has_restored,
             };
 
-            let event = widget.update(UpdateArgs {
+            let args = UpdateArgs {
                 id: id,
                 maybe_parent_id: maybe_parent_id,
                 state: &mut state,
@@ -1207,7 +1524,28 @@ where
                 rect: rect,
                 style: &new_style,
                 ui: ui,
-            });
+            };
+
+            let event = if catch_panics {
+                use std::panic::{self, AssertUnwindSafe};
+                match panic::catch_unwind(AssertUnwindSafe(|| widget.update(args))) {
+                    Ok(event) => Some(event),
+                    Err(_) => {
+                        use std::io::Write;
+                        writeln!(
+                            std::io::stderr(),
+                            "A panic occurred within `Widget::update` for the widget at {:?} \
+                             of type `{}`. The widget has been skipped.",
+                            id,
+                            std::any::type_name::<W>(),
+                        )
+                        .unwrap();
+                        None
+                    }
+                }
+            } else {
+                Some(widget.update(args))
+            };
 
             (state.has_updated, event)
         };
@@ -1259,6 +1597,29 @@ where
     event
 }
 
+// Save the state of the widget of type `W` within the given `Container`.
+fn save_state<W>(container: &Container) -> SavedState
+where
+    W: Widget,
+{
+    let state = container
+        .unique_widget_state::<W>()
+        .and_then(|unique| W::save_state(&unique.state));
+    let scroll_offset = [
+        container
+            .maybe_x_scroll_state
+            .map_or(0.0, |scroll| scroll.offset),
+        container
+            .maybe_y_scroll_state
+            .map_or(0.0, |scroll| scroll.offset),
+    ];
+    SavedState {
+        widget_type: W::saved_state_type().to_string(),
+        state: state,
+        scroll_offset: scroll_offset,
+    }
+}
+
 impl<'a, T> State<'a, T> {
     /// Mutate the internal widget state and set a flag notifying us that there has been a mutation.
     ///
@@ -1294,7 +1655,13 @@ impl Default for CommonBuilder {
             is_floating: false,
             maybe_x_scroll: None,
             maybe_y_scroll: None,
+            scroll_momentum: false,
             crop_kids: false,
+            opacity: 1.0,
+            maybe_accepts_focus: None,
+            maybe_state_key: None,
+            hit_padding: 0.0,
+            ignore_mouse: false,
         }
     }
 }
@@ -1380,20 +1747,93 @@ where
     }
     /// We attempt to retrieve the `x` **Dimension** for the widget via the following:
     /// - Check for specified value at `maybe_x_dimension`
+    /// - Otherwise, if there is some `maybe_aspect_ratio` and a specified `maybe_y_dimension`,
+    ///   derive the width from the height.
     /// - Otherwise, use the default returned by **Widget::default_x_dimension**.
+    ///
+    /// The resolved width is then limited by `maybe_min_width` and `maybe_max_width`.
     fn get_x_dimension(&self, ui: &Ui) -> Dimension {
-        self.common()
-            .style
-            .maybe_x_dimension
-            .unwrap_or_else(|| self.default_x_dimension(ui))
+        let style = &self.common().style;
+        let x = match (style.maybe_x_dimension, style.maybe_aspect_ratio) {
+            (Some(x), _) => x,
+            (None, Some(w_over_h)) if style.maybe_y_dimension.is_some() => {
+                let h = self.get_h(ui).unwrap_or(0.0);
+                Dimension::Absolute(h * w_over_h)
+            }
+            (None, _) => self.default_x_dimension(ui),
+        };
+        limit_dimension(x, Axis::X, style.maybe_min_width, style.maybe_max_width, ui)
     }
     /// We attempt to retrieve the `y` **Dimension** for the widget via the following:
     /// - Check for specified value at `maybe_y_dimension`
+    /// - Otherwise, if there is some `maybe_aspect_ratio`, derive the height from the width.
     /// - Otherwise, use the default returned by **Widget::default_y_dimension**.
+    ///
+    /// The resolved height is then limited by `maybe_min_height` and `maybe_max_height`.
     fn get_y_dimension(&self, ui: &Ui) -> Dimension {
-        self.common()
-            .style
-            .maybe_y_dimension
-            .unwrap_or_else(|| self.default_y_dimension(ui))
+        let style = &self.common().style;
+        let y = match (style.maybe_y_dimension, style.maybe_aspect_ratio) {
+            (Some(y), _) => y,
+            (None, Some(w_over_h)) => {
+                let w = self.get_w(ui).unwrap_or(0.0);
+                Dimension::Absolute(w / w_over_h)
+            }
+            (None, None) => self.default_y_dimension(ui),
+        };
+        limit_dimension(
+            y,
+            Axis::Y,
+            style.maybe_min_height,
+            style.maybe_max_height,
+            ui,
+        )
+    }
+    fn aspect_ratio(mut self, w_over_h: Scalar) -> Self {
+        assert!(
+            w_over_h > 0.0 && w_over_h.is_finite(),
+            "the given aspect ratio was {:?} however it must be a finite number > 0",
+            w_over_h
+        );
+        self.common_mut().style.maybe_aspect_ratio = Some(w_over_h);
+        self
+    }
+    fn min_width(mut self, min: Scalar) -> Self {
+        self.common_mut().style.maybe_min_width = Some(min);
+        self
+    }
+    fn max_width(mut self, max: Scalar) -> Self {
+        self.common_mut().style.maybe_max_width = Some(max);
+        self
+    }
+    fn min_height(mut self, min: Scalar) -> Self {
+        self.common_mut().style.maybe_min_height = Some(min);
+        self
+    }
+    fn max_height(mut self, max: Scalar) -> Self {
+        self.common_mut().style.maybe_max_height = Some(max);
+        self
+    }
+}
+
+/// Limit the length of the given **Dimension** along the given axis to the given range.
+///
+/// The **Dimension** is only resolved to an absolute length if some limit is given.
+fn limit_dimension(
+    dim: Dimension,
+    axis: Axis,
+    maybe_min: Option<Scalar>,
+    maybe_max: Option<Scalar>,
+    ui: &Ui,
+) -> Dimension {
+    if maybe_min.is_none() && maybe_max.is_none() {
+        return dim;
+    }
+    match dim.length(axis, ui) {
+        Some(length) => {
+            let length = maybe_max.map_or(length, |max| length.min(max));
+            let length = maybe_min.map_or(length, |min| length.max(min));
+            Dimension::Absolute(length)
+        }
+        None => dim,
     }
 }
@@ -10,8 +10,10 @@ use {Borderable, Color, Colorable, FontSize, Labelable, Point, Positionable, Sca
 
 /// A widget for precision control over any digit within a value.
 ///
-/// The reaction is triggered when the value is updated or if the mouse button is released while
-/// the cursor is above the widget.
+/// Setting the dialer returns the new value whenever the user changes it.
+///
+/// Once clicked, the NumberDialer captures the keyboard and a value may be typed in directly. The
+/// typed value is committed with `Return` and discarded with `Escape`.
 #[derive(WidgetCommon_)]
 pub struct NumberDialer<'a, T> {
     #[conrod(common_builder)]
@@ -24,8 +26,30 @@ pub struct NumberDialer<'a, T> {
     style: Style,
     /// If true, will allow user input. If false, will disallow user inputs.
     enabled: bool,
+    /// The value to which the NumberDialer is bound, updated whenever the value changes.
+    maybe_binding: Option<&'a mut T>,
+    /// How dragging the mouse over the NumberDialer adjusts its value.
+    drag_mode: DragMode,
+}
+
+/// The way in which dragging the mouse adjusts the value of a **NumberDialer**.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DragMode {
+    /// Dragging vertically steps the pressed digit up or down by one.
+    PerDigit,
+    /// Dragging horizontally anywhere over the widget scrubs the value continuously.
+    ///
+    /// The change in value is proportional to the range between `min` and `max` and accelerates
+    /// with the distance dragged per update. For integer values, the fraction of a step scrubbed
+    /// so far is carried over to the following updates.
+    Continuous,
 }
 
+/// The fraction of the value range that is scrubbed per pixel of a slow `Continuous` drag.
+const SCRUB_SENSITIVITY: f64 = 0.001;
+/// The additional scaling applied to a `Continuous` drag per pixel of movement within one update.
+const SCRUB_ACCELERATION: f64 = 0.1;
+
 /// Unique graphical styling for the NumberDialer.
 #[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
 pub struct Style {
@@ -60,6 +84,10 @@ widget_ids! {
 pub struct State {
     /// The index of the value that is currently pressed.
     pressed_value_idx: Option<usize>,
+    /// The text typed so far while the value is being entered via the keyboard.
+    entry: Option<String>,
+    /// The portion of a `Continuous` scrub that could not yet be represented by the value's type.
+    scrub_remainder: f64,
     ids: Ids,
     glyph_slot_indices: Vec<GlyphSlot>,
 }
@@ -73,6 +101,12 @@ pub struct GlyphSlot {
     text_id: widget::Id,
 }
 
+/// Whether or not `T` may only represent whole numbers.
+fn is_integral<T: NumCast>() -> bool {
+    let half: Option<f64> = <T as NumCast>::from(0.5).and_then(|half| NumCast::from(half));
+    half.unwrap_or(0.0) == 0.0
+}
+
 /// Create the string to be drawn from the given values and precision.
 ///
 /// Combine this with the label string if one is given.
@@ -123,7 +157,7 @@ fn val_string_width(font_size: FontSize, val_string: &String) -> f64 {
 
 impl<'a, T> NumberDialer<'a, T>
 where
-    T: Float,
+    T: Copy + PartialOrd,
 {
     /// Construct a new NumberDialer widget.
     pub fn new(value: T, min: T, max: T, precision: u8) -> Self {
@@ -136,9 +170,21 @@ where
             precision: precision,
             maybe_label: None,
             enabled: true,
+            maybe_binding: None,
+            drag_mode: DragMode::PerDigit,
         }
     }
 
+    /// Construct a new NumberDialer widget bound to the given value.
+    ///
+    /// The NumberDialer displays the current `value`, which is updated in place whenever the user
+    /// changes the value of the NumberDialer. The new value is still yielded as its event.
+    pub fn bind(value: &'a mut T, min: T, max: T, precision: u8) -> Self {
+        let mut number_dialer = NumberDialer::new(*value, min, max, precision);
+        number_dialer.maybe_binding = Some(value);
+        number_dialer
+    }
+
     /// Specify the font used for displaying the label.
     pub fn font_id(mut self, font_id: text::font::Id) -> Self {
         self.style.font_id = Some(Some(font_id));
@@ -147,12 +193,13 @@ where
 
     builder_methods! {
         pub enabled { enabled = bool }
+        pub drag_mode { drag_mode = DragMode }
     }
 }
 
 impl<'a, T> Widget for NumberDialer<'a, T>
 where
-    T: Float + NumCast + ToString,
+    T: Copy + PartialOrd + NumCast + ToString,
 {
     type State = State;
     type Style = Style;
@@ -161,6 +208,8 @@ where
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
         State {
             pressed_value_idx: None,
+            entry: None,
+            scrub_remainder: 0.0,
             ids: Ids::new(id_gen),
             glyph_slot_indices: Vec::new(),
         }
@@ -170,6 +219,10 @@ where
         self.style.clone()
     }
 
+    fn accepts_focus(&self) -> bool {
+        self.enabled
+    }
+
     /// Update the state of the NumberDialer.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
@@ -186,6 +239,8 @@ where
             max,
             precision,
             maybe_label,
+            maybe_binding,
+            drag_mode,
             ..
         } = self;
 
@@ -214,7 +269,59 @@ where
             precision as usize + 1
         };
         let val_string_len = max.to_string().len() + precision_len;
-        let val_string = create_val_string(value, val_string_len, precision);
+        let mut entry = state.entry.clone();
+        let mut new_value = value;
+
+        // Build up the typed entry from the keyboard, committing it with `Return`.
+        for widget_event in ui.widget_input(id).events() {
+            use event;
+            use input::{self, Key};
+
+            match widget_event {
+                event::Widget::Text(text) => {
+                    for ch in text.string.chars() {
+                        if !ch.is_ascii_digit() && ch != '.' && ch != '-' {
+                            continue;
+                        }
+                        let entry = entry.get_or_insert_with(String::new);
+                        if entry.len() < val_string_len {
+                            entry.push(ch);
+                        }
+                    }
+                }
+
+                event::Widget::Press(press) => match press.button {
+                    event::Button::Keyboard(Key::Backspace) => {
+                        if let Some(ref mut entry) = entry {
+                            entry.pop();
+                        }
+                    }
+                    event::Button::Keyboard(Key::Return)
+                    | event::Button::Keyboard(Key::NumPadEnter) => {
+                        let typed = entry.take().and_then(|entry| entry.parse::<f64>().ok());
+                        if let Some(typed) = typed {
+                            let min_f: f64 = NumCast::from(min).unwrap();
+                            let max_f: f64 = NumCast::from(max).unwrap();
+                            let scale = 10.0f64.powi(precision as i32);
+                            let rounded = (typed * scale).round() / scale;
+                            new_value = NumCast::from(clamp(rounded, min_f, max_f)).unwrap();
+                        }
+                    }
+                    event::Button::Keyboard(Key::Escape) => entry = None,
+                    _ => (),
+                },
+
+                // Discard the entry if the keyboard is captured by some other widget.
+                event::Widget::UncapturesInputSource(input::Source::Keyboard) => entry = None,
+
+                _ => (),
+            }
+        }
+
+        let val_string = match entry {
+            Some(ref entry) => entry.clone(),
+            None => create_val_string(new_value, val_string_len, precision),
+        };
         let val_string_dim = [val_string_width(font_size, &val_string), font_size as f64];
         let label_rel_x = -val_string_dim[0] / 2.0;
         let slot_w = value_glyph_slot_width(val_string_dim[1] as u32);
@@ -252,7 +359,8 @@ where
             .mouse()
             .and_then(|m| value_under_rel_xy(m.rel_xy()));
         let mut pressed_value_idx = state.pressed_value_idx;
-        let mut new_value = value;
+        let mut scrub_remainder = state.scrub_remainder;
+        let mut scrub_dx = 0.0;
 
         // Check for the following events:
         // - If a value has been `Press`ed and is being dragged.
@@ -274,11 +382,23 @@ where
                 event::Widget::Release(release) => {
                     if let event::Button::Mouse(MouseButton::Left, _) = release.button {
                         pressed_value_idx = None;
+                        scrub_remainder = 0.0;
                     }
                 }
 
+                // In `Continuous` mode, a horizontal left `Drag` scrubs the whole value.
+                event::Widget::Drag(drag)
+                    if drag.button == input::MouseButton::Left
+                        && drag_mode == DragMode::Continuous
+                        && entry.is_none() =>
+                {
+                    scrub_dx += drag.delta_xy[0];
+                }
+
                 // A left `Drag` moves the `pressed_point` if there is one.
-                event::Widget::Drag(drag) if drag.button == input::MouseButton::Left => {
+                event::Widget::Drag(drag)
+                    if drag.button == input::MouseButton::Left && entry.is_none() =>
+                {
                     if let Some(idx) = pressed_value_idx {
                         let decimal_pos = val_string.chars().position(|ch| ch == '.');
                         let val_f: f64 = NumCast::from(value).unwrap();
@@ -332,8 +452,29 @@ where
             }
         }
 
+        // Scrub by the total distance dragged during this update, so that the acceleration does not
+        // depend on how the drag was divided between events.
+        if scrub_dx != 0.0 {
+            let val_f: f64 = NumCast::from(new_value).unwrap();
+            let min_f: f64 = NumCast::from(min).unwrap();
+            let max_f: f64 = NumCast::from(max).unwrap();
+            let acceleration = 1.0 + scrub_dx.abs() * SCRUB_ACCELERATION;
+            let delta = scrub_dx * acceleration * (max_f - min_f) * SCRUB_SENSITIVITY;
+            let scrubbed = clamp(val_f + scrub_remainder + delta, min_f, max_f);
+            let rounded = if is_integral::<T>() {
+                scrubbed.round()
+            } else {
+                scrubbed
+            };
+            new_value = NumCast::from(rounded).unwrap();
+            scrub_remainder = scrubbed - rounded;
+        }
+
         // If the value has changed produce an event.
         let event = if value != new_value {
+            if let Some(binding) = maybe_binding {
+                *binding = new_value;
+            }
             Some(new_value)
         } else {
             None
@@ -343,6 +484,14 @@ where
             state.update(|state| state.pressed_value_idx = pressed_value_idx);
         }
 
+        if state.entry != entry {
+            state.update(|state| state.entry = entry);
+        }
+
+        if state.scrub_remainder != scrub_remainder {
+            state.update(|state| state.scrub_remainder = scrub_remainder);
+        }
+
         // The **Rectangle** backdrop widget.
         let color = style.color(ui.theme());
         let border = style.border(ui.theme());
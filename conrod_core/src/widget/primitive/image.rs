@@ -1,10 +1,16 @@
 //! A simple, non-interactive widget for drawing an `Image`.
 
 use image;
-use position::{Dimension, Rect};
+use position::{Dimension, Rect, Scalar};
 use widget;
 use {Color, Ui, Widget};
 
+/// The maximum number of tiles drawn for a `tiled` `Image`.
+///
+/// Should more tiles be required to fill the widget's `Rect`, each tile is enlarged by a power of
+/// two until they fit within this limit.
+pub const MAX_TILES: usize = 4096;
+
 /// A primitive and basic widget for drawing an `Image`.
 #[derive(Copy, Clone, WidgetCommon_)]
 pub struct Image {
@@ -15,6 +21,12 @@ pub struct Image {
     pub image_id: image::Id,
     /// The rectangle area of the original source image that should be used.
     pub src_rect: Option<Rect>,
+    /// The anticlockwise rotation of the image about its middle in radians.
+    pub rotation: Scalar,
+    /// The left, right, top and bottom insets of the image if it is drawn as a nine-slice.
+    pub nine_slice: Option<[Scalar; 4]>,
+    /// Whether or not the image is repeated to fill the widget's `Rect`.
+    pub tiled: bool,
     /// Unique styling.
     pub style: Style,
 }
@@ -28,6 +40,12 @@ pub struct State {
     pub src_rect: Option<Rect>,
     /// The unique identifier for the image's associated data that will be drawn.
     pub image_id: image::Id,
+    /// The anticlockwise rotation of the image about its middle in radians.
+    pub rotation: Scalar,
+    /// The left, right, top and bottom insets of the image if it is drawn as a nine-slice.
+    pub nine_slice: Option<[Scalar; 4]>,
+    /// Whether or not the image is repeated to fill the widget's `Rect`.
+    pub tiled: bool,
 }
 
 /// Unique styling for the `Image` widget.
@@ -68,6 +86,9 @@ impl Image {
             common: widget::CommonBuilder::default(),
             image_id: image_id,
             src_rect: None,
+            rotation: 0.0,
+            nine_slice: None,
+            tiled: false,
             style: Style::default(),
         }
     }
@@ -80,6 +101,50 @@ impl Image {
         self
     }
 
+    /// Rotate the image anticlockwise about its middle by the given angle in radians, e.g. for the
+    /// needle of a compass or gauge.
+    ///
+    /// The image is drawn rotated within its unrotated `Rect`, which is still used for layout,
+    /// picking and culling. Parts of the rotated image extending beyond the `Rect` may therefore
+    /// not be drawn if the `Rect` itself is out of view or cropped.
+    pub fn rotation(mut self, radians: Scalar) -> Self {
+        self.rotation = radians;
+        self
+    }
+
+    /// Draw the image as a nine-slice (or "9-patch") with the given left, right, top and bottom
+    /// insets in pixels of the source image, e.g. so that a single button skin may be scaled to
+    /// any size without distorting its corners.
+    ///
+    /// The insets divide both the `source_rectangle` and the widget's `Rect` into a grid of nine
+    /// slices. The corners are drawn at their original size, the edges are stretched along one
+    /// axis and the centre is stretched along both. Insets that exceed either rectangle are scaled
+    /// down to fit.
+    ///
+    /// As the dimensions of the images within an `image::Map` are unknown to the widget, a
+    /// `source_rectangle` must also be given, e.g. one covering the whole image. Otherwise the
+    /// image is drawn unsliced. A nine-slice image is never rotated.
+    pub fn nine_slice(mut self, insets: [Scalar; 4]) -> Self {
+        self.nine_slice = Some(insets);
+        self
+    }
+
+    /// Whether or not the image should be repeated to fill the widget's `Rect` rather than
+    /// stretched, e.g. for a textured background.
+    ///
+    /// Each tile takes its size from the `source_rectangle`, so that one pixel of the source image
+    /// covers one unit of the widget's `Rect`. Tiles begin at the top left of the `Rect`, with
+    /// those of the final row and column cropped to fit. A `source_rectangle` must be given,
+    /// otherwise the image is stretched as usual. Nothing is drawn for a zero-sized
+    /// `source_rectangle`. A tiled image is never rotated, and `nine_slice` takes precedence.
+    ///
+    /// At most `MAX_TILES` tiles are drawn, so a small `source_rectangle` within a large `Rect`
+    /// has its tiles enlarged rather than producing an unbounded number of primitives.
+    pub fn tiled(mut self, tiled: bool) -> Self {
+        self.tiled = tiled;
+        self
+    }
+
     builder_methods! {
         pub color { style.maybe_color = Some(Option<Color>) }
     }
@@ -94,6 +159,9 @@ impl Widget for Image {
         State {
             src_rect: None,
             image_id: self.image_id,
+            rotation: 0.0,
+            nine_slice: None,
+            tiled: false,
         }
     }
 
@@ -118,7 +186,12 @@ impl Widget for Image {
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs { state, .. } = args;
         let Image {
-            image_id, src_rect, ..
+            image_id,
+            src_rect,
+            rotation,
+            nine_slice,
+            tiled,
+            ..
         } = self;
 
         if state.image_id != image_id {
@@ -127,5 +200,14 @@ impl Widget for Image {
         if state.src_rect != src_rect {
             state.update(|state| state.src_rect = src_rect);
         }
+        if state.rotation != rotation {
+            state.update(|state| state.rotation = rotation);
+        }
+        if state.nine_slice != nine_slice {
+            state.update(|state| state.nine_slice = nine_slice);
+        }
+        if state.tiled != tiled {
+            state.update(|state| state.tiled = tiled);
+        }
     }
 }
@@ -19,6 +19,9 @@ pub struct Oval<S> {
     pub resolution: usize,
     /// A type describing the section of the `Oval` that is to be drawn.
     pub section: S,
+    /// If `Some`, a filled `Oval` blends from its fill color at the center to this color at the
+    /// edge.
+    pub maybe_edge_color: Option<Color>,
 }
 
 /// Types that may be used to describe the visible section of the `Oval`.
@@ -61,6 +64,8 @@ pub struct State<S> {
     pub resolution: usize,
     /// A type describing the section of the `Oval` that is to be drawn.
     pub section: S,
+    /// The color at the edge of a gradient filled `Oval`.
+    pub maybe_edge_color: Option<Color>,
 }
 
 /// The default circle resolution if none is specified.
@@ -74,6 +79,7 @@ impl Oval<Full> {
             style: style,
             resolution: DEFAULT_RESOLUTION,
             section: Full,
+            maybe_edge_color: None,
         }
         .wh(dim)
     }
@@ -88,6 +94,12 @@ impl Oval<Full> {
         Oval::styled(dim, Style::fill_with(color))
     }
 
+    /// Build a new **Oval** **Fill**ed with a gradient that blends from the `center_color` at the
+    /// middle of the oval to the `edge_color` at its circumference.
+    pub fn gradient(dim: Dimensions, center_color: Color, edge_color: Color) -> Self {
+        Oval::fill_with(dim, center_color).edge_color(edge_color)
+    }
+
     /// Build a new **Outline**d **Oval** widget.
     pub fn outline(dim: Dimensions) -> Self {
         Oval::styled(dim, Style::outline())
@@ -108,6 +120,15 @@ impl<S> Oval<S> {
         self
     }
 
+    /// Blend the fill from the fill color at the center of the oval to the given color at its
+    /// edge.
+    ///
+    /// Has no effect on an **Outline**d **Oval**.
+    pub fn edge_color(mut self, color: Color) -> Self {
+        self.maybe_edge_color = Some(color);
+        self
+    }
+
     /// Produces an `Oval` where only a section is drawn.
     ///
     /// The given `radians` describes the angle occuppied by the section's circumference.
@@ -116,6 +137,7 @@ impl<S> Oval<S> {
             common,
             style,
             resolution,
+            maybe_edge_color,
             ..
         } = self;
         let section = Section {
@@ -127,6 +149,7 @@ impl<S> Oval<S> {
             style,
             resolution,
             section,
+            maybe_edge_color,
         }
     }
 }
@@ -151,8 +174,9 @@ where
 
     fn init_state(&self, _: widget::id::Generator) -> Self::State {
         State {
-            resolution: DEFAULT_RESOLUTION,
+            resolution: self.resolution,
             section: self.section,
+            maybe_edge_color: self.maybe_edge_color,
         }
     }
 
@@ -172,6 +196,9 @@ where
         if state.section != self.section {
             state.update(|state| state.section = self.section);
         }
+        if state.maybe_edge_color != self.maybe_edge_color {
+            state.update(|state| state.maybe_edge_color = self.maybe_edge_color);
+        }
     }
 }
 
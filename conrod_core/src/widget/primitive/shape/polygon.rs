@@ -5,7 +5,7 @@ use graph;
 use utils::{bounding_box_for_points, vec2_add, vec2_sub};
 use widget;
 use widget::triangles::Triangle;
-use {Color, Colorable, Point, Positionable, Sizeable, Theme, Widget};
+use {Color, Colorable, Point, Positionable, Rect, Sizeable, Theme, Widget};
 
 /// A basic, non-interactive, arbitrary **Polygon** widget.
 ///
@@ -191,6 +191,23 @@ impl<I> Polygon<I> {
     {
         Polygon::centred_styled(points, Style::outline_styled(style))
     }
+
+    /// Specify the bounding rectangle for the **Polygon**, setting its position and size.
+    ///
+    /// Typically, the given `Rect` bounds should be the min and max positions along both axes that
+    /// are touched by the **Polygon**'s points.
+    ///
+    /// This is significantly more efficient than the `abs` and `centred` constructors as the
+    /// bounding rectangle does not have to be calculated from the **Polygon**'s points. This is
+    /// useful for streaming geometry whose points change every frame while its bounds are already
+    /// known.
+    pub fn with_bounding_rect(self, rect: Rect) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let (xy, dim) = rect.xy_dim();
+        self.wh(dim).xy(xy)
+    }
 }
 
 impl<I> Widget for Polygon<I>
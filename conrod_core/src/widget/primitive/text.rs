@@ -1,6 +1,7 @@
 //! The primitive widget used for displaying text.
 
-use position::{Dimension, Scalar};
+pub use position::Orientation;
+use position::{Align, Dimension, Point, Rect, Scalar};
 use std;
 use text;
 use utils;
@@ -42,9 +43,15 @@ pub struct Style {
     /// Alignment of the text along the *x* axis.
     #[conrod(default = "text::Justify::Left")]
     pub justify: Option<text::Justify>,
+    /// Alignment of the block of text along the *y* axis of its bounding **Rect**.
+    #[conrod(default = "Align::End")]
+    pub y_align: Option<Align>,
     /// The id of the font to use for rendering and layout.
     #[conrod(default = "theme.font_id")]
     pub font_id: Option<Option<text::font::Id>>,
+    /// The direction in which the lines of text run.
+    #[conrod(default = "Orientation::Horizontal")]
+    pub orientation: Option<Orientation>,
     // /// The line styling for the text.
     // #[conrod(default = "None")]
     // pub line: Option<Option<Line>>,
@@ -59,6 +66,31 @@ pub enum Wrap {
     Whitespace,
 }
 
+/// The **Rect** within which the lines of text with the given `orientation` are laid out before
+/// being rotated to fill the given `rect`.
+///
+/// `Horizontal` lines run from left to right, while `Vertical` lines run from bottom to top as
+/// though horizontal text were rotated a quarter turn anticlockwise. For `Vertical` text, this is
+/// the `rect` with its width and height swapped.
+pub fn layout_rect(orientation: Orientation, rect: Rect) -> Rect {
+    match orientation {
+        Orientation::Horizontal => rect,
+        Orientation::Vertical => Rect::from_xy_dim(rect.xy(), [rect.h(), rect.w()]),
+    }
+}
+
+/// Map the given point over the `rect` to the point over the `layout_rect` at which it would lie
+/// before the text with the given `orientation` was rotated.
+pub fn unrotate_point(orientation: Orientation, rect: Rect, point: Point) -> Point {
+    match orientation {
+        Orientation::Horizontal => point,
+        Orientation::Vertical => {
+            let [x, y] = rect.xy();
+            [x + (point[1] - y), y - (point[0] - x)]
+        }
+    }
+}
+
 // /// Line styling for the **Text**.
 // pub enum Line {
 //     /// Underline the text.
@@ -133,34 +165,38 @@ impl<'a> Text<'a> {
         self.justify(text::Justify::Right)
     }
 
-    builder_methods! {
-        pub font_size { style.font_size = Some(FontSize) }
-        pub justify { style.justify = Some(text::Justify) }
-        pub line_spacing { style.line_spacing = Some(Scalar) }
+    /// Align the block of text to the bottom of its bounding **Rect**'s *y* axis range.
+    pub fn align_text_bottom(self) -> Self {
+        self.align_text_y(Align::Start)
     }
-}
 
-impl<'a> Widget for Text<'a> {
-    type State = State;
-    type Style = Style;
-    type Event = ();
+    /// Align the block of text to the middle of its bounding **Rect**'s *y* axis range.
+    pub fn align_text_y_middle(self) -> Self {
+        self.align_text_y(Align::Middle)
+    }
 
-    fn init_state(&self, _: widget::id::Generator) -> Self::State {
-        State {
-            string: String::new(),
-            line_infos: Vec::new(),
-        }
+    /// Align the block of text to the top of its bounding **Rect**'s *y* axis range.
+    ///
+    /// This is the default.
+    pub fn align_text_top(self) -> Self {
+        self.align_text_y(Align::End)
     }
 
-    fn style(&self) -> Self::Style {
-        self.style.clone()
+    /// Align the block of text to the middle of its bounding **Rect**.
+    pub fn align_text_middle(self) -> Self {
+        self.center_justify().align_text_y_middle()
     }
 
-    /// If no specific width was given, we'll use the width of the widest line as a default.
-    ///
-    /// The `Font` used by the `Text` is retrieved in order to determine the width of each line. If
-    /// the font used by the `Text` cannot be found, a dimension of `Absolute(0.0)` is returned.
-    fn default_x_dimension(&self, ui: &Ui) -> Dimension {
+    builder_methods! {
+        pub font_size { style.font_size = Some(FontSize) }
+        pub justify { style.justify = Some(text::Justify) }
+        pub align_text_y { style.y_align = Some(Align) }
+        pub line_spacing { style.line_spacing = Some(Scalar) }
+        pub orientation { style.orientation = Some(Orientation) }
+    }
+
+    /// The width of the widest line of text, or `0.0` if the font cannot be found.
+    fn widest_line_width(&self, ui: &Ui) -> Scalar {
         let font = match self
             .style
             .font_id(&ui.theme)
@@ -168,7 +204,7 @@ impl<'a> Widget for Text<'a> {
             .and_then(|id| ui.fonts.get(id))
         {
             Some(font) => font,
-            None => return Dimension::Absolute(0.0),
+            None => return 0.0,
         };
 
         let font_size = self.style.font_size(&ui.theme);
@@ -177,16 +213,12 @@ impl<'a> Widget for Text<'a> {
             let width = text::line::width(line, font, font_size);
             max_width = utils::partial_max(max_width, width);
         }
-        Dimension::Absolute(max_width)
+        max_width
     }
 
-    /// If no specific height was given, we'll use the total height of the text as a default.
-    ///
-    /// The `Font` used by the `Text` is retrieved in order to determine the width of each line. If
-    /// the font used by the `Text` cannot be found, a dimension of `Absolute(0.0)` is returned.
-    fn default_y_dimension(&self, ui: &Ui) -> Dimension {
-        use position::Sizeable;
-
+    /// The height of all lines of text when wrapped to the given `max_width`, or `0.0` if the font
+    /// cannot be found.
+    fn text_height(&self, ui: &Ui, max_width: Option<Scalar>) -> Scalar {
         let font = match self
             .style
             .font_id(&ui.theme)
@@ -194,14 +226,14 @@ impl<'a> Widget for Text<'a> {
             .and_then(|id| ui.fonts.get(id))
         {
             Some(font) => font,
-            None => return Dimension::Absolute(0.0),
+            None => return 0.0,
         };
 
         let text = &self.text;
         let font_size = self.style.font_size(&ui.theme);
         let num_lines = match self.style.maybe_wrap(&ui.theme) {
             None => text.lines().count(),
-            Some(wrap) => match self.get_w(ui) {
+            Some(wrap) => match max_width {
                 None => text.lines().count(),
                 Some(max_w) => match wrap {
                     Wrap::Character => text::line::infos(text, font, font_size)
@@ -214,13 +246,61 @@ impl<'a> Widget for Text<'a> {
             },
         };
         let line_spacing = self.style.line_spacing(&ui.theme);
-        let height = text::height(std::cmp::max(num_lines, 1), font_size, line_spacing);
+        text::height(std::cmp::max(num_lines, 1), font_size, line_spacing)
+    }
+}
+
+impl<'a> Widget for Text<'a> {
+    type State = State;
+    type Style = Style;
+    /// The byte index of the character that was clicked with the left mouse button, if any.
+    type Event = Option<usize>;
+
+    fn init_state(&self, _: widget::id::Generator) -> Self::State {
+        State {
+            string: String::new(),
+            line_infos: Vec::new(),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// If no specific width was given, we'll use the width of the widest line as a default.
+    ///
+    /// The `Font` used by the `Text` is retrieved in order to determine the width of each line. If
+    /// the font used by the `Text` cannot be found, a dimension of `Absolute(0.0)` is returned.
+    ///
+    /// For `Vertical` text, the total height of the text is used instead.
+    fn default_x_dimension(&self, ui: &Ui) -> Dimension {
+        use position::Sizeable;
+        let width = match self.style.orientation(&ui.theme) {
+            Orientation::Horizontal => self.widest_line_width(ui),
+            Orientation::Vertical => self.text_height(ui, self.get_h(ui)),
+        };
+        Dimension::Absolute(width)
+    }
+
+    /// If no specific height was given, we'll use the total height of the text as a default.
+    ///
+    /// The `Font` used by the `Text` is retrieved in order to determine the width of each line. If
+    /// the font used by the `Text` cannot be found, a dimension of `Absolute(0.0)` is returned.
+    ///
+    /// For `Vertical` text, the width of the widest line is used instead.
+    fn default_y_dimension(&self, ui: &Ui) -> Dimension {
+        use position::Sizeable;
+        let height = match self.style.orientation(&ui.theme) {
+            Orientation::Horizontal => self.text_height(ui, self.get_w(ui)),
+            Orientation::Vertical => self.widest_line_width(ui),
+        };
         Dimension::Absolute(height)
     }
 
     /// Update the state of the Text.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
+            id,
             rect,
             state,
             style,
@@ -231,24 +311,22 @@ impl<'a> Widget for Text<'a> {
 
         let maybe_wrap = style.maybe_wrap(ui.theme());
         let font_size = style.font_size(ui.theme());
+        let orientation = style.orientation(ui.theme());
+        let layout_rect = layout_rect(orientation, rect);
 
-        let font = match style
+        let font = style
             .font_id(&ui.theme)
             .or(ui.fonts.ids().next())
-            .and_then(|id| ui.fonts.get(id))
-        {
-            Some(font) => font,
-            None => return,
-        };
+            .and_then(|id| ui.fonts.get(id))?;
 
         // Produces an iterator yielding info for each line within the `text`.
         let new_line_infos = || match maybe_wrap {
             None => text::line::infos(text, font, font_size),
             Some(Wrap::Character) => {
-                text::line::infos(text, font, font_size).wrap_by_character(rect.w())
+                text::line::infos(text, font, font_size).wrap_by_character(layout_rect.w())
             }
             Some(Wrap::Whitespace) => {
-                text::line::infos(text, font, font_size).wrap_by_whitespace(rect.w())
+                text::line::infos(text, font, font_size).wrap_by_whitespace(layout_rect.w())
             }
         };
 
@@ -277,7 +355,71 @@ impl<'a> Widget for Text<'a> {
                 state.update(|state| state.line_infos = new_line_infos);
             }
         }
+
+        // Find the character under the most recent left click.
+        let clicked_xy = ui
+            .widget_input(id)
+            .clicks()
+            .left()
+            .last()
+            .map(|click| [click.xy[0] + rect.x(), click.xy[1] + rect.y()])
+            .map(|xy| unrotate_point(orientation, rect, xy));
+        clicked_xy.and_then(|xy| {
+            let layout = Layout {
+                font_size: font_size,
+                justify: style.justify(&ui.theme),
+                y_align: style.y_align(&ui.theme),
+                line_spacing: style.line_spacing(&ui.theme),
+                rect: layout_rect,
+            };
+            char_byte_index_at(xy, text, &state.line_infos, font, layout)
+        })
+    }
+}
+
+// The parameters used to lay out the lines of a `Text` within its `Rect`.
+struct Layout {
+    font_size: FontSize,
+    justify: text::Justify,
+    y_align: Align,
+    line_spacing: Scalar,
+    rect: Rect,
+}
+
+// The byte index of the character whose glyph lies under the given absolute `xy` position.
+//
+// Returns `None` if the position lies between lines or beyond the end of a line.
+fn char_byte_index_at(
+    xy: Point,
+    text: &str,
+    line_infos: &[text::line::Info],
+    font: &text::Font,
+    layout: Layout,
+) -> Option<usize> {
+    let xys_per_line = text::cursor::xys_per_line_from_text(
+        text,
+        line_infos,
+        font,
+        layout.font_size,
+        layout.justify,
+        layout.y_align,
+        layout.line_spacing,
+        layout.rect,
+    );
+    for (info, (xs, y)) in line_infos.iter().zip(xys_per_line) {
+        if !y.is_over(xy[1]) {
+            continue;
+        }
+        // Each pair of consecutive cursor positions bounds a single character.
+        let xs: Vec<Scalar> = xs.collect();
+        let line = &text[info.byte_range()];
+        return xs
+            .windows(2)
+            .zip(line.char_indices())
+            .find(|&(edges, _)| edges[0] <= xy[0] && xy[0] < edges[1])
+            .map(|(_, (byte, _))| info.start_byte + byte);
     }
+    None
 }
 
 impl<'a> Colorable for Text<'a> {
@@ -0,0 +1,199 @@
+//! A read-only widget for displaying the progress of some task.
+
+pub use position::Orientation;
+use position::{Range, Rect, Scalar};
+use text;
+use utils::clamp;
+use widget;
+use {Borderable, Color, Colorable, FontSize, Positionable, Widget};
+
+/// Displays some `value` out of a `max` as a partially filled bar.
+///
+/// If the bar's width is greater than its height, it fills from left to right, otherwise it fills
+/// from bottom to top. This may be overridden via the `orientation` builder method.
+#[derive(WidgetCommon_)]
+pub struct ProgressBar {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    value: f64,
+    max: f64,
+    /// The direction in which the bar fills, inferred from its dimensions if `None`.
+    pub maybe_orientation: Option<Orientation>,
+    /// Whether or not the percentage of progress is displayed in the middle of the bar.
+    pub show_percentage: bool,
+    style: Style,
+}
+
+/// Graphical styling unique to the ProgressBar widget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the filled portion of the bar.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The color of the unfilled background of the bar.
+    #[conrod(default = "theme.background_color")]
+    pub background_color: Option<Color>,
+    /// The width of the border around the bar.
+    #[conrod(default = "theme.border_width")]
+    pub border: Option<Scalar>,
+    /// The color of the bar's border.
+    #[conrod(default = "theme.border_color")]
+    pub border_color: Option<Color>,
+    /// The color of the percentage label.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size of the percentage label.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the percentage label.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        background,
+        fill,
+        label,
+    }
+}
+
+/// Represents the state of the ProgressBar widget.
+pub struct State {
+    ids: Ids,
+}
+
+impl ProgressBar {
+    /// Construct a new ProgressBar displaying `value` out of `max`.
+    ///
+    /// The `value` is clamped to the range `0.0..=max`.
+    pub fn new(value: f64, max: f64) -> Self {
+        ProgressBar {
+            common: widget::CommonBuilder::default(),
+            value: value,
+            max: max,
+            maybe_orientation: None,
+            show_percentage: false,
+            style: Style::default(),
+        }
+    }
+
+    /// Specify the font used for displaying the percentage label.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods! {
+        pub orientation { maybe_orientation = Some(Orientation) }
+        pub show_percentage { show_percentage = bool }
+        pub background_color { style.background_color = Some(Color) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+    }
+
+    /// The fraction of the bar that is filled, between `0.0` and `1.0`.
+    fn fraction(&self) -> Scalar {
+        if self.max > 0.0 {
+            clamp(self.value, 0.0, self.max) / self.max
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Widget for ProgressBar {
+    type State = State;
+    type Style = Style;
+    type Event = ();
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the ProgressBar.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+
+        let fraction = self.fraction();
+        let orientation = self.maybe_orientation.unwrap_or_else(|| {
+            if rect.w() > rect.h() {
+                Orientation::Horizontal
+            } else {
+                Orientation::Vertical
+            }
+        });
+
+        // The **BorderedRectangle** behind the bar.
+        let border = style.border(ui.theme());
+        widget::BorderedRectangle::new(rect.dim())
+            .middle_of(id)
+            .graphics_for(id)
+            .color(style.background_color(ui.theme()))
+            .border(border)
+            .border_color(style.border_color(ui.theme()))
+            .set(state.ids.background, ui);
+
+        // The **Rectangle** filling the inner area in proportion to the progress.
+        let inner_rect = rect.pad(border);
+        let fill_rect = match orientation {
+            Orientation::Horizontal => Rect {
+                x: Range::new(
+                    inner_rect.left(),
+                    inner_rect.left() + inner_rect.w() * fraction,
+                ),
+                y: inner_rect.y,
+            },
+            Orientation::Vertical => Rect {
+                x: inner_rect.x,
+                y: Range::new(
+                    inner_rect.bottom(),
+                    inner_rect.bottom() + inner_rect.h() * fraction,
+                ),
+            },
+        };
+        widget::Rectangle::fill(fill_rect.dim())
+            .xy(fill_rect.xy())
+            .graphics_for(id)
+            .parent(state.ids.background)
+            .color(style.color(ui.theme()))
+            .set(state.ids.fill, ui);
+
+        // The **Text** displaying the percentage in the middle of the bar.
+        if self.show_percentage {
+            let label = format!("{}%", (fraction * 100.0).round());
+            let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+            widget::Text::new(&label)
+                .and_then(font_id, widget::Text::font_id)
+                .middle_of(id)
+                .graphics_for(id)
+                .color(style.label_color(ui.theme()))
+                .font_size(style.label_font_size(ui.theme()))
+                .set(state.ids.label, ui);
+        }
+    }
+}
+
+impl Colorable for ProgressBar {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+impl Borderable for ProgressBar {
+    builder_methods! {
+        border { style.border = Some(Scalar) }
+        border_color { style.border_color = Some(Color) }
+    }
+}
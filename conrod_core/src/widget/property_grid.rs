@@ -0,0 +1,262 @@
+//! A two-column grid of labelled properties, each with an editor suited to its type.
+
+use position::Scalar;
+use text;
+use widget;
+use {Color, Colorable, FontSize, Positionable, Sizeable, Widget};
+
+/// Displays a list of labelled properties as two columns: the label of each property on the left
+/// and a widget for editing its value on the right.
+///
+/// The editor for each property is chosen by the type of its value. See `PropertyValue`.
+///
+/// All labels share a single column, so the editors are aligned across rows.
+///
+/// Its reaction is triggered whenever the value of some property is changed by the user.
+#[derive(WidgetCommon_)]
+pub struct PropertyGrid<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    properties: &'a [(&'a str, PropertyValue<'a>)],
+    style: Style,
+}
+
+/// The value of a single property along with how it should be edited.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PropertyValue<'a> {
+    /// A `bool` edited via a `Toggle`.
+    Bool(bool),
+    /// A number within the range `min..max`, edited via a `Slider`.
+    Number {
+        /// The current value.
+        value: f64,
+        /// The minimum value.
+        min: f64,
+        /// The maximum value.
+        max: f64,
+    },
+    /// A single line of text edited via a `TextBox`.
+    Text(&'a str),
+    /// A color displayed as a swatch.
+    Color(Color),
+    /// One of a list of options, selected via a `DropDownList`.
+    Choice {
+        /// The options from which the user may choose.
+        options: &'a [&'a str],
+        /// The index of the currently selected option, if any.
+        selected: Option<usize>,
+    },
+}
+
+/// A change to the value of some property made by the user.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    /// The `Toggle` of a `PropertyValue::Bool` was toggled to the given value.
+    Bool(bool),
+    /// The `Slider` of a `PropertyValue::Number` was moved to the given value.
+    Number(f64),
+    /// The text of a `PropertyValue::Text` was updated.
+    Text(String),
+    /// The option at the given index of a `PropertyValue::Choice` was selected.
+    Choice(usize),
+}
+
+/// Unique styling for the `PropertyGrid`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The height of each row.
+    #[conrod(default = "30.0")]
+    pub row_height: Option<Scalar>,
+    /// The width of the label column as a percentage of the width of the grid.
+    #[conrod(default = "0.4")]
+    pub label_column_perc: Option<Scalar>,
+    /// The padding surrounding each label and editor within its cell.
+    #[conrod(default = "4.0")]
+    pub padding: Option<Scalar>,
+    /// The color of the property labels.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size of the property labels.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the labels.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        labels[],
+        toggles[],
+        sliders[],
+        text_boxes[],
+        swatches[],
+        drop_down_lists[],
+    }
+}
+
+/// The state of the `PropertyGrid`.
+pub struct State {
+    ids: Ids,
+}
+
+/// The `Event` type yielded by the `PropertyGrid`.
+///
+/// Each change is paired with the index of the row of the property that changed.
+pub type Event = Vec<(usize, Change)>;
+
+impl<'a> PropertyGrid<'a> {
+    /// Begin building a `PropertyGrid` for the given labelled properties.
+    pub fn new(properties: &'a [(&'a str, PropertyValue<'a>)]) -> Self {
+        PropertyGrid {
+            common: widget::CommonBuilder::default(),
+            properties: properties,
+            style: Style::default(),
+        }
+    }
+
+    /// Specify the font used for displaying the labels.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods! {
+        pub row_height { style.row_height = Some(Scalar) }
+        pub label_column_perc { style.label_column_perc = Some(Scalar) }
+        pub padding { style.padding = Some(Scalar) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+    }
+}
+
+impl<'a> Widget for PropertyGrid<'a> {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the PropertyGrid.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let PropertyGrid { properties, .. } = self;
+
+        // Ensure there are enough ids for every row.
+        let num_rows = properties.len();
+        if state.ids.labels.len() < num_rows {
+            state.update(|state| {
+                let id_gen = &mut ui.widget_id_generator();
+                let ids = &mut state.ids;
+                ids.labels.resize(num_rows, id_gen);
+                ids.toggles.resize(num_rows, id_gen);
+                ids.sliders.resize(num_rows, id_gen);
+                ids.text_boxes.resize(num_rows, id_gen);
+                ids.swatches.resize(num_rows, id_gen);
+                ids.drop_down_lists.resize(num_rows, id_gen);
+            });
+        }
+
+        let row_h = style.row_height(&ui.theme);
+        let pad = style.padding(&ui.theme);
+        let label_color = style.label_color(&ui.theme);
+        let label_font_size = style.label_font_size(&ui.theme);
+        let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+
+        // The columns, relative to the centre of the grid.
+        let label_w = rect.w() * style.label_column_perc(&ui.theme);
+        let editor_w = rect.w() - label_w;
+        let label_x = -rect.w() / 2.0 + label_w / 2.0;
+        let editor_left = -rect.w() / 2.0 + label_w;
+        let editor_x = editor_left + editor_w / 2.0;
+        let (w, h) = (editor_w - pad * 2.0, row_h - pad * 2.0);
+
+        let mut changes = Vec::new();
+        for (i, &(label, value)) in properties.iter().enumerate() {
+            let y = rect.h() / 2.0 - row_h * (i as Scalar + 0.5);
+
+            widget::Text::new(label)
+                .and_then(font_id, widget::Text::font_id)
+                .w(label_w - pad * 2.0)
+                .x_y_relative_to(id, label_x, y)
+                .left_justify()
+                .color(label_color)
+                .font_size(label_font_size)
+                .parent(id)
+                .set(state.ids.labels[i], ui);
+
+            match value {
+                PropertyValue::Bool(value) => {
+                    let x = editor_left + pad + h / 2.0;
+                    let event = widget::Toggle::new(value)
+                        .w_h(h, h)
+                        .x_y_relative_to(id, x, y)
+                        .parent(id)
+                        .set(state.ids.toggles[i], ui);
+                    if let Some(new_value) = event.last() {
+                        changes.push((i, Change::Bool(new_value)));
+                    }
+                }
+
+                PropertyValue::Number { value, min, max } => {
+                    let event = widget::Slider::new(value, min, max)
+                        .w_h(w, h)
+                        .x_y_relative_to(id, editor_x, y)
+                        .parent(id)
+                        .set(state.ids.sliders[i], ui);
+                    if let Some(new_value) = event {
+                        changes.push((i, Change::Number(new_value)));
+                    }
+                }
+
+                PropertyValue::Text(text) => {
+                    let events = widget::TextBox::new(text)
+                        .w_h(w, h)
+                        .x_y_relative_to(id, editor_x, y)
+                        .parent(id)
+                        .set(state.ids.text_boxes[i], ui);
+                    for event in events {
+                        if let widget::text_box::Event::Update(string) = event {
+                            changes.push((i, Change::Text(string)));
+                        }
+                    }
+                }
+
+                PropertyValue::Color(color) => {
+                    widget::Rectangle::fill_with([w, h], color)
+                        .x_y_relative_to(id, editor_x, y)
+                        .parent(id)
+                        .set(state.ids.swatches[i], ui);
+                }
+
+                PropertyValue::Choice { options, selected } => {
+                    let event = widget::DropDownList::new(options, selected)
+                        .w_h(w, h)
+                        .x_y_relative_to(id, editor_x, y)
+                        .parent(id)
+                        .set(state.ids.drop_down_lists[i], ui);
+                    if let Some(idx) = event {
+                        changes.push((i, Change::Choice(idx)));
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+}
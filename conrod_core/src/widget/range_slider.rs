@@ -28,6 +28,10 @@ pub struct RangeSlider<'a, T> {
     ///
     /// By default, this value is `1.0` (no skew).
     pub skew: f32,
+    /// Whether an edge that is dragged past the other edge is clamped to it, or swaps with it.
+    ///
+    /// By default, this value is `true` (edges are clamped).
+    pub clamp_handles: bool,
 }
 
 /// Graphical styling unique to the RangeSlider widget.
@@ -119,6 +123,7 @@ impl<'a, T> RangeSlider<'a, T> {
             max: max,
             maybe_label: None,
             skew: 1.0,
+            clamp_handles: true,
         }
     }
 
@@ -141,6 +146,15 @@ impl<'a, T> RangeSlider<'a, T> {
         self.skew = skew;
         self
     }
+
+    /// Whether an edge that is dragged past the other edge is clamped to it (`true`) or swaps with
+    /// it (`false`), in which case the dragged edge becomes the other edge and the drag continues.
+    ///
+    /// By default, this value is `true`.
+    pub fn clamp_handles(mut self, clamp_handles: bool) -> Self {
+        self.clamp_handles = clamp_handles;
+        self
+    }
 }
 
 impl<'a, T> Widget for RangeSlider<'a, T>
@@ -163,12 +177,12 @@ where
     }
 
     fn kid_area(&self, args: widget::KidAreaArgs<Self>) -> widget::KidArea {
-        const LABEL_PADDING: Scalar = 10.0;
+        let label_padding = args.theme.spacing_md;
         widget::KidArea {
             rect: args.rect,
             pad: Padding {
-                x: Range::new(LABEL_PADDING, LABEL_PADDING),
-                y: Range::new(LABEL_PADDING, LABEL_PADDING),
+                x: Range::new(label_padding, label_padding),
+                y: Range::new(label_padding, label_padding),
             },
         }
     }
@@ -190,6 +204,7 @@ where
             max,
             maybe_label,
             skew,
+            clamp_handles,
             ..
         } = self;
 
@@ -288,12 +303,24 @@ where
                         Some(Drag::Edge(Edge::Start)) => {
                             let abs_drag_to = inner_rect.x() + drag_event.to[0];
                             let v = x_to_value(abs_drag_to);
-                            new_start = utils::clamp(v, min, new_end);
+                            if !clamp_handles && v > new_end {
+                                new_start = new_end;
+                                new_end = v;
+                                maybe_drag = Some(Drag::Edge(Edge::End));
+                            } else {
+                                new_start = utils::clamp(v, min, new_end);
+                            }
                         }
                         Some(Drag::Edge(Edge::End)) => {
                             let abs_drag_to = inner_rect.x() + drag_event.to[0];
                             let v = x_to_value(abs_drag_to);
-                            new_end = utils::clamp(v, new_start, max);
+                            if !clamp_handles && v < new_start {
+                                new_end = new_start;
+                                new_start = v;
+                                maybe_drag = Some(Drag::Edge(Edge::Start));
+                            } else {
+                                new_end = utils::clamp(v, new_start, max);
+                            }
                         }
                         Some(Drag::Handle) => {
                             let drag_amt = drag_event.delta_xy[0];
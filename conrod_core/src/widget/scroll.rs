@@ -11,6 +11,17 @@ pub struct Scroll {
     maybe_initial_alignment: Option<Align>,
 }
 
+/// The visibility of the `Scrollbar` along a single axis of a scrollable widget.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    /// The `Scrollbar` is always visible, even if the children fit within the `kid_area`.
+    Always,
+    /// The `Scrollbar` is only visible while the children exceed the `kid_area` along the axis.
+    Auto,
+    /// No `Scrollbar` is shown, though the axis remains scrollable via the scroll wheel.
+    Never,
+}
+
 /// Scroll state calculated for a single axis.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct State<A> {
@@ -29,6 +40,21 @@ pub struct State<A> {
     pub scrollable_range_len: Scalar,
     /// Whether or not the this axis is currently scrolling.
     pub is_scrolling: bool,
+    /// The rate at which the offset is changing in scalar units per second.
+    ///
+    /// This is only ever non-zero for widgets with `Widget::scroll_momentum` enabled, in which
+    /// case the offset continues to be stepped by the decaying velocity via `apply_momentum`
+    /// once a drag of the scrollable area has been released.
+    pub velocity: Scalar,
+    /// The moment at which the momentum was last stepped via `step_momentum`.
+    last_momentum_step: Option<instant::Instant>,
+    /// Whether or not the left mouse button was held during the last call to `step_momentum`.
+    was_held: bool,
+    /// The offset with which the children were positioned during the last update.
+    ///
+    /// This only differs from the `offset` if the `offset` was set directly via
+    /// `Graph::set_scroll_offset`, in which case the children are yet to be repositioned.
+    layout_offset: Scalar,
     /// The axis type used to instantiate this state.
     axis: PhantomData<A>,
 }
@@ -48,6 +74,12 @@ pub trait Axis {
     fn offset_direction() -> Scalar;
 }
 
+/// The default rate per second at which the `velocity` of a scrollable axis with momentum decays.
+pub const MOMENTUM_FRICTION: Scalar = 4.0;
+
+/// The speed in scalar units per second below which momentum scrolling comes to a stop.
+pub const MOMENTUM_STOP_VELOCITY: Scalar = 5.0;
+
 /// Behaviour for scrolling across the `X` axis.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum X {}
@@ -128,7 +160,8 @@ where
     /// scrollable_range is scrollable.
     ///
     /// The `offset_bounds` are calculated as the amount which the original, un-scrolled,
-    /// `scrollable_range` may be offset from its origin.
+    /// `scrollable_range` may be offset from its origin. If the `scrollable_range` is not
+    /// scrollable, the `offset_bounds` are always `0.0` at both ends.
     ///
     /// ```txt
     ///
@@ -164,6 +197,12 @@ where
             .map(|state| state.offset)
             .unwrap_or(0.0);
 
+        // The offset with which the children were last positioned.
+        let layout_offset = maybe_prev_scroll_state
+            .as_ref()
+            .map(|state| state.layout_offset)
+            .unwrap_or(0.0);
+
         // Padding for the range.
         let padding = A::padding_range(kid_area.pad);
 
@@ -178,22 +217,30 @@ where
             ui.kids_bounding_box(idx)
                 .map(|kids| {
                     A::parallel_range(kids)
-                        .shift(-current_offset)
+                        .shift(-layout_offset)
                         .shift(-kid_area_range.middle())
                 })
                 .unwrap_or_else(|| Range::new(0.0, 0.0))
         };
 
-        // Determine the min and max offset bounds. These bounds are the limits to which the
-        // scrollable_range may be shifted in either direction across the range.
-        let min_offset =
-            Range::new(scrollable_range.start, kid_area_range_origin.start).magnitude();
-        let max_offset = Range::new(scrollable_range.end, kid_area_range_origin.end).magnitude();
-        let offset_bounds = Range::new(min_offset, max_offset);
-
         // The range is only scrollable if it is longer than the padded kid_area_range.
         let is_scrollable = scrollable_range.len() > kid_area_range.len();
 
+        // Determine the min and max offset bounds. These bounds are the limits to which the
+        // scrollable_range may be shifted in either direction across the range.
+        //
+        // If the children fit entirely within the kid_area there is nowhere to scroll to, so the
+        // bounds collapse to zero. This avoids offsetting the children into empty space.
+        let offset_bounds = if is_scrollable {
+            let min_offset =
+                Range::new(scrollable_range.start, kid_area_range_origin.start).magnitude();
+            let max_offset =
+                Range::new(scrollable_range.end, kid_area_range_origin.end).magnitude();
+            Range::new(min_offset, max_offset)
+        } else {
+            Range::new(0.0, 0.0)
+        };
+
         // If the range is scrollable, calculate the new offset by adding the `additional_offset`.
         //
         // The `additional_offset` is given via a `Scroll` event.
@@ -219,12 +266,102 @@ where
             }
         };
 
+        let velocity = maybe_prev_scroll_state
+            .as_ref()
+            .map(|state| state.velocity)
+            .unwrap_or(0.0);
+        let last_momentum_step = maybe_prev_scroll_state
+            .as_ref()
+            .and_then(|state| state.last_momentum_step);
+        let was_held = maybe_prev_scroll_state
+            .as_ref()
+            .map(|state| state.was_held)
+            .unwrap_or(false);
+
         State {
             offset: new_offset,
             offset_bounds: offset_bounds,
             scrollable_range_len: scrollable_range.len(),
             axis: PhantomData,
             is_scrolling: additional_offset != 0.0,
+            velocity: velocity,
+            last_momentum_step: last_momentum_step,
+            was_held: was_held,
+            layout_offset: new_offset,
+        }
+    }
+
+    /// Restore the given offset, e.g. one saved via `Ui::save_state`, stopping any momentum.
+    ///
+    /// The offset is clamped to the `offset_bounds` and the children are positioned with it during
+    /// the current update. However, the bounds are unknown during the first update of the widget
+    /// as its children have not yet been set. In this case, the offset is kept as is and clamped
+    /// during the following update instead.
+    pub fn restore_offset(&mut self, offset: Scalar, is_first_update: bool) {
+        if is_first_update {
+            self.offset_bounds = Range::new(offset, offset);
+            self.offset = offset;
+        } else {
+            self.offset = self.offset_bounds.clamp_value(offset);
+            self.layout_offset = self.offset;
+        }
+        self.velocity = 0.0;
+    }
+
+    /// Step the momentum of the axis at the moment `now`, given the offset of the previous update.
+    ///
+    /// While the scrollable area is dragged, e.g. via a `Scrollbar`, the `velocity` becomes the
+    /// rate at which the offset has changed since the previous step. Steps during which the drag
+    /// pauses or is released carry the last measured `velocity`, so that a fling is not lost to
+    /// the timing of the final input events. Once released, the offset continues to decelerate
+    /// via `apply_momentum`. Scrolling without a drag, e.g. via the mouse wheel, or pressing the
+    /// left mouse button while the offset is in motion stops it.
+    pub fn step_momentum(
+        &mut self,
+        prev_offset: Scalar,
+        has_scrolled: bool,
+        is_held: bool,
+        now: instant::Instant,
+    ) {
+        let dt = self.last_momentum_step.map_or(0.0, |last| {
+            let elapsed = now.duration_since(last);
+            elapsed.as_secs() as Scalar + elapsed.subsec_nanos() as Scalar * 1e-9
+        });
+        self.last_momentum_step = Some(now);
+        let was_held = self.was_held;
+        self.was_held = is_held;
+        match (has_scrolled, is_held) {
+            (true, true) => {
+                if dt > 0.0 {
+                    self.velocity = (self.offset - prev_offset) / dt;
+                }
+            }
+            // The drag was released, so carry its velocity into the momentum.
+            (true, false) if was_held => (),
+            // The drag paused, so carry its velocity, decaying it for as long as it is held still.
+            (false, true) if was_held => self.velocity *= (-MOMENTUM_FRICTION * dt).exp(),
+            (true, false) | (false, true) => self.velocity = 0.0,
+            (false, false) => self.apply_momentum(dt, MOMENTUM_FRICTION),
+        }
+    }
+
+    /// Step the offset by the current `velocity` over the elapsed `dt` seconds before decaying
+    /// the `velocity` by the given `friction` rate per second.
+    ///
+    /// The offset is clamped to the `offset_bounds` and the `velocity` is reset to zero upon
+    /// reaching either bound or upon falling below the `MOMENTUM_STOP_VELOCITY`.
+    pub fn apply_momentum(&mut self, dt: Scalar, friction: Scalar) {
+        if self.velocity == 0.0 || dt <= 0.0 {
+            return;
+        }
+        let prev_offset = self.offset;
+        let offset = prev_offset + self.velocity * dt;
+        self.offset = self.offset_bounds.clamp_value(offset);
+        self.is_scrolling = self.offset != prev_offset;
+        self.layout_offset = self.offset;
+        self.velocity *= (-friction * dt).exp();
+        if self.offset != offset || self.velocity.abs() < MOMENTUM_STOP_VELOCITY {
+            self.velocity = 0.0;
         }
     }
 }
@@ -194,7 +194,11 @@ where
                 let pos_min = handle_range.align_start_of(track_range).middle();
                 let pos_max = handle_range.align_end_of(track_range).middle();
                 let pos_bounds = Range::new(pos_min, pos_max);
-                offset_bounds.map_value_to(offset, &pos_bounds)
+                if offset_bounds.len() == 0.0 {
+                    pos_bounds.start
+                } else {
+                    offset_bounds.map_value_to(offset, &pos_bounds)
+                }
             };
             let range = Range::from_pos_and_len(pos, len);
             A::handle_rect(perpendicular_track_range, range)
@@ -0,0 +1,260 @@
+//! A seek bar for scrubbing through some media, displaying the played and buffered ranges.
+
+use position::{Range, Rect, Scalar};
+use text;
+use utils::{clamp, map_range};
+use widget;
+use {Color, Colorable, FontSize, Positionable, Widget};
+
+/// A horizontal seek bar for some media of the given duration.
+///
+/// The track displays the buffered range behind the played range. While the mouse hovers over the
+/// track, a tick is drawn at the cursor along with a label showing the time at that position.
+///
+/// Clicking the track or dragging the playhead previews the new position, however its reaction is
+/// only triggered once the left mouse button is released, yielding the position to seek to.
+#[derive(Copy, Clone, WidgetCommon_)]
+pub struct Scrubber {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    played: f64,
+    buffered: f64,
+    duration: f64,
+    style: Style,
+    /// Whether or not user input is enabled.
+    pub enabled: bool,
+}
+
+/// Unique styling for the `Scrubber`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the track behind the buffered range.
+    #[conrod(default = "theme.border_color")]
+    pub color: Option<Color>,
+    /// The color of the buffered range.
+    #[conrod(default = "theme.shape_color")]
+    pub buffered_color: Option<Color>,
+    /// The color of the played range.
+    #[conrod(default = "theme.label_color")]
+    pub played_color: Option<Color>,
+    /// The color of the tick drawn at the hovered position.
+    #[conrod(default = "theme.label_color")]
+    pub hover_color: Option<Color>,
+    /// The color of the label showing the time at the hovered position.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size of the label showing the time at the hovered position.
+    #[conrod(default = "theme.font_size_small")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the label.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        track,
+        buffered,
+        played,
+        hover_tick,
+        hover_label,
+    }
+}
+
+/// The state of the `Scrubber`.
+pub struct State {
+    ids: Ids,
+}
+
+/// The `Event` type yielded by the `Scrubber`.
+///
+/// The position to seek to, if the user released the mouse upon the track during this update.
+pub type Event = Option<f64>;
+
+/// The width of the tick drawn at the hovered position.
+const HOVER_TICK_WIDTH: Scalar = 2.0;
+
+impl Scrubber {
+    /// Begin building a `Scrubber` for media of the given `duration`, of which `played` has been
+    /// played and `buffered` has been buffered.
+    pub fn new(played: f64, buffered: f64, duration: f64) -> Self {
+        Scrubber {
+            common: widget::CommonBuilder::default(),
+            played: played,
+            buffered: buffered,
+            duration: duration,
+            style: Style::default(),
+            enabled: true,
+        }
+    }
+
+    /// Specify the font used for displaying the label.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods! {
+        pub buffered_color { style.buffered_color = Some(Color) }
+        pub played_color { style.played_color = Some(Color) }
+        pub hover_color { style.hover_color = Some(Color) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+        pub enabled { enabled = bool }
+    }
+}
+
+impl Widget for Scrubber {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Scrubber.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let Scrubber {
+            played,
+            buffered,
+            duration,
+            enabled,
+            ..
+        } = self;
+
+        let position_at = |x: Scalar| position_from_x(rect, x, duration);
+
+        // Seek to wherever the left mouse button was released.
+        let mut maybe_seek = None;
+        let mut played = played;
+        if enabled {
+            let input = ui.widget_input(id);
+            for (xy, _) in input.releases().mouse().left() {
+                maybe_seek = Some(position_at(rect.x() + xy[0]));
+            }
+            match input.mouse() {
+                // Preview the new position while the mouse button is held down.
+                Some(mouse) if mouse.buttons.left().is_down() => {
+                    played = position_at(mouse.abs_xy()[0]);
+                }
+                _ => (),
+            }
+            if let Some(seek) = maybe_seek {
+                played = seek;
+            }
+        }
+
+        let (buffered_rect, played_rect) = fill_rects(rect, played, buffered, duration);
+
+        widget::Rectangle::fill_with(rect.dim(), style.color(&ui.theme))
+            .xy(rect.xy())
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.track, ui);
+
+        widget::Rectangle::fill_with(buffered_rect.dim(), style.buffered_color(&ui.theme))
+            .xy(buffered_rect.xy())
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.buffered, ui);
+
+        widget::Rectangle::fill_with(played_rect.dim(), style.played_color(&ui.theme))
+            .xy(played_rect.xy())
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.played, ui);
+
+        // Draw a tick and the time at the hovered position.
+        let maybe_hover_x = match ui.widget_input(id).mouse() {
+            Some(ref mouse) if enabled && mouse.is_over() => {
+                Some(clamp(mouse.abs_xy()[0], rect.left(), rect.right()))
+            }
+            _ => None,
+        };
+        if let Some(x) = maybe_hover_x {
+            widget::Rectangle::fill_with(
+                [HOVER_TICK_WIDTH, rect.h()],
+                style.hover_color(&ui.theme),
+            )
+            .x_y(x, rect.y())
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.hover_tick, ui);
+
+            let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+            widget::Text::new(&format_time(position_at(x)))
+                .and_then(font_id, widget::Text::font_id)
+                .x(x)
+                .up_from(id, 4.0)
+                .color(style.label_color(&ui.theme))
+                .font_size(style.label_font_size(&ui.theme))
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.hover_label, ui);
+        }
+
+        maybe_seek
+    }
+}
+
+impl Colorable for Scrubber {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+/// The rectangles of the buffered and played fills respectively within the given track `rect`.
+///
+/// Both fills begin at the left edge of the track and are clamped to its width.
+pub fn fill_rects(rect: Rect, played: f64, buffered: f64, duration: f64) -> (Rect, Rect) {
+    let fill = |position: f64| {
+        let right = x_from_position(rect, position, duration);
+        Rect {
+            x: Range::new(rect.left(), right),
+            y: rect.y,
+        }
+    };
+    (fill(buffered), fill(played))
+}
+
+/// Format the given number of seconds as `m:ss`, or `h:mm:ss` for an hour or more.
+pub fn format_time(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+// The absolute *x* position along the track for the given position within the media.
+fn x_from_position(rect: Rect, position: f64, duration: f64) -> Scalar {
+    if duration <= 0.0 {
+        return rect.left();
+    }
+    let position = clamp(position, 0.0, duration);
+    map_range(position, 0.0, duration, rect.left(), rect.right())
+}
+
+// The position within the media for the given absolute *x* position along the track.
+fn position_from_x(rect: Rect, x: Scalar, duration: f64) -> f64 {
+    if rect.w() <= 0.0 || duration <= 0.0 {
+        return 0.0;
+    }
+    let x = clamp(x, rect.left(), rect.right());
+    map_range(x, rect.left(), rect.right(), 0.0, duration)
+}
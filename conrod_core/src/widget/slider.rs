@@ -1,8 +1,11 @@
 //! A widget for selecting a single value along some linear range.
 
+use event;
 use num::{Float, NumCast, ToPrimitive};
+pub use position::Orientation;
 use position::{Padding, Range, Rect, Scalar};
 use text;
+use utils::{clamp, map_range, value_from_perc};
 use widget;
 use widget::triangles::Triangle;
 use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Widget};
@@ -10,10 +13,11 @@ use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Widget};
 /// Linear value selection.
 ///
 /// If the slider's width is greater than it's height, it will automatically become a horizontal
-/// slider, otherwise it will be a vertical slider.
+/// slider, otherwise it will be a vertical slider. This may be overridden via the `orientation`
+/// builder method.
 ///
-/// Its reaction is triggered if the value is updated or if the mouse button is released while
-/// the cursor is above the rectangle.
+/// Setting the slider returns the new value whenever the user changes it, e.g.
+/// `if let Some(value) = Slider::new(value, min, max).set(id, ui) { .. }`.
 #[derive(WidgetCommon_)]
 pub struct Slider<'a, T> {
     #[conrod(common_builder)]
@@ -29,10 +33,27 @@ pub struct Slider<'a, T> {
     ///
     /// All skew amounts should be greater than 0.0.
     pub skew: f32,
+    /// The scale along which the value is mapped to the position of the slider's handle.
+    pub scale: Scale,
+    /// The orientation of the slider, inferred from its dimensions if `None`.
+    ///
+    /// The value increases from left to right when `Horizontal`, or from bottom to top when
+    /// `Vertical`.
+    pub maybe_orientation: Option<Orientation>,
+    /// The number of evenly spaced detents between `min` and `max` (inclusive) to which the value
+    /// snaps. Fewer than two steps leave the slider continuous.
+    pub steps: usize,
+    /// How strongly the value snaps to the nearest detent, from `0.0` to `1.0`.
+    ///
+    /// The value snaps when the cursor is within this fraction of half the distance between two
+    /// detents. At `1.0` the value always snaps to the nearest detent.
+    pub snap_strength: f32,
     maybe_label: Option<&'a str>,
     style: Style,
     /// Whether or not user input is enabled for the Slider.
     pub enabled: bool,
+    /// The value to which the Slider is bound, updated whenever the value changes.
+    maybe_binding: Option<&'a mut T>,
 }
 
 /// Graphical styling unique to the Slider widget.
@@ -62,6 +83,7 @@ widget_ids! {
     struct Ids {
         triangles,
         label,
+        ticks[],
     }
 }
 
@@ -70,6 +92,21 @@ pub struct State {
     ids: Ids,
 }
 
+/// The scale along which the value of a `Slider` is mapped to the position of its handle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Scale {
+    /// The value is mapped linearly between `min` and `max`.
+    Linear,
+    /// The value is mapped log-linearly between `min` and `max`, so that equal ratios between
+    /// values span equal lengths of the slider. Useful for frequencies and zoom levels.
+    ///
+    /// The value at some percentage `perc` along the slider is `min * (max / min).powf(perc)`.
+    ///
+    /// Both `min` and `max` must be greater than zero, otherwise the `Slider` falls back to a
+    /// `Linear` scale.
+    Logarithmic,
+}
+
 impl<'a, T> Slider<'a, T> {
     /// Construct a new Slider widget.
     pub fn new(value: T, min: T, max: T) -> Self {
@@ -80,11 +117,29 @@ impl<'a, T> Slider<'a, T> {
             min: min,
             max: max,
             skew: 1.0,
+            scale: Scale::Linear,
+            maybe_orientation: None,
+            steps: 0,
+            snap_strength: 1.0,
             maybe_label: None,
             enabled: true,
+            maybe_binding: None,
         }
     }
 
+    /// Construct a new Slider widget bound to the given value.
+    ///
+    /// The Slider displays the current `value`, which is updated in place whenever the user
+    /// changes the value of the Slider. The new value is still yielded as the Slider's event.
+    pub fn bind(value: &'a mut T, min: T, max: T) -> Self
+    where
+        T: Copy,
+    {
+        let mut slider = Slider::new(*value, min, max);
+        slider.maybe_binding = Some(value);
+        slider
+    }
+
     /// Specify the font used for displaying the label.
     pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
         self.style.label_font_id = Some(Some(font_id));
@@ -93,6 +148,10 @@ impl<'a, T> Slider<'a, T> {
 
     builder_methods! {
         pub skew { skew = f32 }
+        pub scale { scale = Scale }
+        pub orientation { maybe_orientation = Some(Orientation) }
+        pub steps { steps = usize }
+        pub snap_strength { snap_strength = f32 }
         pub enabled { enabled = bool }
     }
 }
@@ -116,20 +175,18 @@ where
     }
 
     fn kid_area(&self, args: widget::KidAreaArgs<Self>) -> widget::KidArea {
-        const LABEL_PADDING: Scalar = 10.0;
+        let label_padding = args.theme.spacing_md;
         widget::KidArea {
             rect: args.rect,
             pad: Padding {
-                x: Range::new(LABEL_PADDING, LABEL_PADDING),
-                y: Range::new(LABEL_PADDING, LABEL_PADDING),
+                x: Range::new(label_padding, label_padding),
+                y: Range::new(label_padding, label_padding),
             },
         }
     }
 
     /// Update the state of the Slider.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
-        use utils::{clamp, map_range, value_from_perc};
-
         let widget::UpdateArgs {
             id,
             state,
@@ -143,33 +200,71 @@ where
             min,
             max,
             skew,
+            scale,
+            maybe_orientation,
+            steps,
+            snap_strength,
             maybe_label,
+            maybe_binding,
+            enabled,
             ..
         } = self;
 
-        let is_horizontal = rect.w() > rect.h();
+        // A logarithmic scale is only possible for a strictly positive range, so fall back to a
+        // linear scale otherwise.
+        let scale = match scale {
+            Scale::Logarithmic if !(min > T::zero() && max > T::zero()) => Scale::Linear,
+            scale => scale,
+        };
+
+        let orientation = maybe_orientation.unwrap_or_else(|| {
+            if rect.w() > rect.h() {
+                Orientation::Horizontal
+            } else {
+                Orientation::Vertical
+            }
+        });
         let border = style.border(ui.theme());
         let inner_rect = rect.pad(border);
 
-        let new_value = if let Some(mouse) = ui.widget_input(id).mouse() {
+        // The range along which the handle moves.
+        let track = match orientation {
+            Orientation::Horizontal => inner_rect.x,
+            Orientation::Vertical => inner_rect.y,
+        };
+        let track_len = track.len();
+
+        // The distance along the track of the given value.
+        let pos_from_value = |value: T| -> Scalar {
+            let perc = scale.perc_from_value(value, min, max);
+            perc.powf(1.0 / skew as f64) * track_len
+        };
+
+        let new_value = if let Some(mouse) = ui.widget_input(id).mouse().filter(|_| enabled) {
             if mouse.buttons.left().is_down() {
-                let mouse_abs_xy = mouse.abs_xy();
-                if is_horizontal {
-                    // Horizontal.
-                    let inner_w = inner_rect.w();
-                    let slider_w = mouse_abs_xy[0] - inner_rect.x.start;
-                    let perc = clamp(slider_w, 0.0, inner_w) / inner_w;
-                    let skewed_perc = (perc).powf(skew as f64);
-                    let w_perc = skewed_perc;
-                    value_from_perc(w_perc as f32, min, max)
-                } else {
-                    // Vertical.
-                    let inner_h = inner_rect.h();
-                    let slider_h = mouse_abs_xy[1] - inner_rect.y.start;
-                    let perc = clamp(slider_h, 0.0, inner_h) / inner_h;
-                    let skewed_perc = (perc).powf(skew as f64);
-                    let h_perc = skewed_perc;
-                    value_from_perc(h_perc as f32, min, max)
+                let mouse_pos = match orientation {
+                    Orientation::Horizontal => mouse.abs_xy()[0],
+                    Orientation::Vertical => mouse.abs_xy()[1],
+                };
+                let pos = clamp(mouse_pos - track.start, 0.0, track_len);
+                let skewed_perc = (pos / track_len).powf(skew as f64);
+                let value = scale.value_from_perc(skewed_perc, min, max);
+
+                // Snap to the nearest detent if it is within the snapping distance.
+                let nearest_detent = detents(steps, min, max)
+                    .map(|detent| (detent, (pos_from_value(detent) - pos).abs()))
+                    .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                match nearest_detent {
+                    Some((detent, distance)) => {
+                        let spacing = track_len / (steps - 1) as Scalar;
+                        let threshold = spacing / 2.0 * snap_strength as Scalar;
+                        if snap_strength >= 1.0 || distance <= threshold {
+                            detent
+                        } else {
+                            value
+                        }
+                    }
+                    None => value,
                 }
             } else {
                 value
@@ -180,6 +275,9 @@ where
 
         // The **Rectangle** for the border.
         let interaction_color = |ui: &::ui::UiCell, color: Color| {
+            if !enabled {
+                return ui.theme.disabled(color);
+            }
             ui.widget_input(id)
                 .mouse()
                 .map(|mouse| {
@@ -193,9 +291,9 @@ where
         };
 
         // The **Rectangle** for the adjustable slider.
-        let value_perc = map_range(new_value, min, max, 0.0, 1.0);
+        let value_perc = scale.perc_from_value(new_value, min, max);
         let unskewed_perc = value_perc.powf(1.0 / skew as f64);
-        let (slider_rect, blank_rect) = if is_horizontal {
+        let (slider_rect, blank_rect) = if let Orientation::Horizontal = orientation {
             let left = inner_rect.x.start;
             let slider = map_range(unskewed_perc, 0.0, 1.0, left, inner_rect.x.end);
             let right = inner_rect.x.end;
@@ -263,6 +361,31 @@ where
             .parent(id)
             .set(state.ids.triangles, ui);
 
+        // A faint tick mark at each detent.
+        let num_ticks = detents(steps, min, max).count();
+        if state.ids.ticks.len() < num_ticks {
+            state.update(|state| {
+                state
+                    .ids
+                    .ticks
+                    .resize(num_ticks, &mut ui.widget_id_generator())
+            });
+        }
+        let tick_color = style.label_color(ui.theme()).alpha(0.25);
+        for (detent, &tick_id) in detents(steps, min, max).zip(state.ids.ticks.iter()) {
+            let tick_pos = track.start + pos_from_value(detent);
+            let (xy, dim) = match orientation {
+                Orientation::Horizontal => ([tick_pos, inner_rect.y()], [1.0, inner_rect.h()]),
+                Orientation::Vertical => ([inner_rect.x(), tick_pos], [inner_rect.w(), 1.0]),
+            };
+            widget::Rectangle::fill(dim)
+                .xy(xy)
+                .color(tick_color)
+                .graphics_for(id)
+                .parent(id)
+                .set(tick_id, ui);
+        }
+
         // The **Text** for the slider's label (if it has one).
         if let Some(label) = maybe_label {
             let label_color = style.label_color(ui.theme());
@@ -272,7 +395,7 @@ where
             widget::Text::new(label)
                 .and_then(font_id, widget::Text::font_id)
                 .and(|text| {
-                    if is_horizontal {
+                    if let Orientation::Horizontal = orientation {
                         text.mid_left_of(id)
                     } else {
                         text.mid_bottom_of(id)
@@ -286,6 +409,14 @@ where
 
         // If the value has just changed, return the new value.
         if value != new_value {
+            let kind = match ui.widget_input(id).drags().left().next() {
+                Some(_) => event::InteractionKind::Drag,
+                None => event::InteractionKind::ValueChanged,
+            };
+            ui.log_interaction(id, "Slider", kind);
+            if let Some(binding) = maybe_binding {
+                *binding = new_value;
+            }
             Some(new_value)
         } else {
             None
@@ -311,3 +442,52 @@ impl<'a, T> Labelable<'a> for Slider<'a, T> {
         label_font_size { style.label_font_size = Some(FontSize) }
     }
 }
+
+/// The values of `steps` evenly spaced detents between `min` and `max` inclusive.
+///
+/// Yields nothing for fewer than two steps.
+fn detents<T>(steps: usize, min: T, max: T) -> impl Iterator<Item = T>
+where
+    T: Float + NumCast + ToPrimitive,
+{
+    let num = if steps < 2 { 0 } else { steps };
+    (0..num).map(move |i| value_from_perc(i as f32 / (num - 1) as f32, min, max))
+}
+
+impl Scale {
+    /// The value at the given percentage along the slider.
+    fn value_from_perc<T>(self, perc: Scalar, min: T, max: T) -> T
+    where
+        T: Float + NumCast + ToPrimitive,
+    {
+        match self {
+            Scale::Linear => value_from_perc(perc as f32, min, max),
+            Scale::Logarithmic => {
+                let min = min.to_f64().unwrap();
+                let max = max.to_f64().unwrap();
+                NumCast::from(min * (max / min).powf(perc)).unwrap()
+            }
+        }
+    }
+
+    /// The percentage along the slider of the given value.
+    fn perc_from_value<T>(self, value: T, min: T, max: T) -> Scalar
+    where
+        T: Float + NumCast + ToPrimitive,
+    {
+        match self {
+            Scale::Linear => map_range(value, min, max, 0.0, 1.0),
+            Scale::Logarithmic => {
+                let value = clamp(value, min.min(max), min.max(max));
+                let value = value.to_f64().unwrap().ln();
+                let min = min.to_f64().unwrap().ln();
+                let max = max.to_f64().unwrap().ln();
+                // An empty range would otherwise produce `NaN`.
+                if max == min {
+                    return 0.0;
+                }
+                (value - min) / (max - min)
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Alignment guides for snapping a dragged widget to its siblings on a design surface.
+
+use graph::Walker;
+use position::{Range, Rect, Scalar};
+use widget;
+use {Color, Colorable, Widget};
+
+/// Snaps the rectangle of a widget that is being dragged upon some design surface so that its
+/// edges or centre align with those of its sibling widgets, drawing a guide `Line` along each
+/// alignment. This is commonly known as "smart guides".
+///
+/// The `SnapGuides` should be instantiated as a child of the surface each update while the
+/// widget is being dragged, and prior to instantiating the dragged widget. Its reaction yields
+/// the snapped rectangle at which the dragged widget should be instantiated.
+///
+/// The siblings are the other depth-wise children of the surface, whose rectangles are retrieved
+/// from the widget graph.
+#[derive(Copy, Clone, WidgetCommon_)]
+pub struct SnapGuides {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    surface: widget::Id,
+    dragged: widget::Id,
+    dragged_rect: Rect,
+    style: Style,
+}
+
+/// Unique styling for the `SnapGuides`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The distance within which edges and centres are snapped into alignment.
+    #[conrod(default = "5.0")]
+    pub threshold: Option<Scalar>,
+    /// The color of the guide lines.
+    #[conrod(default = "theme.border_color")]
+    pub color: Option<Color>,
+    /// The thickness of the guide lines.
+    #[conrod(default = "1.0")]
+    pub thickness: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        lines[],
+    }
+}
+
+/// The state of the `SnapGuides`.
+pub struct State {
+    ids: Ids,
+}
+
+/// An alignment between the dragged rectangle and at least one of its siblings.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Guide {
+    /// A vertical guide at `x`, spanning the aligned rectangles along the *y* axis.
+    Vertical {
+        /// The position of the guide along the *x* axis.
+        x: Scalar,
+        /// The extent of the guide along the *y* axis.
+        y: Range,
+    },
+    /// A horizontal guide at `y`, spanning the aligned rectangles along the *x* axis.
+    Horizontal {
+        /// The position of the guide along the *y* axis.
+        y: Scalar,
+        /// The extent of the guide along the *x* axis.
+        x: Range,
+    },
+}
+
+/// The result of snapping a dragged rectangle to its siblings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snap {
+    /// The dragged rectangle after snapping.
+    pub rect: Rect,
+    /// The guides along which the snapped rectangle aligns with its siblings.
+    pub guides: Vec<Guide>,
+}
+
+/// The `Event` type yielded by the `SnapGuides`.
+pub type Event = Snap;
+
+impl SnapGuides {
+    /// Begin building the `SnapGuides` for the `dragged` widget upon the given `surface`.
+    ///
+    /// The `dragged_rect` is the absolute rectangle at which the widget would be placed if it
+    /// were not snapped.
+    pub fn new(surface: widget::Id, dragged: widget::Id, dragged_rect: Rect) -> Self {
+        SnapGuides {
+            common: widget::CommonBuilder::default(),
+            surface: surface,
+            dragged: dragged,
+            dragged_rect: dragged_rect,
+            style: Style::default(),
+        }
+    }
+
+    builder_methods! {
+        pub threshold { style.threshold = Some(Scalar) }
+        pub thickness { style.thickness = Some(Scalar) }
+    }
+}
+
+impl Widget for SnapGuides {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the SnapGuides.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            style,
+            ui,
+            ..
+        } = args;
+        let SnapGuides {
+            surface,
+            dragged,
+            dragged_rect,
+            ..
+        } = self;
+
+        let siblings: Vec<Rect> = {
+            let graph = ui.widget_graph();
+            graph
+                .depth_children(surface)
+                .iter(graph)
+                .nodes()
+                .filter(|&child| child != dragged && child != id)
+                .filter_map(|child| graph.widget(child).map(|container| container.rect))
+                .collect()
+        };
+        let threshold = style.threshold(&ui.theme);
+        let snapped = snap(dragged_rect, &siblings, threshold);
+
+        // Draw a line along each guide.
+        if state.ids.lines.len() < snapped.guides.len() {
+            let num_lines = snapped.guides.len();
+            state.update(|state| {
+                state
+                    .ids
+                    .lines
+                    .resize(num_lines, &mut ui.widget_id_generator())
+            });
+        }
+        let color = style.color(&ui.theme);
+        let thickness = style.thickness(&ui.theme);
+        for (&line_id, &guide) in state.ids.lines.iter().zip(snapped.guides.iter()) {
+            let (start, end) = match guide {
+                Guide::Vertical { x, y } => ([x, y.start], [x, y.end]),
+                Guide::Horizontal { y, x } => ([x.start, y], [x.end, y]),
+            };
+            widget::Line::abs(start, end)
+                .color(color)
+                .thickness(thickness)
+                .parent(id)
+                .set(line_id, ui);
+        }
+
+        snapped
+    }
+}
+
+impl Colorable for SnapGuides {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+/// Snap the given `rect` so that its edges or centre align with those of the `others` that are
+/// within the `threshold`, producing a `Guide` for each resulting alignment.
+///
+/// Each axis is snapped independently to the nearest alignment on that axis.
+pub fn snap(rect: Rect, others: &[Rect], threshold: Scalar) -> Snap {
+    let x_offset = snap_offset(rect.x, others.iter().map(|other| other.x), threshold);
+    let y_offset = snap_offset(rect.y, others.iter().map(|other| other.y), threshold);
+    let rect = rect.shift_x(x_offset).shift_y(y_offset);
+
+    let mut guides = Vec::new();
+    for &x in &aligned_lines(rect.x, others.iter().map(|other| other.x)) {
+        let y = others
+            .iter()
+            .filter(|other| lines(other.x).iter().any(|&o| approx_eq(o, x)))
+            .fold(rect.y, |y, other| extend(y, other.y));
+        guides.push(Guide::Vertical { x: x, y: y });
+    }
+    for &y in &aligned_lines(rect.y, others.iter().map(|other| other.y)) {
+        let x = others
+            .iter()
+            .filter(|other| lines(other.y).iter().any(|&o| approx_eq(o, y)))
+            .fold(rect.x, |x, other| extend(x, other.x));
+        guides.push(Guide::Horizontal { y: y, x: x });
+    }
+
+    Snap {
+        rect: rect,
+        guides: guides,
+    }
+}
+
+// The start, middle and end of the given range.
+fn lines(range: Range) -> [Scalar; 3] {
+    let range = range.undirected();
+    [range.start, range.middle(), range.end]
+}
+
+// The smallest offset within the threshold that aligns one of the lines of `range` with one of
+// the lines of the `others`.
+fn snap_offset<I>(range: Range, others: I, threshold: Scalar) -> Scalar
+where
+    I: IntoIterator<Item = Range>,
+{
+    let mut best: Option<Scalar> = None;
+    for other in others {
+        for &a in &lines(range) {
+            for &b in &lines(other) {
+                let offset = b - a;
+                if offset.abs() <= threshold && best.map_or(true, |best| offset.abs() < best.abs())
+                {
+                    best = Some(offset);
+                }
+            }
+        }
+    }
+    best.unwrap_or(0.0)
+}
+
+// The lines of `range` that are aligned with some line of the `others`.
+fn aligned_lines<I>(range: Range, others: I) -> Vec<Scalar>
+where
+    I: IntoIterator<Item = Range>,
+{
+    let others: Vec<Range> = others.into_iter().collect();
+    lines(range)
+        .iter()
+        .cloned()
+        .filter(|&a| {
+            others
+                .iter()
+                .any(|&other| lines(other).iter().any(|&b| approx_eq(a, b)))
+        })
+        .collect()
+}
+
+// Extend the given range so that it also spans `other`.
+fn extend(range: Range, other: Range) -> Range {
+    let (range, other) = (range.undirected(), other.undirected());
+    Range::new(range.start.min(other.start), range.end.max(other.end))
+}
+
+fn approx_eq(a: Scalar, b: Scalar) -> bool {
+    (a - b).abs() < 1e-6
+}
@@ -0,0 +1,240 @@
+//! A pair of panes separated by a draggable divider.
+
+use cursor;
+use input;
+pub use position::Orientation;
+use position::{Rect, Scalar};
+use utils::clamp;
+use widget;
+use {Color, Colorable, Positionable, Widget};
+
+/// Divides its area into two panes separated by a divider, reporting the area of each pane.
+///
+/// The `ratio` is the portion of the area (minus the divider) occupied by the first pane, i.e. the
+/// left pane for a `Vertical` divider or the top pane for a `Horizontal` divider.
+///
+/// The divider may be dragged with the mouse. Once clicked, the divider captures the keyboard and
+/// may also be moved by a `keyboard_step` at a time using the arrow keys along its axis.
+///
+/// Its reaction yields the rectangles of both panes, along with the new ratio if it was changed.
+#[derive(Copy, Clone, WidgetCommon_)]
+pub struct Splitter {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    ratio: Scalar,
+    min_ratio: Scalar,
+    max_ratio: Scalar,
+    orientation: Orientation,
+    style: Style,
+    /// Whether or not user input is enabled.
+    pub enabled: bool,
+}
+
+/// Unique styling for the `Splitter`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the divider.
+    #[conrod(default = "theme.border_color")]
+    pub color: Option<Color>,
+    /// The thickness of the divider.
+    #[conrod(default = "6.0")]
+    pub divider_thickness: Option<Scalar>,
+    /// The amount by which the ratio changes for each arrow key press.
+    #[conrod(default = "0.05")]
+    pub keyboard_step: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        divider,
+    }
+}
+
+/// The state of the `Splitter`.
+pub struct State {
+    ids: Ids,
+}
+
+/// The `Event` type yielded by the `Splitter`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Event {
+    /// The area of the first pane, i.e. the left or top pane.
+    pub first: Rect,
+    /// The area of the second pane, i.e. the right or bottom pane.
+    pub second: Rect,
+    /// The new ratio if it was changed by the user during this update.
+    pub new_ratio: Option<Scalar>,
+}
+
+impl Splitter {
+    /// Begin building a `Splitter` with the given `ratio` and a `Vertical` divider.
+    pub fn new(ratio: Scalar) -> Self {
+        Splitter {
+            common: widget::CommonBuilder::default(),
+            ratio: ratio,
+            min_ratio: 0.0,
+            max_ratio: 1.0,
+            orientation: Orientation::Vertical,
+            style: Style::default(),
+            enabled: true,
+        }
+    }
+
+    /// Limit the ratio to the given range when resized by the user.
+    ///
+    /// By default the ratio may range from `0.0` to `1.0`.
+    pub fn ratio_limits(mut self, min: Scalar, max: Scalar) -> Self {
+        self.min_ratio = min;
+        self.max_ratio = max;
+        self
+    }
+
+    builder_methods! {
+        pub orientation { orientation = Orientation }
+        pub divider_thickness { style.divider_thickness = Some(Scalar) }
+        pub keyboard_step { style.keyboard_step = Some(Scalar) }
+        pub enabled { enabled = bool }
+    }
+}
+
+impl Widget for Splitter {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Splitter.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let Splitter {
+            ratio,
+            min_ratio,
+            max_ratio,
+            orientation,
+            enabled,
+            ..
+        } = self;
+
+        let thickness = style.divider_thickness(&ui.theme);
+        let length = match orientation {
+            Orientation::Vertical => rect.w(),
+            Orientation::Horizontal => rect.h(),
+        } - thickness;
+
+        // Resize the panes by dragging the divider or by pressing the arrow keys while focused.
+        let mut new_ratio = clamp_ratio(ratio, min_ratio, max_ratio);
+        if enabled {
+            let divider_input = ui.widget_input(state.ids.divider);
+            if length > 0.0 {
+                for drag in divider_input.drags().left() {
+                    let delta = match orientation {
+                        Orientation::Vertical => drag.delta_xy[0],
+                        Orientation::Horizontal => -drag.delta_xy[1],
+                    };
+                    new_ratio = clamp_ratio(new_ratio + delta / length, min_ratio, max_ratio);
+                }
+            }
+            let step = style.keyboard_step(&ui.theme);
+            for press in divider_input.presses().key() {
+                let direction = match (orientation, press.key) {
+                    (Orientation::Vertical, input::Key::Left)
+                    | (Orientation::Horizontal, input::Key::Up) => -1.0,
+                    (Orientation::Vertical, input::Key::Right)
+                    | (Orientation::Horizontal, input::Key::Down) => 1.0,
+                    _ => continue,
+                };
+                new_ratio = clamp_ratio(new_ratio + direction * step, min_ratio, max_ratio);
+            }
+        }
+
+        let (first, divider, second) = split(rect, new_ratio, thickness, orientation);
+
+        // Highlight the divider while it is focused.
+        let color = style.color(&ui.theme);
+        let color =
+            if ui.global_input().current.widget_capturing_keyboard == Some(state.ids.divider) {
+                color.highlighted()
+            } else {
+                color
+            };
+        widget::Rectangle::fill_with(divider.dim(), color)
+            .xy(divider.xy())
+            .parent(id)
+            .set(state.ids.divider, ui);
+        if enabled {
+            let cursor = match orientation {
+                Orientation::Vertical => cursor::MouseCursor::ResizeHorizontal,
+                Orientation::Horizontal => cursor::MouseCursor::ResizeVertical,
+            };
+            ui.request_mouse_cursor(state.ids.divider, cursor);
+        }
+
+        Event {
+            first: first,
+            second: second,
+            new_ratio: if new_ratio != ratio {
+                Some(new_ratio)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl Colorable for Splitter {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+/// Split the given `rect` into the first pane, the divider and the second pane respectively.
+pub fn split(
+    rect: Rect,
+    ratio: Scalar,
+    thickness: Scalar,
+    orientation: Orientation,
+) -> (Rect, Rect, Rect) {
+    match orientation {
+        Orientation::Vertical => {
+            let length = (rect.w() - thickness).max(0.0);
+            let a = rect.left() + length * ratio;
+            let b = a + thickness;
+            (
+                Rect::from_corners([rect.left(), rect.bottom()], [a, rect.top()]),
+                Rect::from_corners([a, rect.bottom()], [b, rect.top()]),
+                Rect::from_corners([b, rect.bottom()], [rect.right(), rect.top()]),
+            )
+        }
+        Orientation::Horizontal => {
+            let length = (rect.h() - thickness).max(0.0);
+            let a = rect.top() - length * ratio;
+            let b = a - thickness;
+            (
+                Rect::from_corners([rect.left(), a], [rect.right(), rect.top()]),
+                Rect::from_corners([rect.left(), b], [rect.right(), a]),
+                Rect::from_corners([rect.left(), rect.bottom()], [rect.right(), b]),
+            )
+        }
+    }
+}
+
+// Clamp the ratio to the given limits, which are themselves clamped to `0.0..1.0`.
+fn clamp_ratio(ratio: Scalar, min: Scalar, max: Scalar) -> Scalar {
+    let min = clamp(min, 0.0, 1.0);
+    let max = clamp(max, min, 1.0);
+    clamp(ratio, min, max)
+}
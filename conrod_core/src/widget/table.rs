@@ -0,0 +1,438 @@
+//! A table of rows and columns with a header of resizable, sortable columns.
+
+use color;
+use position::{Rect, Scalar};
+use std;
+use widget;
+use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Sizeable, UiCell, Widget};
+
+/// A table of rows and columns topped by a header containing a label for each column.
+///
+/// Each column may be resized by dragging the divider on the right edge of its header, while
+/// clicking the header of a column cycles its sort order from ascending, to descending, to
+/// unsorted. The column widths and the current sort are stored within the `Table`'s `State`.
+///
+/// The `Table` only sorts its *indicator*, not the rows themselves - the user is expected to order
+/// their data according to the `sort` reported within the `Event`.
+///
+/// The body of the `Table` scrolls both vertically and horizontally (in the case that the columns
+/// are wider than the `Table`) and only yields `Cell`s for rows that are visible.
+#[derive(Clone, WidgetCommon_)]
+#[allow(missing_copy_implementations)]
+pub struct Table<'a> {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    columns: &'a [&'a str],
+    num_rows: usize,
+    style: Style,
+}
+
+/// Unique styling for the `Table`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the body of the table.
+    #[conrod(default = "theme.background_color")]
+    pub color: Option<Color>,
+    /// The color of the header of each column.
+    #[conrod(default = "theme.shape_color")]
+    pub header_color: Option<Color>,
+    /// The color of the dividers between the headers of each column.
+    #[conrod(default = "theme.border_color")]
+    pub divider_color: Option<Color>,
+    /// The width of the dividers between the headers of each column.
+    #[conrod(default = "4.0")]
+    pub divider_width: Option<Scalar>,
+    /// The height of the header.
+    #[conrod(default = "30.0")]
+    pub header_height: Option<Scalar>,
+    /// The height of each row.
+    #[conrod(default = "24.0")]
+    pub row_height: Option<Scalar>,
+    /// The initial width of each column.
+    #[conrod(default = "100.0")]
+    pub column_width: Option<Scalar>,
+    /// The width below which columns may not be resized.
+    #[conrod(default = "20.0")]
+    pub min_column_width: Option<Scalar>,
+    /// The color of the header labels and sort indicators.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size of the header labels.
+    #[conrod(default = "theme.font_size_small")]
+    pub label_font_size: Option<FontSize>,
+}
+
+widget_ids! {
+    struct Ids {
+        headers[],
+        sort_indicator,
+        dividers[],
+        body,
+        content,
+        cells[],
+        x_scrollbar,
+        y_scrollbar,
+    }
+}
+
+/// The state of the `Table`.
+pub struct State {
+    ids: Ids,
+    column_widths: Vec<Scalar>,
+    sort: Option<Sort>,
+}
+
+/// The column by which the rows of the `Table` should be sorted.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sort {
+    /// The index of the column.
+    pub column: usize,
+    /// Whether the rows should be sorted in ascending (`true`) or descending (`false`) order.
+    pub ascending: bool,
+}
+
+/// The `Event` type yielded by the `Table`.
+#[derive(Clone, Debug)]
+pub struct Event {
+    /// The `Cell`s of the visible rows, for instantiating the content of each.
+    pub cells: Cells,
+    /// The current sort of the `Table`'s rows.
+    pub sort: Option<Sort>,
+    /// Whether or not the `sort` was changed by the user during this update.
+    pub sort_changed: bool,
+}
+
+/// An iterator yielding a `Cell` for every column of each visible row.
+#[derive(Clone, Debug)]
+pub struct Cells {
+    cells: std::vec::IntoIter<Cell>,
+}
+
+/// Data necessary for instantiating the content of a single cell.
+#[derive(Copy, Clone, Debug)]
+pub struct Cell {
+    /// The id generated for the widget.
+    pub widget_id: widget::Id,
+    /// The index of the row.
+    pub row: usize,
+    /// The index of the column.
+    pub column: usize,
+    /// The width of the cell.
+    pub w: Scalar,
+    /// The height of the cell.
+    pub h: Scalar,
+    /// The *x* position of the cell relative to the centre of the table's scrolled content.
+    rel_x: Scalar,
+    /// The *y* position of the cell relative to the centre of the table's scrolled content.
+    rel_y: Scalar,
+    /// The id of the table's scrolled content, upon which the cell is placed.
+    content_id: widget::Id,
+}
+
+impl<'a> Table<'a> {
+    /// Begin building a `Table` with the given column labels and number of rows.
+    pub fn new(columns: &'a [&'a str], num_rows: usize) -> Self {
+        Table {
+            common: widget::CommonBuilder {
+                crop_kids: true,
+                ..widget::CommonBuilder::default()
+            },
+            columns: columns,
+            num_rows: num_rows,
+            style: Style::default(),
+        }
+    }
+
+    builder_methods! {
+        pub header_color { style.header_color = Some(Color) }
+        pub divider_color { style.divider_color = Some(Color) }
+        pub divider_width { style.divider_width = Some(Scalar) }
+        pub header_height { style.header_height = Some(Scalar) }
+        pub row_height { style.row_height = Some(Scalar) }
+        pub column_width { style.column_width = Some(Scalar) }
+        pub min_column_width { style.min_column_width = Some(Scalar) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+    }
+}
+
+impl State {
+    /// The width of each column.
+    pub fn column_widths(&self) -> &[Scalar] {
+        &self.column_widths
+    }
+
+    /// The current sort of the `Table`'s rows.
+    pub fn sort(&self) -> Option<Sort> {
+        self.sort
+    }
+}
+
+impl<'a> Widget for Table<'a> {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+            column_widths: Vec::new(),
+            sort: None,
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Table.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let Table {
+            columns, num_rows, ..
+        } = self;
+        let num_columns = columns.len();
+
+        // Ensure there is a width and an id for the header and divider of every column.
+        if state.column_widths.len() != num_columns {
+            let column_width = style.column_width(&ui.theme);
+            state.update(|state| state.column_widths.resize(num_columns, column_width));
+        }
+        if state.ids.headers.len() < num_columns {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| {
+                state.ids.headers.resize(num_columns, id_gen);
+                state.ids.dividers.resize(num_columns, id_gen);
+            });
+        }
+
+        // Resize columns by dragging their dividers.
+        let min_column_width = style.min_column_width(&ui.theme);
+        for i in 0..num_columns {
+            let delta: Scalar = ui
+                .widget_input(state.ids.dividers[i])
+                .drags()
+                .left()
+                .map(|drag| drag.delta_xy[0])
+                .sum();
+            if delta != 0.0 {
+                let width = (state.column_widths[i] + delta).max(min_column_width);
+                state.update(|state| state.column_widths[i] = width);
+            }
+        }
+
+        let header_h = style.header_height(&ui.theme).min(rect.h());
+        let row_h = style.row_height(&ui.theme);
+        let total_w: Scalar = state.column_widths.iter().sum();
+        let total_h = row_h * num_rows as Scalar;
+        let header_rect = Rect::from_corners(
+            [rect.left(), rect.top() - header_h],
+            [rect.right(), rect.top()],
+        );
+        let body_rect = Rect::from_corners(
+            [rect.left(), rect.bottom()],
+            [rect.right(), rect.top() - header_h],
+        );
+
+        // The scrollable body along with the content upon which the cells are placed.
+        let color = style.color(&ui.theme);
+        widget::Canvas::new()
+            .wh(body_rect.dim())
+            .xy(body_rect.xy())
+            .color(color)
+            .border(0.0)
+            .scroll_kids()
+            .parent(id)
+            .set(state.ids.body, ui);
+        widget::Rectangle::fill([total_w, total_h])
+            .color(color::TRANSPARENT)
+            .top_left_of(state.ids.body)
+            .parent(state.ids.body)
+            .set(state.ids.content, ui);
+        let content_rect = ui.rect_of(state.ids.content).unwrap_or(body_rect);
+
+        // The header of each column, scrolled horizontally along with the body.
+        let header_color = style.header_color(&ui.theme);
+        let label_color = style.label_color(&ui.theme);
+        let label_font_size = style.label_font_size(&ui.theme);
+        let divider_color = style.divider_color(&ui.theme);
+        let divider_w = style.divider_width(&ui.theme);
+        // The sorted column may have been removed since the last update.
+        let mut sort = state.sort.filter(|sort| sort.column < num_columns);
+        let mut left = content_rect.left();
+        for (i, &label) in columns.iter().enumerate() {
+            let w = state.column_widths[i];
+            let header_rect =
+                Rect::from_corners([left, header_rect.bottom()], [left + w, header_rect.top()]);
+
+            let clicks = widget::Button::new()
+                .wh(header_rect.dim())
+                .xy(header_rect.xy())
+                .color(header_color)
+                .border(0.0)
+                .label(label)
+                .label_color(label_color)
+                .label_font_size(label_font_size)
+                .parent(id)
+                .set(state.ids.headers[i], ui);
+            for _click in clicks {
+                sort = next_sort(sort, i);
+            }
+
+            left += w;
+        }
+
+        // Indicate the sorted column with a triangle on the right of its header.
+        if let Some(Sort { column, ascending }) = sort {
+            let right =
+                content_rect.left() + state.column_widths[..column + 1].iter().sum::<Scalar>();
+            let half = (header_h / 6.0).min(state.column_widths[column] / 4.0);
+            let [x, y] = [right - divider_w - half * 2.0, header_rect.y()];
+            let points = if ascending {
+                [[x - half, y - half], [x + half, y - half], [x, y + half]]
+            } else {
+                [[x - half, y + half], [x + half, y + half], [x, y - half]]
+            };
+            widget::Polygon::abs_fill_with(points, label_color)
+                .parent(id)
+                .graphics_for(state.ids.headers[column])
+                .set(state.ids.sort_indicator, ui);
+        }
+
+        // The dividers are instantiated after the headers so that they're picked first.
+        let mut right = content_rect.left();
+        for i in 0..num_columns {
+            right += state.column_widths[i];
+            widget::Rectangle::fill([divider_w, header_h])
+                .x_y(right - divider_w / 2.0, header_rect.y())
+                .color(divider_color)
+                .parent(id)
+                .set(state.ids.dividers[i], ui);
+        }
+
+        let sort_changed = sort != state.sort;
+        if sort_changed {
+            state.update(|state| state.sort = sort);
+        }
+
+        // Only yield cells for the visible rows.
+        let scrolled = content_rect.top() - body_rect.top();
+        let (start_row, end_row) = if row_h > 0.0 {
+            let start = (scrolled / row_h).floor().max(0.0) as usize;
+            let end = ((scrolled + body_rect.h()) / row_h).ceil().max(0.0) as usize;
+            (start.min(num_rows), end.min(num_rows))
+        } else {
+            (0, 0)
+        };
+        let num_cells = (end_row - start_row) * num_columns;
+        if state.ids.cells.len() < num_cells {
+            let id_gen = &mut ui.widget_id_generator();
+            state.update(|state| state.ids.cells.resize(num_cells, id_gen));
+        }
+        let mut cells = Vec::with_capacity(num_cells);
+        let mut cell_ids = state.ids.cells.iter().cloned();
+        for row in start_row..end_row {
+            let rel_y = total_h / 2.0 - (row as Scalar + 0.5) * row_h;
+            let mut left = -total_w / 2.0;
+            for column in 0..num_columns {
+                let w = state.column_widths[column];
+                cells.push(Cell {
+                    widget_id: cell_ids.next().unwrap(),
+                    row: row,
+                    column: column,
+                    w: w,
+                    h: row_h,
+                    rel_x: left + w / 2.0,
+                    rel_y: rel_y,
+                    content_id: state.ids.content,
+                });
+                left += w;
+            }
+        }
+
+        widget::Scrollbar::x_axis(state.ids.body)
+            .auto_hide(true)
+            .set(state.ids.x_scrollbar, ui);
+        widget::Scrollbar::y_axis(state.ids.body)
+            .auto_hide(true)
+            .set(state.ids.y_scrollbar, ui);
+
+        Event {
+            cells: Cells {
+                cells: cells.into_iter(),
+            },
+            sort: sort,
+            sort_changed: sort_changed,
+        }
+    }
+}
+
+impl<'a> Colorable for Table<'a> {
+    builder_method!(color { style.color = Some(Color) });
+}
+
+/// The sort after clicking the header of the given column.
+///
+/// Clicking a column cycles its sort from ascending, to descending, to unsorted.
+fn next_sort(sort: Option<Sort>, column: usize) -> Option<Sort> {
+    match sort {
+        Some(Sort {
+            column: c,
+            ascending: true,
+        }) if c == column => Some(Sort {
+            column: column,
+            ascending: false,
+        }),
+        Some(Sort {
+            column: c,
+            ascending: false,
+        }) if c == column => None,
+        _ => Some(Sort {
+            column: column,
+            ascending: true,
+        }),
+    }
+}
+
+impl Iterator for Cells {
+    type Item = Cell;
+    fn next(&mut self) -> Option<Cell> {
+        self.cells.next()
+    }
+}
+
+impl Cell {
+    /// Sets the given widget as the widget to use for the cell.
+    ///
+    /// Sets the:
+    /// - position of the widget.
+    /// - dimensions of the widget.
+    /// - parent of the widget.
+    /// - and finally sets the widget within the `Ui`.
+    pub fn set<W>(self, widget: W, ui: &mut UiCell) -> W::Event
+    where
+        W: Widget,
+    {
+        let Cell {
+            widget_id,
+            w,
+            h,
+            rel_x,
+            rel_y,
+            content_id,
+            ..
+        } = self;
+        widget
+            .w_h(w, h)
+            .x_y_relative_to(content_id, rel_x, rel_y)
+            .parent(content_id)
+            .set(widget_id, ui)
+    }
+}
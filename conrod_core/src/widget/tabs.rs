@@ -14,6 +14,8 @@ pub struct Tabs<'a> {
     tabs: &'a [(widget::Id, &'a str)],
     style: Style,
     maybe_starting_tab_idx: Option<usize>,
+    reorderable: bool,
+    closable: bool,
 }
 
 /// The state to be cached within the Canvas.
@@ -31,10 +33,32 @@ pub struct Tab {
     id: widget::Id,
     /// The **Tab**'s selectable **Button**.
     button_id: widget::Id,
+    /// The **Button** used to close the **Tab** when the **Tabs** are closable.
+    close_button_id: widget::Id,
 }
 
-/// The padding between the edge of the title bar and the title bar's label.
-const TAB_BAR_LABEL_PADDING: f64 = 4.0;
+/// Events produced by the `Tabs` widget.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    /// The user dragged a tab to a new position.
+    Reorder(Reorder),
+    /// The close button of the tab with the given `widget::Id` was clicked.
+    ///
+    /// The tab remains until it is removed from the slice of tabs given to the widget.
+    Close(widget::Id),
+}
+
+/// A tab that the user has dragged from one index within the tab bar to another.
+///
+/// The `Tabs` widget immediately draws its tab bar in the new order, however the same reordering
+/// should be applied to the slice of tabs given to the widget, e.g. via `Reorder::apply`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Reorder {
+    /// The index of the tab before it was moved.
+    pub from: usize,
+    /// The index of the tab after it was moved.
+    pub to: usize,
+}
 
 /// Unique styling for the `Tabs` widget.
 #[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
@@ -57,6 +81,9 @@ pub struct Style {
     /// The styling for each `Canvas`.
     #[conrod(default = "widget::canvas::Style::default()")]
     pub canvas: Option<widget::canvas::Style>,
+    /// Whether or not the labels of a vertical tab bar run from bottom to top.
+    #[conrod(default = "false")]
+    pub rotate_vertical_labels: Option<bool>,
 }
 
 /// The direction in which the tabs will be laid out.
@@ -76,6 +103,8 @@ impl<'a> Tabs<'a> {
             style: Style::default(),
             tabs: tabs,
             maybe_starting_tab_idx: None,
+            reorderable: false,
+            closable: false,
         }
     }
 
@@ -169,17 +198,53 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// When the tabs are laid out vertically, rotate each label a quarter turn anticlockwise.
+    ///
+    /// The width of the tab bar is then only required to fit the height of a line of text rather
+    /// than the width of the widest label.
+    pub fn rotate_vertical_labels(mut self, rotate: bool) -> Self {
+        self.style.rotate_vertical_labels = Some(rotate);
+        self
+    }
+
     builder_methods! {
         pub starting_tab_idx { maybe_starting_tab_idx = Some(usize) }
         pub label_color { style.label_color = Some(Color) }
         pub label_font_size { style.label_font_size = Some(FontSize) }
+        pub reorderable { reorderable = bool }
+        pub closable { closable = bool }
+    }
+}
+
+impl Reorder {
+    /// Move the element at the `from` index to the `to` index, shifting the elements in between.
+    pub fn apply<T>(&self, slice: &mut [T]) {
+        if self.from < self.to {
+            slice[self.from..=self.to].rotate_left(1);
+        } else {
+            slice[self.to..=self.from].rotate_right(1);
+        }
+    }
+
+    /// The index at which the element that was at the given `idx` lies after the reordering.
+    pub fn reordered_idx(&self, idx: usize) -> usize {
+        let Reorder { from, to } = *self;
+        if idx == from {
+            to
+        } else if from < idx && idx <= to {
+            idx - 1
+        } else if to <= idx && idx < from {
+            idx + 1
+        } else {
+            idx
+        }
     }
 }
 
 impl<'a> Widget for Tabs<'a> {
     type State = State;
     type Style = Style;
-    type Event = ();
+    type Event = Vec<Event>;
 
     fn init_state(&self, _: widget::id::Generator) -> Self::State {
         State {
@@ -192,6 +257,26 @@ impl<'a> Widget for Tabs<'a> {
         self.style.clone()
     }
 
+    fn saved_state_type() -> &'static str {
+        "Tabs"
+    }
+
+    /// Saves the index of the selected tab.
+    fn save_state(state: &Self::State) -> Option<String> {
+        state.maybe_selected_tab_idx.map(|idx| idx.to_string())
+    }
+
+    /// Restores the index of the selected tab.
+    fn restore_state(state: &mut Self::State, saved: &str) -> bool {
+        match saved.parse() {
+            Ok(idx) => {
+                state.maybe_selected_tab_idx = Some(idx);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
     /// The area on which child widgets will be placed when using the `Place` Positionable methods.
     fn kid_area(&self, args: widget::KidAreaArgs<Self>) -> widget::KidArea {
         let widget::KidAreaArgs {
@@ -203,22 +288,29 @@ impl<'a> Widget for Tabs<'a> {
         let font_size = style.label_font_size(theme);
         let bar_thickness = style.bar_thickness(theme);
         let canvas_style = style.canvas(theme);
+        let label_padding = theme.spacing_xs;
         match style.layout(theme) {
             Layout::Horizontal => {
-                let tab_bar_h = horizontal_tab_bar_h(bar_thickness, font_size as Scalar);
+                let tab_bar_h =
+                    horizontal_tab_bar_h(bar_thickness, font_size as Scalar, label_padding);
                 widget::KidArea {
                     rect: rect.pad_top(tab_bar_h),
                     pad: canvas_style.padding(theme),
                 }
             }
             Layout::Vertical => {
-                let max_text_width = style
-                    .font_id(theme)
-                    .or(fonts.ids().next())
-                    .and_then(|id| fonts.get(id))
-                    .map(|font| max_text_width(self.tabs.iter(), font_size, font))
-                    .unwrap_or(0.0);
-                let tab_bar_w = vertical_tab_bar_w(bar_thickness, max_text_width as Scalar);
+                let max_text_width = if style.rotate_vertical_labels(theme) {
+                    font_size as Scalar
+                } else {
+                    style
+                        .font_id(theme)
+                        .or(fonts.ids().next())
+                        .and_then(|id| fonts.get(id))
+                        .map(|font| max_text_width(self.tabs.iter(), font_size, font))
+                        .unwrap_or(0.0)
+                };
+                let tab_bar_w =
+                    vertical_tab_bar_w(bar_thickness, max_text_width as Scalar, label_padding);
                 widget::KidArea {
                     rect: rect.pad_left(tab_bar_w),
                     pad: canvas_style.padding(theme),
@@ -228,7 +320,7 @@ impl<'a> Widget for Tabs<'a> {
     }
 
     /// Update the state of the Tabs.
-    fn update(self, args: widget::UpdateArgs<Self>) {
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
             id,
             state,
@@ -240,24 +332,44 @@ impl<'a> Widget for Tabs<'a> {
         let Tabs {
             tabs,
             maybe_starting_tab_idx,
+            reorderable,
+            closable,
             ..
         } = self;
         let layout = style.layout(&ui.theme);
         let font_size = style.label_font_size(&ui.theme);
         let canvas_style = style.canvas(&ui.theme);
-        let max_text_width = style
-            .font_id(&ui.theme)
-            .or(ui.fonts.ids().next())
-            .and_then(|id| ui.fonts.get(id))
-            .map(|font| max_text_width(self.tabs.iter(), font_size, font))
-            .unwrap_or(0.0);
+        let rotate_labels = layout == Layout::Vertical && style.rotate_vertical_labels(&ui.theme);
+        // Rotated labels only need to fit the height of a line of text across the tab bar.
+        let max_text_width = if rotate_labels {
+            font_size as Scalar
+        } else {
+            style
+                .font_id(&ui.theme)
+                .or(ui.fonts.ids().next())
+                .and_then(|id| ui.fonts.get(id))
+                .map(|font| max_text_width(self.tabs.iter(), font_size, font))
+                .unwrap_or(0.0)
+        };
+        let label_orientation = if rotate_labels {
+            widget::text::Orientation::Vertical
+        } else {
+            widget::text::Orientation::Horizontal
+        };
 
         // Calculate the area of the tab bar.
         let font_height = font_size as Scalar;
         let bar_thickness = style.bar_thickness(&ui.theme);
         let dim = rect.dim();
-        let rel_tab_bar_rect =
-            rel_tab_bar_area(dim, layout, bar_thickness, font_height, max_text_width);
+        let label_padding = ui.theme.spacing_xs;
+        let rel_tab_bar_rect = rel_tab_bar_area(
+            dim,
+            layout,
+            bar_thickness,
+            font_height,
+            max_text_width,
+            label_padding,
+        );
 
         // Update the `tabs` **Vec** stored within our **State**, only if there have been changes.
         let tabs_have_changed = state.tabs.len() != tabs.len()
@@ -282,12 +394,54 @@ impl<'a> Widget for Tabs<'a> {
                     let extension = tabs[num_tabs..].iter().map(|&(id, _)| Tab {
                         id: id,
                         button_id: id_gen.next(),
+                        close_button_id: id_gen.next(),
                     });
                     state.tabs.extend(extension);
                 }
             });
         }
 
+        // If a tab is being dragged, move it to the position of the tab nearest to the mouse.
+        let mut maybe_reorder = None;
+        if reorderable {
+            for (i, tab) in state.tabs.iter().enumerate() {
+                let input = ui.widget_input(tab.button_id);
+                let drag_xy = match (input.drags().left().last(), ui.rect_of(tab.button_id)) {
+                    (Some(drag), Some(button_rect)) => utils::vec2_add(button_rect.xy(), drag.to),
+                    _ => continue,
+                };
+                let rel_drag_xy = utils::vec2_sub(drag_xy, rect.xy());
+                let target_idx = nearest_tab_idx(tabs, layout, rel_tab_bar_rect, rel_drag_xy);
+                if target_idx != i {
+                    maybe_reorder = Some(Reorder {
+                        from: i,
+                        to: target_idx,
+                    });
+                }
+                break;
+            }
+        }
+
+        // Reorder our own tabs so that each `Button` follows its tab to its new position.
+        let reordered_tabs;
+        let tabs = match maybe_reorder {
+            None => tabs,
+            Some(reorder) => {
+                state.update(|state| {
+                    reorder.apply(&mut state.tabs);
+                    state.maybe_selected_tab_idx = state
+                        .maybe_selected_tab_idx
+                        .map(|idx| reorder.reordered_idx(idx));
+                });
+                let mut tabs = tabs.to_vec();
+                reorder.apply(&mut tabs);
+                reordered_tabs = tabs;
+                &reordered_tabs[..]
+            }
+        };
+
+        let mut events: Vec<Event> = maybe_reorder.into_iter().map(Event::Reorder).collect();
+
         // Instantiate the widgets associated with each Tab.
         let maybe_selected_tab_idx = {
             let color = canvas_style.color(&ui.theme);
@@ -296,6 +450,7 @@ impl<'a> Widget for Tabs<'a> {
             let label_color = style.label_color(ui.theme());
             let mut maybe_selected_tab_idx = state
                 .maybe_selected_tab_idx
+                .filter(|&idx| idx < tabs.len())
                 .or(maybe_starting_tab_idx)
                 .or_else(|| if tabs.len() > 0 { Some(0) } else { None });
             let mut tab_rects = TabRects::new(tabs, layout, rel_tab_bar_rect);
@@ -303,6 +458,15 @@ impl<'a> Widget for Tabs<'a> {
             while let Some((tab_rect, _, label)) = tab_rects.next_with_id_and_label() {
                 use {Borderable, Colorable, Labelable, Positionable, Sizeable};
                 let tab = state.tabs[i];
+
+                // Reserve the right edge of a closable tab for its close button.
+                let (tab_rect, maybe_close_rect) = if closable {
+                    let close_w = tab_rect.h().min(tab_rect.w() / 2.0);
+                    let close_rect = tab_rect.pad_left(tab_rect.w() - close_w);
+                    (tab_rect.pad_right(close_w), Some(close_rect))
+                } else {
+                    (tab_rect, None)
+                };
                 let (xy, dim) = tab_rect.xy_dim();
 
                 // We'll instantiate each selectable **Tab** as a **Button** widget.
@@ -314,6 +478,7 @@ impl<'a> Widget for Tabs<'a> {
                     .border_color(border_color)
                     .label(label)
                     .label_color(label_color)
+                    .label_orientation(label_orientation)
                     .parent(id)
                     .set(tab.button_id, &mut ui)
                     .was_clicked()
@@ -321,6 +486,24 @@ impl<'a> Widget for Tabs<'a> {
                     maybe_selected_tab_idx = Some(i);
                 }
 
+                if let Some(close_rect) = maybe_close_rect {
+                    let close_rect = close_rect.pad(close_rect.w() / 4.0);
+                    if widget::Button::new()
+                        .wh(close_rect.dim())
+                        .xy_relative_to(id, close_rect.xy())
+                        .color(color)
+                        .border(border)
+                        .border_color(border_color)
+                        .label("x")
+                        .label_color(label_color)
+                        .parent(id)
+                        .set(tab.close_button_id, ui)
+                        .was_clicked()
+                    {
+                        events.push(Event::Close(tab.id));
+                    }
+                }
+
                 i += 1;
             }
             maybe_selected_tab_idx
@@ -342,7 +525,36 @@ impl<'a> Widget for Tabs<'a> {
                 .parent(id)
                 .set(child_id, &mut ui);
         }
+
+        events
+    }
+}
+
+/// The index of the tab whose centre is nearest to the given point along the layout's axis.
+///
+/// Points beyond either end of the tab bar produce the index of the first or last tab.
+fn nearest_tab_idx(
+    tabs: &[(widget::Id, &str)],
+    layout: Layout,
+    rel_tab_bar_rect: Rect,
+    rel_xy: Point,
+) -> usize {
+    let axis = match layout {
+        Layout::Horizontal => 0,
+        Layout::Vertical => 1,
+    };
+    let mut tab_rects = TabRects::new(tabs, layout, rel_tab_bar_rect);
+    let mut nearest: Option<(usize, Scalar)> = None;
+    let mut i = 0;
+    while let Some((tab_rect, _, _)) = tab_rects.next_with_id_and_label() {
+        let distance = (tab_rect.xy()[axis] - rel_xy[axis]).abs();
+        match nearest {
+            Some((_, nearest_distance)) if nearest_distance <= distance => (),
+            _ => nearest = Some((i, distance)),
+        }
+        i += 1;
     }
+    nearest.map(|(idx, _)| idx).unwrap_or(0)
 }
 
 /// Calculate the max text width yielded by a string in the tabs slice.
@@ -367,17 +579,18 @@ fn rel_tab_bar_area(
     maybe_bar_thickness: Option<Scalar>,
     font_size: f64,
     max_text_width: f64,
+    label_padding: Scalar,
 ) -> Rect {
     match layout {
         Layout::Horizontal => {
             let w = dim[0];
-            let h = horizontal_tab_bar_h(maybe_bar_thickness, font_size);
+            let h = horizontal_tab_bar_h(maybe_bar_thickness, font_size, label_padding);
             let x = 0.0;
             let y = dim[1] / 2.0 - h / 2.0;
             Rect::from_xy_dim([x, y], [w, h])
         }
         Layout::Vertical => {
-            let w = vertical_tab_bar_w(maybe_bar_thickness, max_text_width);
+            let w = vertical_tab_bar_w(maybe_bar_thickness, max_text_width, label_padding);
             let h = dim[1];
             let x = -dim[0] / 2.0 + w / 2.0;
             let y = 0.0;
@@ -387,13 +600,25 @@ fn rel_tab_bar_area(
 }
 
 /// The height of a horizontally laid out tab bar area.
-fn horizontal_tab_bar_h(maybe_bar_thickness: Option<Scalar>, font_size: Scalar) -> Scalar {
-    maybe_bar_thickness.unwrap_or_else(|| font_size + TAB_BAR_LABEL_PADDING * 2.0)
+///
+/// The `label_padding` is the padding between the edge of the tab bar and the tab labels.
+fn horizontal_tab_bar_h(
+    maybe_bar_thickness: Option<Scalar>,
+    font_size: Scalar,
+    label_padding: Scalar,
+) -> Scalar {
+    maybe_bar_thickness.unwrap_or_else(|| font_size + label_padding * 2.0)
 }
 
 /// The width of a vertically laid out tab bar area.
-fn vertical_tab_bar_w(maybe_bar_thickness: Option<Scalar>, max_text_width: Scalar) -> Scalar {
-    maybe_bar_thickness.unwrap_or_else(|| max_text_width + TAB_BAR_LABEL_PADDING * 2.0)
+///
+/// The `label_padding` is the padding between the edge of the tab bar and the tab labels.
+fn vertical_tab_bar_w(
+    maybe_bar_thickness: Option<Scalar>,
+    max_text_width: Scalar,
+    label_padding: Scalar,
+) -> Scalar {
+    maybe_bar_thickness.unwrap_or_else(|| max_text_width + label_padding * 2.0)
 }
 
 fn tab_dim(num_tabs: usize, tab_bar_dim: Dimensions, layout: Layout) -> Dimensions {
@@ -447,7 +672,7 @@ impl<'a> TabRects<'a> {
         let start_xy = start_tab_rect.xy();
         let xy_step = match layout {
             Layout::Horizontal => [tab_dim[0], 0.0],
-            Layout::Vertical => [0.0, tab_dim[1]],
+            Layout::Vertical => [0.0, -tab_dim[1]],
         };
         TabRects {
             tabs: tabs.iter(),
@@ -17,6 +17,8 @@ pub struct TextBox<'a> {
     common: widget::CommonBuilder,
     text: &'a str,
     style: Style,
+    /// The `String` to which the TextBox is bound, updated whenever the text is edited.
+    maybe_binding: Option<&'a mut String>,
 }
 
 /// Unique graphical styling for the TextBox.
@@ -70,9 +72,20 @@ impl<'a> TextBox<'a> {
             common: widget::CommonBuilder::default(),
             style: Style::default(),
             text: text,
+            maybe_binding: None,
         }
     }
 
+    /// Construct a TextBox widget bound to the given `String`.
+    ///
+    /// The TextBox displays the current `text`, which is updated in place whenever the user edits
+    /// it. The edits are still yielded as `Event::Update`s.
+    pub fn bind(text: &'a mut String) -> Self {
+        let mut text_box = TextBox::new("");
+        text_box.maybe_binding = Some(text);
+        text_box
+    }
+
     /// Align the text to the left of its bounding **Rect**'s *x* axis range.
     pub fn left_justify(self) -> Self {
         self.justify(text::Justify::Left)
@@ -136,7 +149,15 @@ impl<'a> Widget for TextBox<'a> {
             ui,
             ..
         } = args;
-        let TextBox { text, .. } = self;
+        let TextBox {
+            text,
+            maybe_binding,
+            ..
+        } = self;
+        let text = match maybe_binding {
+            Some(ref binding) => binding.as_str(),
+            None => text,
+        };
 
         let font_size = style.font_size(ui.theme());
         let border = style.border(ui.theme());
@@ -176,6 +197,9 @@ impl<'a> Widget for TextBox<'a> {
             .parent(id)
             .set(state.ids.text_edit, ui)
         {
+            if let Some(binding) = maybe_binding {
+                *binding = new_string.clone();
+            }
             events.push(Event::Update(new_string));
         }
 
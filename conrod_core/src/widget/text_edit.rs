@@ -197,6 +197,10 @@ impl<'a> Widget for TextEdit<'a> {
         self.style.clone()
     }
 
+    fn accepts_focus(&self) -> bool {
+        true
+    }
+
     fn default_y_dimension(&self, ui: &Ui) -> Dimension {
         // If the user has specified `restrict_to_height = true`, then we should infer the height
         // using the previous widget as is the default case.
@@ -247,6 +251,8 @@ impl<'a> Widget for TextEdit<'a> {
         let TextEdit { text, .. } = self;
         let mut text = std::borrow::Cow::Borrowed(text);
 
+        ui.request_mouse_cursor(id, cursor::MouseCursor::Text);
+
         // Retrieve the `font_id`, as long as a valid `Font` for it still exists.
         //
         // If we've no font to use for text logic, bail out without updating.
@@ -918,10 +924,6 @@ impl<'a> Widget for TextEdit<'a> {
             }
         }
 
-        if let Some(_) = ui.widget_input(id).mouse() {
-            ui.set_mouse_cursor(cursor::MouseCursor::Text);
-        }
-
         let cursor_has_changed = state.cursor != cursor;
         if cursor_has_changed {
             state.update(|state| state.cursor = cursor);
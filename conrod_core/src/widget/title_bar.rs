@@ -69,7 +69,7 @@ pub struct Style {
 
 /// The padding between the edge of the title bar and the title bar's label.
 ///
-/// This is used to determine the size of the TitleBar.
+/// This is used to determine the size of the TitleBar via `calc_height`.
 const LABEL_PADDING: f64 = 4.0;
 
 impl<'a> TitleBar<'a> {
@@ -126,8 +126,19 @@ impl<'a> TitleBar<'a> {
 }
 
 /// Calculate the default height for the **TitleBar**'s rect.
+///
+/// This assumes the padding of the default `Theme`'s `spacing_xs`. See
+/// `calc_height_with_label_padding` to specify the padding.
 pub fn calc_height(font_size: FontSize) -> Scalar {
-    font_size as Scalar + LABEL_PADDING * 2.0
+    calc_height_with_label_padding(font_size, LABEL_PADDING)
+}
+
+/// Calculate the default height for the **TitleBar**'s rect.
+///
+/// The `label_padding` is the padding between the edge of the title bar and its label, usually
+/// the `Theme`'s `spacing_xs`.
+pub fn calc_height_with_label_padding(font_size: FontSize, label_padding: Scalar) -> Scalar {
+    font_size as Scalar + label_padding * 2.0
 }
 
 impl<'a> Widget for TitleBar<'a> {
@@ -147,7 +158,7 @@ impl<'a> Widget for TitleBar<'a> {
 
     fn default_y_dimension(&self, ui: &Ui) -> Dimension {
         let font_size = self.style.font_size(&ui.theme);
-        let h = calc_height(font_size);
+        let h = calc_height_with_label_padding(font_size, ui.theme.spacing_xs);
         Dimension::Absolute(h)
     }
 
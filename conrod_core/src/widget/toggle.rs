@@ -1,7 +1,10 @@
 //! A button that allows for toggling boolean state.
 
-use position::{self, Align};
+use color;
+use position::{self, Align, Range, Rect};
+use std::cell::Cell;
 use text;
+use utils::map_range;
 use widget;
 use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Scalar, Widget};
 
@@ -10,17 +13,27 @@ use {Borderable, Color, Colorable, FontSize, Labelable, Positionable, Scalar, Wi
 /// Like the Button widget, it's reaction is triggered upon release and will return the new bool
 /// state.
 ///
-/// Note that the Toggle will not mutate the bool for you, you should do this yourself within the
-/// react function.
+/// Note that the Toggle will not mutate the bool for you unless it is constructed via
+/// `Toggle::bind`, otherwise you should do this yourself when handling its event.
+///
+/// Via `Toggle::tri_state`, the Toggle may also display an indeterminate value, e.g. for a
+/// "select all" checkbox whose children are only partially selected.
 #[derive(Clone, WidgetCommon_)]
 pub struct Toggle<'a> {
     #[conrod(common_builder)]
     common: widget::CommonBuilder,
-    value: bool,
+    /// The value displayed by the Toggle, where `None` is indeterminate.
+    value: Option<bool>,
+    /// The value to which an indeterminate Toggle resolves when clicked.
+    indeterminate_resolves_to: bool,
     maybe_label: Option<&'a str>,
     style: Style,
     /// If true, will allow user inputs. If false, will disallow user inputs.
     pub enabled: bool,
+    /// If true, the Toggle is drawn as a switch with a sliding knob.
+    switch: bool,
+    /// The value to which the Toggle is bound, updated whenever the Toggle is clicked.
+    maybe_binding: Option<&'a Cell<bool>>,
 }
 
 /// Styling for the Toggle including coloring, bordering and labelling.
@@ -50,11 +63,16 @@ pub struct Style {
     /// The position of the title bar's `Label` widget over the *y* axis.
     #[conrod(default = "position::Relative::Align(Align::Middle)")]
     pub label_y: Option<position::Relative>,
+    /// The color of the knob when the Toggle is drawn as a switch.
+    #[conrod(default = "color::WHITE")]
+    pub knob_color: Option<Color>,
 }
 
 widget_ids! {
     struct Ids {
         rectangle,
+        knob,
+        dash,
         label,
     }
 }
@@ -62,8 +80,15 @@ widget_ids! {
 /// The state of the Toggle.
 pub struct State {
     ids: Ids,
+    /// The position of the switch's knob, from `0.0` (off) to `1.0` (on).
+    knob_perc: Scalar,
+    /// The moment at which the knob last stepped towards its target, if it is animating.
+    maybe_last_knob_step: Option<instant::Instant>,
 }
 
+/// The duration in seconds taken for a switch's knob to slide from one side to the other.
+const KNOB_SLIDE_SECS: f64 = 0.15;
+
 /// The `Event` type yielded by the `Toggle` widget.
 ///
 /// Implements `Iterator` yielding a `bool` indicating the new state for each time the `Toggle` was
@@ -95,11 +120,51 @@ impl<'a> Toggle<'a> {
             common: widget::CommonBuilder::default(),
             style: Style::default(),
             maybe_label: None,
-            value: value,
+            value: Some(value),
+            indeterminate_resolves_to: true,
             enabled: true,
+            switch: false,
+            maybe_binding: None,
         }
     }
 
+    /// Construct a new Toggle widget bound to the given value.
+    ///
+    /// The Toggle displays the current `value`, which is updated in place whenever the Toggle is
+    /// clicked. The clicks are still yielded as the Toggle's event.
+    pub fn bind(value: &'a mut bool) -> Toggle<'a> {
+        let mut toggle = Toggle::new(*value);
+        toggle.maybe_binding = Some(Cell::from_mut(value));
+        toggle
+    }
+
+    /// Display the given value, where `None` is indeterminate.
+    ///
+    /// An indeterminate Toggle is drawn with a dash across its middle, or with its knob in the
+    /// middle of its track if drawn as a switch. Clicking it yields the value given to
+    /// `resolve_indeterminate_to`, which is `true` by default. A bound value is only updated once
+    /// the Toggle is clicked.
+    pub fn tri_state(mut self, value: Option<bool>) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// The value to which an indeterminate Toggle resolves when clicked. Defaults to `true`.
+    pub fn resolve_indeterminate_to(mut self, value: bool) -> Self {
+        self.indeterminate_resolves_to = value;
+        self
+    }
+
+    /// Whether or not the Toggle is drawn as a switch: a pill-shaped track with a knob that slides
+    /// to the right side when on and to the left side when off. Defaults to `false`.
+    ///
+    /// The knob animates between sides rather than snapping, requesting continuous updates from
+    /// the `Ui` while it slides.
+    pub fn style_as_switch(mut self, switch: bool) -> Self {
+        self.switch = switch;
+        self
+    }
+
     /// Specify the font used for displaying the label.
     pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
         self.style.label_font_id = Some(Some(font_id));
@@ -120,6 +185,7 @@ impl<'a> Toggle<'a> {
 
     builder_methods! {
         pub enabled { enabled = bool }
+        pub knob_color { style.knob_color = Some(Color) }
     }
 }
 
@@ -131,6 +197,8 @@ impl<'a> Widget for Toggle<'a> {
     fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
         State {
             ids: Ids::new(id_gen),
+            knob_perc: knob_perc(self.value),
+            maybe_last_knob_step: None,
         }
     }
 
@@ -138,6 +206,10 @@ impl<'a> Widget for Toggle<'a> {
         self.style.clone()
     }
 
+    fn accepts_focus(&self) -> bool {
+        self.enabled
+    }
+
     /// Update the state of the Toggle.
     fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
         let widget::UpdateArgs {
@@ -150,13 +222,17 @@ impl<'a> Widget for Toggle<'a> {
         } = args;
         let Toggle {
             value,
+            indeterminate_resolves_to,
             enabled,
             maybe_label,
+            switch,
+            maybe_binding,
             ..
         } = self;
 
+        // An indeterminate value resolves upon the first click.
         let times_clicked = TimesClicked {
-            state: value,
+            state: value.unwrap_or(!indeterminate_resolves_to),
             count: if enabled {
                 let input = ui.widget_input(id);
                 (input.clicks().left().count() + input.taps().count()) as u16
@@ -168,15 +244,19 @@ impl<'a> Widget for Toggle<'a> {
         // BorderedRectangle widget.
         let dim = rect.dim();
         let border = style.border(ui.theme());
+        let new_value = times_clicked.clone().last().or(value);
+        if let (Some(binding), Some(new_value)) = (maybe_binding, new_value) {
+            binding.set(new_value);
+        }
         let color = {
             let color = style.color(ui.theme());
-            let new_value = times_clicked.clone().last().unwrap_or(value);
-            let color = if new_value {
+            let color = if new_value == Some(true) {
                 color
             } else {
                 color.with_luminance(0.1)
             };
             match ui.widget_input(id).mouse() {
+                _ if !enabled => ui.theme.disabled(color),
                 Some(mouse) => {
                     if mouse.buttons.left().is_down() {
                         color.clicked()
@@ -187,14 +267,71 @@ impl<'a> Widget for Toggle<'a> {
                 None => color,
             }
         };
-        let border_color = style.border_color(ui.theme());
-        widget::BorderedRectangle::new(dim)
-            .middle_of(id)
-            .graphics_for(id)
-            .color(color)
-            .border(border)
-            .border_color(border_color)
-            .set(state.ids.rectangle, ui);
+        if switch {
+            // Slide the knob towards the side representing the new value.
+            let target = knob_perc(new_value);
+            if state.knob_perc != target {
+                let now = ui.now();
+                let secs = state
+                    .maybe_last_knob_step
+                    .map_or(0.0, |last| now.duration_since(last).as_secs_f64());
+                let step = secs / KNOB_SLIDE_SECS;
+                let knob_perc = if target > state.knob_perc {
+                    (state.knob_perc + step).min(target)
+                } else {
+                    (state.knob_perc - step).max(target)
+                };
+                let maybe_last_knob_step = if knob_perc != target {
+                    ui.request_continuous_updates();
+                    Some(now)
+                } else {
+                    None
+                };
+                state.update(|state| {
+                    state.knob_perc = knob_perc;
+                    state.maybe_last_knob_step = maybe_last_knob_step;
+                });
+            }
+
+            // The pill-shaped track.
+            let (track, knob) = switch_rects(rect, state.knob_perc);
+            widget::RoundedRectangle::fill_with(track.dim(), track.h() / 2.0, color)
+                .middle_of(id)
+                .graphics_for(id)
+                .set(state.ids.rectangle, ui);
+
+            // The sliding knob.
+            let knob_color = style.knob_color(ui.theme());
+            widget::Circle::fill_with(knob.w() / 2.0, knob_color)
+                .x_y_relative_to(id, knob.x() - rect.x(), knob.y() - rect.y())
+                .graphics_for(id)
+                .parent(id)
+                .set(state.ids.knob, ui);
+        } else {
+            let border_color = style.border_color(ui.theme());
+            widget::BorderedRectangle::new(dim)
+                .middle_of(id)
+                .graphics_for(id)
+                .color(color)
+                .border(border)
+                .border_color(border_color)
+                .set(state.ids.rectangle, ui);
+
+            // The dash across the middle of an indeterminate Toggle.
+            if new_value.is_none() {
+                let color = style.color(ui.theme());
+                let color = if enabled {
+                    color
+                } else {
+                    ui.theme.disabled(color)
+                };
+                widget::Rectangle::fill_with([dim[0] / 2.0, dim[1] / 5.0], color)
+                    .middle_of(id)
+                    .graphics_for(id)
+                    .parent(id)
+                    .set(state.ids.dash, ui);
+            }
+        }
 
         // Label widget.
         if let Some(label) = maybe_label {
@@ -217,6 +354,34 @@ impl<'a> Widget for Toggle<'a> {
     }
 }
 
+/// The position of a switch's knob for the given value, where an indeterminate value rests in the
+/// middle of the track.
+fn knob_perc(value: Option<bool>) -> Scalar {
+    match value {
+        Some(true) => 1.0,
+        Some(false) => 0.0,
+        None => 0.5,
+    }
+}
+
+/// The track and knob of a switch-style Toggle occupying the given `rect`.
+///
+/// The `knob_perc` describes the position of the knob from the left (off) side of the track at
+/// `0.0` to the right (on) side at `1.0`.
+pub fn switch_rects(rect: Rect, knob_perc: Scalar) -> (Rect, Rect) {
+    let pad = rect.h() / 10.0;
+    let knob_diameter = rect.h() - pad * 2.0;
+    let knob_radius = knob_diameter / 2.0;
+    let knob_x_min = rect.left() + pad + knob_radius;
+    let knob_x_max = rect.right() - pad - knob_radius;
+    let knob_x = map_range(knob_perc, 0.0, 1.0, knob_x_min, knob_x_max);
+    let knob = Rect {
+        x: Range::from_pos_and_len(knob_x, knob_diameter),
+        y: Range::from_pos_and_len(rect.y(), knob_diameter),
+    };
+    (rect, knob)
+}
+
 impl<'a> Colorable for Toggle<'a> {
     builder_method!(color { style.color = Some(Color) });
 }
@@ -0,0 +1,201 @@
+//! A pannable, zoomable view onto a 2D world, e.g. for node editors and diagrams.
+
+use position::{Dimensions, Point, Range, Scalar};
+use utils::{clamp, vec2_add, vec2_sub};
+use widget;
+use {Color, Colorable, Positionable, Widget};
+
+/// A pannable, zoomable view onto a 2D "world" coordinate space.
+///
+/// The view is panned by dragging with the left mouse button and zoomed by scrolling, where
+/// scrolling up zooms in. Zooming is anchored upon the mouse so that the world point beneath the
+/// cursor remains beneath it.
+///
+/// Its reaction yields the current `Transform` every update. Children may be placed in world
+/// coordinates by mapping their positions via `Transform::world_to_screen` and their dimensions
+/// via `Transform::scale`, while mouse coordinates may be mapped to the world via
+/// `Transform::screen_to_world` for hit-testing. Children are cropped to the `Viewport`.
+#[derive(Copy, Clone, WidgetCommon_)]
+pub struct Viewport {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    zoom_limits: Range,
+    style: Style,
+    /// Whether or not user input is enabled.
+    pub enabled: bool,
+}
+
+/// Unique styling for the `Viewport`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the background behind the world.
+    #[conrod(default = "theme.background_color")]
+    pub color: Option<Color>,
+    /// The rate at which scrolling zooms the view.
+    ///
+    /// The zoom is doubled for every `1.0 / zoom_speed` scrolled.
+    #[conrod(default = "0.01")]
+    pub zoom_speed: Option<Scalar>,
+}
+
+widget_ids! {
+    struct Ids {
+        background,
+    }
+}
+
+/// The state of the `Viewport`.
+pub struct State {
+    ids: Ids,
+    pan: Point,
+    zoom: Scalar,
+}
+
+/// The mapping between the world coordinates of a `Viewport` and the absolute coordinates of
+/// the screen.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    /// The screen position of the centre of the `Viewport`.
+    pub origin: Point,
+    /// The world position displayed at the centre of the `Viewport`.
+    pub pan: Point,
+    /// The number of screen units per world unit.
+    pub zoom: Scalar,
+}
+
+impl Transform {
+    /// Map the given world position to its absolute screen position.
+    pub fn world_to_screen(&self, world: Point) -> Point {
+        let [x, y] = vec2_sub(world, self.pan);
+        vec2_add(self.origin, [x * self.zoom, y * self.zoom])
+    }
+
+    /// Map the given absolute screen position to its world position.
+    ///
+    /// This is the inverse of `world_to_screen`.
+    pub fn screen_to_world(&self, screen: Point) -> Point {
+        let [x, y] = vec2_sub(screen, self.origin);
+        vec2_add(self.pan, [x / self.zoom, y / self.zoom])
+    }
+
+    /// Scale the given world dimensions to screen dimensions.
+    pub fn scale(&self, dim: Dimensions) -> Dimensions {
+        [dim[0] * self.zoom, dim[1] * self.zoom]
+    }
+}
+
+impl Viewport {
+    /// Begin building a `Viewport`.
+    pub fn new() -> Self {
+        Viewport {
+            common: widget::CommonBuilder {
+                crop_kids: true,
+                ..widget::CommonBuilder::default()
+            },
+            zoom_limits: Range::new(0.1, 10.0),
+            style: Style::default(),
+            enabled: true,
+        }
+    }
+
+    /// The minimum and maximum zoom of the view (`0.1` and `10.0` by default).
+    ///
+    /// Panics if `min` is not greater than `0.0` or if `min` is greater than `max`.
+    pub fn zoom_limits(mut self, min: Scalar, max: Scalar) -> Self {
+        assert!(
+            min > 0.0 && min <= max,
+            "the given zoom limits were {:?} however the min must be > 0 and <= the max",
+            (min, max)
+        );
+        self.zoom_limits = Range::new(min, max);
+        self
+    }
+
+    builder_methods! {
+        pub zoom_speed { style.zoom_speed = Some(Scalar) }
+        pub enabled { enabled = bool }
+    }
+}
+
+impl Widget for Viewport {
+    type State = State;
+    type Style = Style;
+    type Event = Transform;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+            pan: [0.0, 0.0],
+            zoom: 1.0,
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the Viewport.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let Viewport {
+            zoom_limits,
+            enabled,
+            ..
+        } = self;
+
+        let (min_zoom, max_zoom) = (zoom_limits.start, zoom_limits.end);
+        let mut transform = Transform {
+            origin: rect.xy(),
+            pan: state.pan,
+            zoom: clamp(state.zoom, min_zoom, max_zoom),
+        };
+
+        if enabled {
+            // Pan by dragging.
+            for drag in ui.widget_input(id).drags().left() {
+                let [dx, dy] = drag.delta_xy;
+                let delta = [dx / transform.zoom, dy / transform.zoom];
+                transform.pan = vec2_sub(transform.pan, delta);
+            }
+
+            // Zoom about the mouse by scrolling.
+            let zoom_speed = style.zoom_speed(&ui.theme);
+            let mouse_xy = ui.global_input().current.mouse.xy;
+            for scroll in ui.widget_input(id).scrolls() {
+                let anchor = transform.screen_to_world(mouse_xy);
+                let zoom = transform.zoom * (2.0 as Scalar).powf(scroll.y * zoom_speed);
+                transform.zoom = clamp(zoom, min_zoom, max_zoom);
+                let [x, y] = vec2_sub(mouse_xy, transform.origin);
+                transform.pan = vec2_sub(anchor, [x / transform.zoom, y / transform.zoom]);
+            }
+        }
+
+        if state.pan != transform.pan || state.zoom != transform.zoom {
+            state.update(|state| {
+                state.pan = transform.pan;
+                state.zoom = transform.zoom;
+            });
+        }
+
+        let color = style.color(&ui.theme);
+        widget::Rectangle::fill(rect.dim())
+            .xy(rect.xy())
+            .color(color)
+            .graphics_for(id)
+            .parent(id)
+            .set(state.ids.background, ui);
+
+        transform
+    }
+}
+
+impl Colorable for Viewport {
+    builder_method!(color { style.color = Some(Color) });
+}
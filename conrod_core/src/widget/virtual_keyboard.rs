@@ -0,0 +1,339 @@
+//! An on-screen keyboard for touch screens and kiosks.
+
+use event::Input;
+use input;
+use position::Scalar;
+use text;
+use widget;
+use {Color, Colorable, FontSize, Labelable, Positionable, Sizeable, Widget};
+
+/// A grid of key `Button`s that types into whichever widget is capturing the keyboard, e.g. a
+/// focused `TextBox` or `TextEdit`.
+///
+/// Character keys produce `Input::Text` events while the backspace and enter keys produce the
+/// `Press` and `Release` of `Key::Backspace` and `Key::Return` respectively. These are pushed to
+/// the `Ui` as though they were typed, so that the widget capturing the keyboard receives them
+/// during the next update.
+///
+/// Pressing a key would usually move keyboard capture to that key. Instead, the keyboard returns
+/// capture to the widget that was last capturing the keyboard before any of its keys.
+///
+/// Its reaction yields the input events produced by the keys pressed during the update.
+#[derive(Copy, Clone, WidgetCommon_)]
+pub struct VirtualKeyboard {
+    #[conrod(common_builder)]
+    common: widget::CommonBuilder,
+    style: Style,
+    /// Whether or not user input is enabled.
+    pub enabled: bool,
+}
+
+/// Unique styling for the `VirtualKeyboard`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, WidgetStyle_)]
+pub struct Style {
+    /// The color of the keys.
+    #[conrod(default = "theme.shape_color")]
+    pub color: Option<Color>,
+    /// The spacing between neighbouring keys.
+    #[conrod(default = "4.0")]
+    pub key_spacing: Option<Scalar>,
+    /// The color of the key labels.
+    #[conrod(default = "theme.label_color")]
+    pub label_color: Option<Color>,
+    /// The font size of the key labels.
+    #[conrod(default = "theme.font_size_medium")]
+    pub label_font_size: Option<FontSize>,
+    /// The ID of the font used to display the key labels.
+    #[conrod(default = "theme.font_id")]
+    pub label_font_id: Option<Option<text::font::Id>>,
+}
+
+widget_ids! {
+    struct Ids {
+        keys[],
+    }
+}
+
+/// The set of keys displayed by the `VirtualKeyboard`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Layout {
+    /// Letters, toggled between lower and upper case by the shift key.
+    Alpha,
+    /// Numbers and common punctuation.
+    Numeric,
+    /// Less common symbols.
+    Symbols,
+}
+
+/// The state of the `VirtualKeyboard`.
+pub struct State {
+    ids: Ids,
+    layout: Layout,
+    shift: bool,
+    target: Option<widget::Id>,
+}
+
+/// The `Event` type yielded by the `VirtualKeyboard`.
+pub type Event = Vec<Input>;
+
+/// A single key of the keyboard.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Key {
+    Char(char),
+    Shift,
+    Backspace,
+    Space,
+    Enter,
+    Layout(Layout),
+}
+
+/// The width of the widest row in units of a character key's width.
+const ROW_UNITS: Scalar = 10.0;
+
+impl State {
+    /// The currently displayed `Layout`.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Whether or not the shift key is active.
+    pub fn shift(&self) -> bool {
+        self.shift
+    }
+}
+
+impl Key {
+    // The width of the key in units of a character key's width.
+    fn units(&self) -> Scalar {
+        match *self {
+            Key::Char(_) => 1.0,
+            Key::Shift | Key::Backspace | Key::Layout(_) => 1.5,
+            Key::Enter => 2.0,
+            Key::Space => 5.0,
+        }
+    }
+
+    fn label(&self, shift: bool) -> String {
+        match *self {
+            Key::Char(ch) if shift => ch.to_uppercase().collect(),
+            Key::Char(ch) => ch.to_string(),
+            Key::Shift => "Shift".to_string(),
+            Key::Backspace => "Back".to_string(),
+            Key::Space => "Space".to_string(),
+            Key::Enter => "Enter".to_string(),
+            Key::Layout(Layout::Alpha) => "ABC".to_string(),
+            Key::Layout(Layout::Numeric) => "123".to_string(),
+            Key::Layout(Layout::Symbols) => "#+=".to_string(),
+        }
+    }
+}
+
+impl Layout {
+    // The rows of keys, from top to bottom.
+    fn rows(&self) -> Vec<Vec<Key>> {
+        fn chars(s: &str) -> Vec<Key> {
+            s.chars().map(Key::Char).collect()
+        }
+        let (top, middle, bottom, left, switch) = match *self {
+            Layout::Alpha => (
+                "qwertyuiop",
+                "asdfghjkl",
+                "zxcvbnm",
+                Key::Shift,
+                Layout::Numeric,
+            ),
+            Layout::Numeric => (
+                "1234567890",
+                "-/:;()$&@\"",
+                ".,?!'",
+                Key::Layout(Layout::Symbols),
+                Layout::Alpha,
+            ),
+            Layout::Symbols => (
+                "[]{}#%^*+=",
+                "_\\|~<>`",
+                ".,?!'",
+                Key::Layout(Layout::Numeric),
+                Layout::Alpha,
+            ),
+        };
+        let mut third = vec![left];
+        third.extend(chars(bottom));
+        third.push(Key::Backspace);
+        vec![
+            chars(top),
+            chars(middle),
+            third,
+            vec![Key::Layout(switch), Key::Space, Key::Enter],
+        ]
+    }
+}
+
+impl VirtualKeyboard {
+    /// Begin building a `VirtualKeyboard`.
+    pub fn new() -> Self {
+        VirtualKeyboard {
+            common: widget::CommonBuilder::default(),
+            style: Style::default(),
+            enabled: true,
+        }
+    }
+
+    /// Specify the font used for displaying the key labels.
+    pub fn label_font_id(mut self, font_id: text::font::Id) -> Self {
+        self.style.label_font_id = Some(Some(font_id));
+        self
+    }
+
+    builder_methods! {
+        pub key_spacing { style.key_spacing = Some(Scalar) }
+        pub label_color { style.label_color = Some(Color) }
+        pub label_font_size { style.label_font_size = Some(FontSize) }
+        pub enabled { enabled = bool }
+    }
+}
+
+impl Widget for VirtualKeyboard {
+    type State = State;
+    type Style = Style;
+    type Event = Event;
+
+    fn init_state(&self, id_gen: widget::id::Generator) -> Self::State {
+        State {
+            ids: Ids::new(id_gen),
+            layout: Layout::Alpha,
+            shift: false,
+            target: None,
+        }
+    }
+
+    fn style(&self) -> Self::Style {
+        self.style.clone()
+    }
+
+    /// Update the state of the VirtualKeyboard.
+    fn update(self, args: widget::UpdateArgs<Self>) -> Self::Event {
+        let widget::UpdateArgs {
+            id,
+            state,
+            rect,
+            style,
+            ui,
+            ..
+        } = args;
+        let VirtualKeyboard { enabled, .. } = self;
+
+        // Keep track of the widget that we are typing into, returning keyboard capture to it if
+        // it was taken by one of our keys.
+        let capturing = ui.global_input().current.widget_capturing_keyboard;
+        match capturing {
+            Some(capturing)
+                if capturing == id
+                    || ui
+                        .widget_graph()
+                        .does_recursive_depth_edge_exist(id, capturing) =>
+            {
+                if let Some(target) = state.target {
+                    ui.keyboard_capture(target);
+                }
+            }
+            Some(capturing) if state.target != Some(capturing) => {
+                state.update(|state| state.target = Some(capturing));
+            }
+            _ => (),
+        }
+
+        let rows = state.layout.rows();
+        let num_keys = rows.iter().map(|row| row.len()).sum();
+        if state.ids.keys.len() < num_keys {
+            state.update(|state| {
+                state
+                    .ids
+                    .keys
+                    .resize(num_keys, &mut ui.widget_id_generator())
+            });
+        }
+
+        let spacing = style.key_spacing(&ui.theme);
+        let color = style.color(&ui.theme);
+        let label_color = style.label_color(&ui.theme);
+        let label_font_size = style.label_font_size(&ui.theme);
+        let font_id = style.label_font_id(&ui.theme).or(ui.fonts.ids().next());
+        let unit_w = rect.w() / ROW_UNITS;
+        let row_h = rect.h() / rows.len() as Scalar;
+
+        let mut layout = state.layout;
+        let mut shift = state.shift;
+        let mut events = Vec::new();
+        let mut key_ids = state.ids.keys.iter();
+        for (i, row) in rows.iter().enumerate() {
+            // Each row is centred horizontally.
+            let row_w = row.iter().map(|key| key.units() * unit_w).sum::<Scalar>();
+            let y = rect.h() / 2.0 - row_h * (i as Scalar + 0.5);
+            let mut left = -row_w / 2.0;
+            for &key in row {
+                let key_w = key.units() * unit_w;
+                let x = left + key_w / 2.0;
+                left += key_w;
+
+                let key_color = match key {
+                    Key::Shift if state.shift => color.clicked(),
+                    _ => color,
+                };
+                let label = key.label(state.shift);
+                let mut button = widget::Button::new()
+                    .w_h(key_w - spacing, row_h - spacing)
+                    .x_y_relative_to(id, x, y)
+                    .color(key_color)
+                    .label(&label)
+                    .label_color(label_color)
+                    .label_font_size(label_font_size)
+                    .enabled(enabled)
+                    .parent(id);
+                if let Some(font_id) = font_id {
+                    button = button.label_font_id(font_id);
+                }
+                let key_id = *key_ids.next().unwrap();
+                for _click in button.set(key_id, ui) {
+                    match key {
+                        Key::Char(_) => {
+                            events.push(Input::Text(key.label(shift)));
+                            shift = false;
+                        }
+                        Key::Space => events.push(Input::Text(" ".to_string())),
+                        Key::Shift => shift = !shift,
+                        Key::Layout(new_layout) => {
+                            layout = new_layout;
+                            shift = false;
+                        }
+                        Key::Backspace | Key::Enter => {
+                            let key = match key {
+                                Key::Backspace => input::Key::Backspace,
+                                _ => input::Key::Return,
+                            };
+                            events.push(Input::Press(input::Button::Keyboard(key)));
+                            events.push(Input::Release(input::Button::Keyboard(key)));
+                        }
+                    }
+                }
+            }
+        }
+
+        if state.layout != layout || state.shift != shift {
+            state.update(|state| {
+                state.layout = layout;
+                state.shift = shift;
+            });
+        }
+
+        for event in &events {
+            ui.push_input(event.clone());
+        }
+
+        events
+    }
+}
+
+impl Colorable for VirtualKeyboard {
+    builder_method!(color { style.color = Some(Color) });
+}
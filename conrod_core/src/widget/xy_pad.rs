@@ -239,7 +239,7 @@ where
         let y_string = val_to_string(new_y, max_y, max_y - min_y, rect.h() as usize);
         let value_string = format!("{}, {}", x_string, y_string);
         let cross_hair_xy = [inner_rect.x() + v_line_x, inner_rect.y() + h_line_y];
-        const VALUE_TEXT_PAD: f64 = 5.0;
+        let value_text_pad = ui.theme.spacing_sm;
         let x_direction = match inner_rect.x.closest_edge(cross_hair_xy[0]) {
             Edge::End => Direction::Backwards,
             Edge::Start => Direction::Forwards,
@@ -251,8 +251,8 @@ where
         let value_font_size = style.value_font_size(ui.theme());
         widget::Text::new(&value_string)
             .and_then(font_id, widget::Text::font_id)
-            .x_direction_from(state.ids.v_line, x_direction, VALUE_TEXT_PAD)
-            .y_direction_from(state.ids.h_line, y_direction, VALUE_TEXT_PAD)
+            .x_direction_from(state.ids.v_line, x_direction, value_text_pad)
+            .y_direction_from(state.ids.h_line, y_direction, value_text_pad)
             .color(line_color)
             .graphics_for(id)
             .parent(id)